@@ -0,0 +1,2745 @@
+//! Derive macros for the [lilbig](https://crates.io/crates/lilbig) crate.
+//!
+//! This crate is not intended to be used directly. Enable the `derive` feature of `lilbig` instead,
+//! which re-exports these macros alongside the traits they implement.
+
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::meta::ParseNestedMeta;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Field, Fields, Generics, Ident, Index, LitStr, Member,
+    Pat, Token, Type, Variant, WherePredicate,
+};
+
+/// Derive `FieldsByteOrdered` for a struct, enum, or union by swapping the byte-order of each of
+/// its fields.
+///
+/// Every field of the struct must itself implement `FieldsByteOrdered`. Structs with named fields,
+/// tuple structs, and unit structs are supported.
+///
+/// Enums, such as protocol messages with data-carrying variants, are swapped by matching on the
+/// active variant and swapping its fields. The discriminant of the enum is not swapped, and the
+/// field attributes below apply to the fields of each variant, with `len_field` and `tag` naming
+/// fields of the same variant. The enum is order invariant if the fields of all of its variants are.
+///
+/// Unions are swapped by swapping their active field, which is designated by the `unsafe_active`
+/// attribute described below. The fields that may be active must implement `WireSafe` in addition
+/// to `FieldsByteOrdered`. This does not make swapping them sound on its own, as writing a union
+/// through a field narrower than the active one leaves the trailing bytes of the active field
+/// uninitialized, and so the attribute is unsafe. No field attributes are supported, and
+/// `#[repr(packed)]` unions are rejected.
+///
+/// The fields of `#[repr(packed)]` structs, such as on-disk headers, are swapped through unaligned
+/// copies, as references may not be taken to them.
+///
+/// Swaps of fields whose types are order invariant, such as byte arrays, `bool`, and
+/// `PhantomData`, are elided at compile time through
+/// `FieldsByteOrdered::IS_ORDER_INVARIANT`. The struct is itself order invariant if each of its
+/// fields is.
+///
+/// The implementations for generic structs are bounded by the traits required of the types of
+/// their fields that mention the struct's type parameters, such as `T: FieldsByteOrdered` for a
+/// field of type `T`. Fields limited by `len_field` are not bounded.
+///
+/// # Struct attributes
+/// - `#[lilbig(bound = "T: Trait, ...")]` replaces the inferred bounds with the given where
+///   predicates, for cases where the inferred bounds are wrong. An empty string removes the bounds.
+///   The bounds apply to each of the crate's derives on the struct.
+/// - `#[lilbig(unsafe_active = "field")]` designates `field` as the active field of a union, which
+///   is always swapped. Alternatively,
+///   `#[lilbig(unsafe_active = "select", match(0 => ping, 1 | 2 => data))]` calls `select`, a
+///   function or closure taking a reference to the union, and swaps the field of the arm matching
+///   the value it returns. No field is swapped if no arm matches. The selector is called before any
+///   field is swapped, which suits tags that are order invariant, such as a leading byte shared by
+///   each of the union's fields.
+///
+///   The attribute is unsafe: it asserts that every byte of the active field is initialized
+///   whenever the derived methods are called, and the behavior is undefined otherwise. This holds
+///   if each field of the union is as large as the union, or if the union is only ever written
+///   through the field that is active when it is swapped.
+///
+/// # Field attributes
+/// - `#[lilbig(len_field = "count")]` swaps only the first `count` elements of a collection field,
+///   where `count` is an integer field of the same struct. The remaining elements are left
+///   unmodified. The count is read after its own byte-order has been swapped, which suits
+///   converting to the machine's native byte-order. Adding `len_read = "before"` reads the count
+///   before it is swapped instead, which suits converting from the machine's native byte-order.
+///   Counts exceeding the length of the collection are clamped to its length, and negative counts
+///   swap none of its elements.
+/// - `#[lilbig(tag = "kind", match(0 => Ping, 1 | 2 => Data))]` swaps a field holding a C-style
+///   tagged union as the overlay selected by the value of `kind`, another field of the same struct.
+///   The field must implement `WireSafe`, such as a byte array, and each overlay must implement both
+///   `FieldsByteOrdered` and `WireSafe` and be no larger than the field. The overlay is swapped over
+///   the leading bytes of the field, and fields whose tag matches no arm are left unmodified. The
+///   tag is read after its own byte-order has been swapped unless `tag_read = "before"` is given,
+///   as with `len_read`.
+/// - `#[lilbig(padding)]` marks a field as padding, such as a reserved byte array. Padding is not
+///   swapped, and is instead zeroed by `FieldsByteOrdered::zero_padding()` so that stale contents
+///   are not leaked when the struct is written to a file or the network. The field must implement
+///   `WireSafe`. Padding nested within the struct's other fields is zeroed as well.
+/// - `#[lilbig(skip)]` exempts a field to which byte-order is irrelevant, such as a `String`, from
+///   swapping. The field need not implement any of the crate's traits, and is left unmodified by
+///   `FieldsByteOrdered::zero_padding()`.
+/// - `#[lilbig(with = "path")]` swaps a field by calling the function at `path` with a mutable
+///   reference to the field, rather than through the field's implementation of
+///   `FieldsByteOrdered`. This suits fields needing bespoke handling, such as mixed-endian GUIDs.
+///   Combined with `len_field`, the function is called with each of the swapped elements instead.
+/// - `#[lilbig(order = "be")]` or `#[lilbig(order = "le")]` pins a field to a fixed byte-order
+///   regardless of that of the rest of the struct, such as a big-endian checksum within an
+///   otherwise little-endian header. Pinned fields are left unmodified by
+///   `FieldsByteOrdered::swap_field_orders()`, and are instead converted between their pinned
+///   byte-order and the machine's native byte-order by
+///   `FieldsByteOrdered::swap_pinned_field_orders()`, which the `order_fields` methods call as
+///   described by its documentation. Pinned fields nested within the struct's other fields are
+///   converted as well.
+/// - `#[lilbig(preserve_bits = MASK)]` swaps a field while leaving the bits selected by `MASK`, an
+///   expression of the field's type, as they were, such as the ownership bit of a DMA descriptor.
+///   The mask selects bits of the field's in-memory representation as described by
+///   `lilbig::masked::swap_preserving_bits()`, which performs the swap. The field must implement
+///   `WireSafe`.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::{ByteOrder, FieldsByteOrdered};
+///
+/// #[derive(FieldsByteOrdered)]
+/// #[repr(transparent)]
+/// struct GroupId(u32);
+///
+/// #[derive(FieldsByteOrdered)]
+/// #[repr(C)]
+/// struct FileInfo {
+///     offset: u64,
+///     length: u32,
+///     groups: [GroupId; 2],
+/// }
+///
+/// let mut info = FileInfo {
+///     offset: 0x0123456789abcdef,
+///     length: 0x01020304,
+///     groups: [GroupId(0xaabbccdd), GroupId(0x11223344)],
+/// };
+/// info.order_fields_be(ByteOrder::Le);
+/// assert_eq!(info.offset, 0x0123456789abcdef_u64.to_be());
+/// assert_eq!(info.length, 0x01020304_u32.to_be());
+/// assert_eq!(info.groups[1].0, 0x11223344_u32.to_be());
+/// ```
+///
+/// Swapping a packed on-disk header:
+/// ```
+/// use lilbig::{ByteOrder, FieldsByteOrdered};
+///
+/// #[derive(FieldsByteOrdered)]
+/// #[repr(C, packed)]
+/// struct Header {
+///     kind: u8,
+///     length: u32,
+///     offsets: [u16; 2],
+/// }
+///
+/// let mut header = Header {
+///     kind: 7,
+///     length: 0x01020304,
+///     offsets: [0x0102, 0x0304],
+/// };
+/// header.order_fields_be(ByteOrder::Le);
+/// assert_eq!({ header.length }, 0x01020304_u32.to_be());
+/// assert_eq!({ header.offsets }, [0x0102_u16.to_be(), 0x0304_u16.to_be()]);
+/// ```
+///
+/// Swapping the active variant of an enum:
+/// ```
+/// use lilbig::{ByteOrder, FieldsByteOrdered};
+///
+/// #[derive(Debug, PartialEq, FieldsByteOrdered)]
+/// enum Request {
+///     Ping { sequence: u32 },
+///     Read(u64, u16),
+///     Quit,
+/// }
+///
+/// let mut request = Request::Read(0x0102030405060708_u64.to_be(), 0x0910_u16.to_be());
+/// request.order_fields_ne(ByteOrder::Be);
+/// assert_eq!(request, Request::Read(0x0102030405060708, 0x0910));
+/// ```
+///
+/// Swapping a union whose fields share a leading tag:
+/// ```
+/// use lilbig::{ByteOrder, FieldsByteOrdered, WireSafe};
+///
+/// #[derive(Clone, Copy, FieldsByteOrdered)]
+/// #[repr(C)]
+/// struct Resize {
+///     kind: u8,
+///     padding: [u8; 3],
+///     width: u32,
+///     height: u32,
+/// }
+///
+/// #[derive(Clone, Copy, FieldsByteOrdered)]
+/// #[repr(C)]
+/// struct Key {
+///     kind: u8,
+///     padding: [u8; 1],
+///     code: u16,
+/// }
+///
+/// // SAFETY: `Resize` and `Key` contain only integers and byte arrays, without padding.
+/// unsafe impl WireSafe for Resize {}
+/// unsafe impl WireSafe for Key {}
+///
+/// #[derive(Clone, Copy, FieldsByteOrdered)]
+/// // SAFETY: `Event` is only written through the field selected by `kind`.
+/// #[lilbig(unsafe_active = "|event: &Event| unsafe { event.kind }", match(1 => resize, 2 => key))]
+/// #[repr(C)]
+/// union Event {
+///     kind: u8,
+///     resize: Resize,
+///     key: Key,
+/// }
+///
+/// let key = Key { kind: 2, padding: [0], code: 0x0102_u16.to_be() };
+/// let mut event = Event { key };
+/// event.order_fields_ne(ByteOrder::Be);
+/// assert_eq!(unsafe { event.key.code }, 0x0102);
+/// ```
+///
+/// Swapping a tagged union:
+/// ```
+/// use lilbig::{ByteOrder, FieldsByteOrdered, WireSafe};
+///
+/// #[derive(Clone, Copy, FieldsByteOrdered)]
+/// #[repr(C)]
+/// struct Ping {
+///     sequence: u32,
+/// }
+///
+/// // SAFETY: `Ping` contains only a `u32`.
+/// unsafe impl WireSafe for Ping {}
+///
+/// #[derive(FieldsByteOrdered)]
+/// #[repr(C)]
+/// struct Message {
+///     kind: u16,
+///     #[lilbig(tag = "kind", match(0 => Ping, 1 => [u16; 4]))]
+///     body: [u8; 8],
+/// }
+///
+/// let mut message = Message {
+///     kind: 1_u16.to_be(),
+///     body: [0, 1, 0, 2, 0, 3, 0, 4],
+/// };
+/// message.order_fields_ne(ByteOrder::Be);
+/// assert_eq!(message.kind, 1);
+/// assert_eq!(<[u16; 4]>::from_bytes(&message.body), Some([1, 2, 3, 4]));
+/// ```
+///
+/// Order invariant structs:
+/// ```
+/// use core::marker::PhantomData;
+/// use lilbig::FieldsByteOrdered;
+///
+/// #[derive(FieldsByteOrdered)]
+/// struct Name<T> {
+///     bytes: [u8; 16],
+///     terminated: bool,
+///     _marker: PhantomData<T>,
+/// }
+///
+/// assert!(Name::<u32>::IS_ORDER_INVARIANT);
+/// ```
+///
+/// Generic structs:
+/// ```
+/// use lilbig::{ByteOrder, FieldsByteOrdered};
+///
+/// #[derive(FieldsByteOrdered)]
+/// struct Header<T> {
+///     version: u16,
+///     payload: T,
+/// }
+///
+/// // Fields limited by `len_field` are not bounded, so the bounds are given explicitly.
+/// #[derive(FieldsByteOrdered)]
+/// #[lilbig(bound = "T: FieldsByteOrdered")]
+/// struct Batch<T> {
+///     count: u8,
+///     #[lilbig(len_field = "count")]
+///     items: [T; 4],
+/// }
+///
+/// let mut header = Header {
+///     version: 1_u16.to_be(),
+///     payload: 0x01020304_u32.to_be(),
+/// };
+/// header.order_fields_ne(ByteOrder::Be);
+/// assert_eq!(header.payload, 0x01020304);
+///
+/// let mut batch = Batch {
+///     count: 1,
+///     items: [0x0102_u16.to_be(); 4],
+/// };
+/// batch.order_fields_ne(ByteOrder::Be);
+/// assert_eq!(batch.items, [0x0102, 0x0102_u16.to_be(), 0x0102_u16.to_be(), 0x0102_u16.to_be()]);
+/// ```
+///
+/// Skipping fields to which byte-order is irrelevant:
+/// ```
+/// use lilbig::FieldsByteOrdered;
+///
+/// #[derive(FieldsByteOrdered)]
+/// struct PersonRecord {
+///     #[lilbig(skip)]
+///     name: String,
+///     age: u16,
+/// }
+///
+/// let mut record = PersonRecord {
+///     name: "Wolfgang".into(),
+///     age: 23,
+/// };
+/// record.swap_field_orders();
+/// assert_eq!(record.name, "Wolfgang");
+/// assert_eq!(record.age, 23_u16.swap_bytes());
+/// ```
+///
+/// Swapping fields with custom functions:
+/// ```
+/// use lilbig::FieldsByteOrdered;
+///
+/// /// Swap the 16-bit halves of a word whose halves are each stored in big-endian byte-order.
+/// fn swap_halves(word: &mut u32) {
+///     *word = word.rotate_left(16);
+/// }
+///
+/// #[derive(FieldsByteOrdered)]
+/// struct Registers {
+///     status: u16,
+///     #[lilbig(with = "swap_halves")]
+///     counter: u32,
+/// }
+///
+/// let mut registers = Registers {
+///     status: 0x0102,
+///     counter: 0x11223344,
+/// };
+/// registers.swap_field_orders();
+/// assert_eq!(registers.status, 0x0201);
+/// assert_eq!(registers.counter, 0x33441122);
+/// ```
+///
+/// Swapping only the valid elements of an array:
+/// ```
+/// use lilbig::{ByteOrder, FieldsByteOrdered};
+///
+/// #[derive(FieldsByteOrdered)]
+/// struct Readings {
+///     count: u16,
+///     #[lilbig(len_field = "count")]
+///     values: [u32; 4],
+/// }
+///
+/// let mut readings = Readings {
+///     count: 2_u16.to_be(),
+///     values: [1_u32.to_be(), 2_u32.to_be(), 0xdeadbeef, 0xdeadbeef],
+/// };
+/// readings.order_fields_ne(ByteOrder::Be);
+/// assert_eq!(readings.count, 2);
+/// assert_eq!(readings.values, [1, 2, 0xdeadbeef, 0xdeadbeef]);
+/// ```
+///
+/// Leaving the elements of an array with a corrupt negative count unmodified:
+/// ```
+/// use lilbig::FieldsByteOrdered;
+///
+/// #[derive(FieldsByteOrdered)]
+/// struct Samples {
+///     count: i8,
+///     #[lilbig(len_field = "count")]
+///     values: [u16; 2],
+/// }
+///
+/// let mut samples = Samples {
+///     count: -1,
+///     values: [0x0102, 0x0304],
+/// };
+/// samples.swap_field_orders();
+/// assert_eq!(samples.values, [0x0102, 0x0304]);
+/// ```
+///
+/// Clamping a count too large for `usize` to the length of the array:
+/// ```
+/// use lilbig::FieldsByteOrdered;
+///
+/// #[derive(FieldsByteOrdered)]
+/// struct Samples {
+///     count: u128,
+///     #[lilbig(len_field = "count")]
+///     values: [u16; 2],
+/// }
+///
+/// let mut samples = Samples {
+///     count: u128::MAX,
+///     values: [0x0102, 0x0304],
+/// };
+/// samples.swap_field_orders();
+/// assert_eq!(samples.values, [0x0201, 0x0403]);
+/// ```
+///
+/// Preserving the ownership bit of a DMA descriptor:
+/// ```
+/// use lilbig::FieldsByteOrdered;
+///
+/// /// Mask of the ownership bit, the most significant bit of the descriptor's first byte.
+/// const OWNED: u32 = u32::from_ne_bytes([0x80, 0, 0, 0]);
+///
+/// #[derive(FieldsByteOrdered)]
+/// #[repr(C)]
+/// struct Descriptor {
+///     #[lilbig(preserve_bits = OWNED)]
+///     control: u32,
+///     address: u32,
+/// }
+///
+/// let mut descriptor = Descriptor {
+///     control: u32::from_ne_bytes([0x80, 0x00, 0x00, 0x40]),
+///     address: 0x1000,
+/// };
+/// descriptor.swap_field_orders();
+/// assert_eq!(descriptor.control.to_ne_bytes(), [0xc0, 0x00, 0x00, 0x80]);
+/// assert_eq!(descriptor.address, 0x1000_u32.swap_bytes());
+/// ```
+#[proc_macro_derive(FieldsByteOrdered, attributes(lilbig))]
+pub fn derive_fields_byte_ordered(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_fields_byte_ordered(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expand the `FieldsByteOrdered` derive for `input`.
+fn expand_fields_byte_ordered(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    // The struct's context is only used by `FieldsByteOrderedWith`, but is validated regardless.
+    let container = ContainerAttrs::parse(input)?;
+    let (swap, zero_padding, pinned_swap, invariants, pinned, bounds) = match &input.data {
+        Data::Struct(data) => {
+            let access = if is_packed(input)? {
+                Access::Packed
+            } else {
+                Access::Field
+            };
+            let SwapBody {
+                captures,
+                swaps,
+                limited_swaps,
+                invariants,
+                zero_padding,
+                bounds,
+                pinned_swaps,
+                pinned,
+            } = SwapBody::new(&data.fields, None, access)?;
+            (
+                quote!(#(#captures)* #(#swaps)* #(#limited_swaps)*),
+                quote!(#(#zero_padding)*),
+                quote!(#(#pinned_swaps)*),
+                invariants,
+                pinned,
+                bounds,
+            )
+        }
+        Data::Enum(data) => {
+            let mut swap_arms = Vec::new();
+            let mut zero_padding_arms = Vec::new();
+            let mut pinned_swap_arms = Vec::new();
+            let mut invariants = Vec::new();
+            let mut pinned = Vec::new();
+            let mut bounds = Vec::new();
+            for variant in &data.variants {
+                let body = SwapBody::new(&variant.fields, None, Access::Binding)?;
+                let SwapBody {
+                    captures,
+                    swaps,
+                    limited_swaps,
+                    zero_padding,
+                    pinned_swaps,
+                    ..
+                } = &body;
+                let pattern = variant_pattern(variant);
+                swap_arms.push(quote! {
+                    #pattern => {
+                        #(#captures)*
+                        #(#swaps)*
+                        #(#limited_swaps)*
+                    }
+                });
+                zero_padding_arms.push(quote! {
+                    #pattern => {
+                        #(#zero_padding)*
+                    }
+                });
+                pinned_swap_arms.push(quote! {
+                    #pattern => {
+                        #(#pinned_swaps)*
+                    }
+                });
+                invariants.extend(body.invariants);
+                pinned.extend(body.pinned);
+                bounds.extend(body.bounds);
+            }
+            // Fields that are not swapped, such as padding, are bound regardless.
+            (
+                quote! {
+                    #[allow(unused_variables)]
+                    match *self {
+                        #(#swap_arms)*
+                    }
+                },
+                quote! {
+                    #[allow(unused_variables)]
+                    match *self {
+                        #(#zero_padding_arms)*
+                    }
+                },
+                quote! {
+                    #[allow(unused_variables)]
+                    match *self {
+                        #(#pinned_swap_arms)*
+                    }
+                },
+                invariants,
+                pinned,
+                bounds,
+            )
+        }
+        Data::Union(data) => {
+            if is_packed(input)? {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "`FieldsByteOrdered` may not be derived for `#[repr(packed)]` unions",
+                ));
+            }
+            let SwapBody {
+                swaps,
+                invariants,
+                zero_padding,
+                bounds,
+                pinned_swaps,
+                pinned,
+                ..
+            } = union_swap(&data.fields, &container)?;
+            (
+                quote!(#(#swaps)*),
+                quote!(#(#zero_padding)*),
+                quote!(#(#pinned_swaps)*),
+                invariants,
+                pinned,
+                bounds,
+            )
+        }
+    };
+    if container.active.is_some() && !matches!(input.data, Data::Union(_)) {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`unsafe_active` is only supported by unions",
+        ));
+    }
+
+    let ident = &input.ident;
+    let generics = bounded_generics(&input.generics, &container, bounds);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::lilbig::FieldsByteOrdered for #ident #ty_generics #where_clause {
+            const IS_ORDER_INVARIANT: bool = true #(&& #invariants)*;
+            const HAS_PINNED_FIELDS: bool = false #(|| #pinned)*;
+
+            #[inline]
+            fn swap_field_orders(&mut self) {
+                #swap
+            }
+
+            #[inline]
+            fn zero_padding(&mut self) {
+                #zero_padding
+            }
+
+            #[inline]
+            fn swap_pinned_field_orders(&mut self) {
+                #pinned_swap
+            }
+        }
+    })
+}
+
+/// Generate the statements swapping the active field of a union with the fields `fields`, which is
+/// selected by the `active` attribute of `container`.
+fn union_swap(fields: &syn::FieldsNamed, container: &ContainerAttrs) -> syn::Result<SwapBody> {
+    if let Some(field) = fields.named.iter().find(|field| {
+        field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("lilbig"))
+    }) {
+        return Err(syn::Error::new_spanned(
+            field,
+            "field attributes are not supported by unions",
+        ));
+    }
+    let Some(active) = &container.active else {
+        return Err(syn::Error::new_spanned(
+            fields,
+            "`FieldsByteOrdered` requires the active field of a union to be given by \
+             `#[lilbig(unsafe_active = \"field\")]`",
+        ));
+    };
+    let field_type = |ident: &Ident| {
+        fields
+            .named
+            .iter()
+            .find(|field| field.ident.as_ref() == Some(ident))
+            .map(|field| &field.ty)
+            .ok_or_else(|| {
+                syn::Error::new_spanned(ident, "`unsafe_active` must name a field of the union")
+            })
+    };
+
+    // Taking references to the fields of a union is unsafe, as the field may not hold a valid
+    // value. The fields are thus required to implement `WireSafe`, which makes any initialized
+    // value valid, while `unsafe_active` asserts that the field is initialized.
+    let access = |ident: &Ident, method: TokenStream2| {
+        quote! {
+            ::lilbig::FieldsByteOrdered::#method(wire_safe(
+                // SAFETY: Every initialized bit pattern is a valid value of the field, as it
+                // implements `WireSafe`, and `unsafe_active` asserts that the field is initialized.
+                unsafe { &mut self.#ident },
+            ));
+        }
+    };
+    let wire_safe = quote! {
+        #[inline(always)]
+        fn wire_safe<T: ::lilbig::WireSafe>(field: &mut T) -> &mut T {
+            field
+        }
+    };
+    let mut invariants = Vec::new();
+    let mut pinned = Vec::new();
+    let mut bounds = Vec::new();
+    let mut active_fields = Vec::new();
+    match active {
+        Active::Field(ident) => active_fields.push((None, ident)),
+        Active::Selector(_, arms) => {
+            active_fields.extend(
+                arms.iter()
+                    .map(|ActiveArm { pat, field }| (Some(pat), field)),
+            );
+        }
+    }
+    for (_, field) in &active_fields {
+        let ty = field_type(field)?;
+        invariants.push(quote!(<#ty as ::lilbig::FieldsByteOrdered>::IS_ORDER_INVARIANT));
+        pinned.push(quote!(<#ty as ::lilbig::FieldsByteOrdered>::HAS_PINNED_FIELDS));
+        bounds.push((
+            ty.clone(),
+            quote!(::lilbig::FieldsByteOrdered + ::lilbig::WireSafe),
+        ));
+    }
+    // Generate the statements calling `method` upon the active field.
+    let dispatch = |method: TokenStream2| match active {
+        Active::Field(ident) => {
+            let call = access(ident, method);
+            quote!(#wire_safe #call)
+        }
+        Active::Selector(select, _) => {
+            let arms = active_fields.iter().map(|(pat, field)| {
+                let call = access(field, method.clone());
+                quote!(#pat => { #call })
+            });
+            quote! {
+                #wire_safe
+                let select: fn(&Self) -> _ = #select;
+                #[allow(unreachable_patterns)]
+                match select(self) {
+                    #(#arms)*
+                    _ => {}
+                }
+            }
+        }
+    };
+    Ok(SwapBody {
+        captures: Vec::new(),
+        swaps: vec![dispatch(quote!(swap_field_orders))],
+        limited_swaps: Vec::new(),
+        invariants,
+        zero_padding: vec![dispatch(quote!(zero_padding))],
+        bounds,
+        pinned_swaps: vec![dispatch(quote!(swap_pinned_field_orders))],
+        pinned,
+    })
+}
+
+/// Derive `ByteOrdered` for a struct by swapping the byte-order of each of its fields by value and
+/// returning the struct.
+///
+/// This suits small value types, such as newtypes and fixed-size records, that are passed around by
+/// value rather than converted in place. Every field of the struct must itself implement
+/// `ByteOrdered` or be an array of such a type. Structs with named fields, tuple structs, unit
+/// structs, and `#[repr(packed)]` structs are supported. Enums and unions are rejected.
+///
+/// The implementations for generic structs are bounded as they are by
+/// `#[derive(FieldsByteOrdered)]`, and the `bound` struct attribute is likewise supported.
+///
+/// # Field attributes
+/// - `#[lilbig(padding)]` and `#[lilbig(skip)]` leave a field unmodified.
+/// - `#[lilbig(with = "path")]` swaps a field by calling the function at `path` with a mutable
+///   reference to the field.
+/// - `#[lilbig(preserve_bits = MASK)]` swaps a field while preserving the bits selected by `MASK`.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::{ByteOrder, ByteOrdered};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, ByteOrdered)]
+/// struct Meters(u32);
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, ByteOrdered)]
+/// struct Sample {
+///     distance: Meters,
+///     bearings: [u16; 2],
+/// }
+///
+/// let sample = Sample {
+///     distance: Meters(0x01020304),
+///     bearings: [0x0506, 0x0708],
+/// };
+/// assert_eq!(
+///     sample.ordered_as(ByteOrder::Le, ByteOrder::Be),
+///     Sample {
+///         distance: Meters(0x04030201),
+///         bearings: [0x0605, 0x0807],
+///     },
+/// );
+/// ```
+#[proc_macro_derive(ByteOrdered, attributes(lilbig))]
+pub fn derive_byte_ordered(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_byte_ordered(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expand the `ByteOrdered` derive for `input`.
+fn expand_byte_ordered(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`ByteOrdered` may only be derived for structs",
+        ));
+    };
+    let container = ContainerAttrs::parse(input)?;
+
+    let mut bindings = Vec::new();
+    let mut fields = Vec::new();
+    let mut bounds = Vec::new();
+    for (index, (field, member)) in data.fields.iter().zip(members(&data.fields)).enumerate() {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.len_field.is_some()
+            || attrs.tag.is_some()
+            || attrs.ctx
+            || attrs.order.is_some()
+            || attrs.is_gated()
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`ByteOrdered` only supports the `padding`, `skip`, `with`, and `preserve_bits` \
+                 field attributes",
+            ));
+        }
+        let binding = quote::format_ident!("__lilbig_field_{}", index);
+        let value = if attrs.padding || attrs.skip {
+            quote!(#binding)
+        } else if let Some(with) = &attrs.with {
+            quote! {{
+                let mut #binding = #binding;
+                #with(&mut #binding);
+                #binding
+            }}
+        } else if let Some(mask) = &attrs.preserve_bits {
+            bounds.push((
+                field.ty.clone(),
+                quote!(::lilbig::FieldsByteOrdered + ::lilbig::WireSafe),
+            ));
+            quote! {{
+                let mut #binding = #binding;
+                ::lilbig::masked::swap_preserving_bits(&mut #binding, #mask);
+                #binding
+            }}
+        } else {
+            bounds.push((
+                element_type(&field.ty).clone(),
+                quote!(::lilbig::ByteOrdered),
+            ));
+            swapped_type(&field.ty, quote!(#binding))
+        };
+        bindings.push(quote!(#member: #binding));
+        fields.push(quote!(#member: #value));
+    }
+
+    let ident = &input.ident;
+    let generics = bounded_generics(&input.generics, &container, bounds);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::lilbig::ByteOrdered for #ident #ty_generics #where_clause {
+            #[inline]
+            fn swapped_order(self) -> Self {
+                let Self { #(#bindings),* } = self;
+                Self { #(#fields),* }
+            }
+        }
+    })
+}
+
+/// Retrieve the type of the innermost elements of `ty` if it is an array, or `ty` otherwise.
+fn element_type(ty: &Type) -> &Type {
+    match ty {
+        Type::Array(array) => element_type(&array.elem),
+        Type::Group(group) => element_type(&group.elem),
+        Type::Paren(paren) => element_type(&paren.elem),
+        _ => ty,
+    }
+}
+
+/// Generate the expression swapping the byte-order of `value`, a value of type `ty`, expanding
+/// arrays into the swaps of their elements.
+fn swapped_type(ty: &Type, value: TokenStream2) -> TokenStream2 {
+    map_elements(
+        ty,
+        value,
+        &|ty, value| quote!(<#ty as ::lilbig::ByteOrdered>::swapped_order(#value)),
+    )
+}
+
+/// Generate the expression applying `map` to `value`, a value of type `ty`, expanding arrays into
+/// the applications to their innermost elements. `map` is given the type of the element along with
+/// the expression of its value.
+fn map_elements(
+    ty: &Type,
+    value: TokenStream2,
+    map: &dyn Fn(&Type, TokenStream2) -> TokenStream2,
+) -> TokenStream2 {
+    match ty {
+        Type::Array(array) => {
+            let mapped = map_elements(&array.elem, quote!(element), map);
+            quote!(#value.map(|element| #mapped))
+        }
+        Type::Group(group) => map_elements(&group.elem, value, map),
+        Type::Paren(paren) => map_elements(&paren.elem, value, map),
+        _ => map(ty, value),
+    }
+}
+
+/// Generate the type wrapping `ty` in `wrapper`, expanding arrays into arrays of their wrapped
+/// innermost elements.
+fn wrapped_type(ty: &Type, wrapper: &TokenStream2) -> TokenStream2 {
+    match ty {
+        Type::Array(array) => {
+            let elem = wrapped_type(&array.elem, wrapper);
+            let len = &array.len;
+            quote!([#elem; #len])
+        }
+        Type::Group(group) => wrapped_type(&group.elem, wrapper),
+        Type::Paren(paren) => wrapped_type(&paren.elem, wrapper),
+        _ => quote!(#wrapper<#ty>),
+    }
+}
+
+/// Derive a wire twin of a struct, a sibling struct whose fields are stored in a fixed byte-order,
+/// along with conversions between the two.
+///
+/// The byte-order of the twin is given by the `#[lilbig(twin_order = "le")]` or
+/// `#[lilbig(twin_order = "be")]` attribute of the struct, and the name of the twin by the optional
+/// `#[lilbig(twin = "Name")]` attribute, which defaults to the name of the struct suffixed with
+/// `Le` or `Be`. Each field of the twin wraps the corresponding field of the struct in
+/// `lilbig::ordered::Le` or `lilbig::ordered::Be`, with arrays wrapping each of their elements, so
+/// the on-disk representation is a distinct type that can't be mistaken for native data. Fields
+/// annotated with `#[lilbig(padding)]` are kept as they are, and those pinned by
+/// `#[lilbig(order = "...")]` are wrapped in the wrapper of their pinned byte-order instead. The
+/// other field attributes are rejected.
+///
+/// The struct must be `#[repr(C)]` or `#[repr(transparent)]` such that the twin, which shares the
+/// visibility and `#[repr]` of the struct, has a stable layout. The twin also shares the visibility
+/// and documentation of each of the struct's fields. It implements `Debug`, `Clone`, `Copy`, and
+/// `FieldsByteOrdered`, the latter of which leaves it unmodified, as well as `From` of the struct,
+/// which is implemented in turn for the struct from the twin. It additionally implements `WireSafe`
+/// when every field of the twin does, allowing it to be read from and written as bytes directly.
+/// Every field of the struct must implement `ByteOrdered` or be an array of such a type, and the
+/// twin must contain no padding, which is checked at compile time. Padding may instead be declared
+/// explicitly by fields annotated with `#[lilbig(padding)]`. Enums, unions, and generic structs are
+/// rejected.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::{WireSafe, WireTwin};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, WireTwin)]
+/// #[lilbig(twin_order = "be")]
+/// #[repr(C)]
+/// struct Header {
+///     length: u32,
+///     ports: [u16; 2],
+/// }
+///
+/// let header = Header { length: 0x01020304, ports: [80, 443] };
+/// let wire = HeaderBe::from(header);
+/// assert_eq!(wire.length.get(), 0x01020304);
+/// assert_eq!(wire.length.as_bytes(), [0x01, 0x02, 0x03, 0x04]);
+/// assert_eq!(wire.ports[1].as_bytes(), [0x01, 0xbb]);
+/// assert_eq!(Header::from(wire), header);
+///
+/// let bytes = [0x00, 0x00, 0x00, 0x2a, 0x00, 0x35, 0x1f, 0x90];
+/// let wire = HeaderBe::from_bytes(&bytes).unwrap();
+/// assert_eq!(wire.as_bytes(), bytes);
+/// assert_eq!(Header::from(wire), Header { length: 42, ports: [53, 8080] });
+/// ```
+///
+/// Twins containing padding are rejected:
+/// ```compile_fail,E0080
+/// use lilbig::WireTwin;
+///
+/// #[derive(Clone, Copy, WireTwin)]
+/// #[lilbig(twin_order = "le")]
+/// #[repr(C)]
+/// struct Header {
+///     length: u32,
+///     port: u16,
+/// }
+/// ```
+///
+/// Structs without a stable layout are rejected:
+/// ```compile_fail
+/// use lilbig::WireTwin;
+///
+/// #[derive(Clone, Copy, WireTwin)]
+/// #[lilbig(twin_order = "le")]
+/// struct Header {
+///     length: u32,
+///     flags: u32,
+/// }
+/// ```
+#[proc_macro_derive(WireTwin, attributes(lilbig))]
+pub fn derive_wire_twin(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_wire_twin(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expand the `WireTwin` derive for `input`.
+fn expand_wire_twin(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`WireTwin` may only be derived for structs",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`WireTwin` may not be derived for generic structs",
+        ));
+    }
+    if !reprs(input)?
+        .iter()
+        .any(|repr| repr == "C" || repr == "transparent")
+    {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`WireTwin` requires the struct to be `#[repr(C)]` or `#[repr(transparent)]`",
+        ));
+    }
+    let container = ContainerAttrs::parse(input)?;
+    let Some(order) = &container.twin_order else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`WireTwin` requires the byte-order of the twin to be given by \
+             `#[lilbig(twin_order = \"le\")]` or `#[lilbig(twin_order = \"be\")]`",
+        ));
+    };
+
+    let ident = &input.ident;
+    let twin = container
+        .twin
+        .clone()
+        .unwrap_or_else(|| quote::format_ident!("{}{}", ident, order));
+    let wrapper = quote!(::lilbig::ordered::#order);
+    let mut definitions = Vec::new();
+    let mut bindings = Vec::new();
+    let mut into_twin = Vec::new();
+    let mut from_twin = Vec::new();
+    let mut twin_tys = Vec::new();
+    for (index, (field, member)) in data.fields.iter().zip(members(&data.fields)).enumerate() {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.len_field.is_some()
+            || attrs.tag.is_some()
+            || attrs.ctx
+            || attrs.skip
+            || attrs.with.is_some()
+            || attrs.preserve_bits.is_some()
+            || attrs.is_gated()
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`WireTwin` only supports the `padding` and `order` field attributes",
+            ));
+        }
+        let binding = quote::format_ident!("__lilbig_field_{}", index);
+        let ty = &field.ty;
+        let (twin_ty, into, from) = if attrs.padding {
+            (quote!(#ty), quote!(#binding), quote!(#binding))
+        } else {
+            let wrapper = match &attrs.order {
+                Some(order) => quote!(::lilbig::ordered::#order),
+                None => wrapper.clone(),
+            };
+            (
+                wrapped_type(ty, &wrapper),
+                map_elements(
+                    ty,
+                    quote!(#binding),
+                    &|_, value| quote!(#wrapper::new(#value)),
+                ),
+                map_elements(ty, quote!(#binding), &|_, value| quote!(#value.get())),
+            )
+        };
+        let docs = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"));
+        let vis = &field.vis;
+        definitions.push(match &field.ident {
+            Some(name) => quote!(#(#docs)* #vis #name: #twin_ty),
+            None => quote!(#(#docs)* #vis #twin_ty),
+        });
+        bindings.push(quote!(#member: #binding));
+        into_twin.push(quote!(#member: #into));
+        from_twin.push(quote!(#member: #from));
+        twin_tys.push(twin_ty);
+    }
+
+    let vis = &input.vis;
+    let reprs = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"));
+    let doc = format!(
+        "Wire twin of [`{ident}`] whose fields are stored in {} byte-order.",
+        if order == "Le" {
+            "little-endian"
+        } else {
+            "big-endian"
+        },
+    );
+    let definition = match &data.fields {
+        Fields::Named(_) => quote!(#vis struct #twin { #(#definitions,)* }),
+        Fields::Unnamed(_) => quote!(#vis struct #twin(#(#definitions,)*);),
+        Fields::Unit => quote!(#vis struct #twin;),
+    };
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy)]
+        #(#reprs)*
+        #definition
+
+        #[automatically_derived]
+        impl ::core::convert::From<#ident> for #twin {
+            #[inline]
+            fn from(value: #ident) -> Self {
+                let #ident { #(#bindings,)* } = value;
+                Self { #(#into_twin,)* }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::From<#twin> for #ident {
+            #[inline]
+            fn from(value: #twin) -> Self {
+                let #twin { #(#bindings,)* } = value;
+                Self { #(#from_twin,)* }
+            }
+        }
+
+        /// The byte-order of the twin's fields is fixed thus swapping leaves it unmodified.
+        #[automatically_derived]
+        impl ::lilbig::FieldsByteOrdered for #twin {
+            const IS_ORDER_INVARIANT: bool = true;
+
+            #[inline(always)]
+            fn swap_field_orders(&mut self) {}
+        }
+
+        // The size of the twin must be the sum of the sizes of its fields, such that it contains
+        // no padding.
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<#twin>() == 0 #(+ ::core::mem::size_of::<#twin_tys>())*,
+            "wire twins must not contain padding",
+        );
+
+        // SAFETY: Every field of the twin is `WireSafe`, and the twin contains no padding as
+        // asserted above. The bounds are higher-ranked so that the impl is omitted rather than
+        // rejected when they don't hold.
+        #[automatically_derived]
+        unsafe impl ::lilbig::WireSafe for #twin
+        where
+            #(for<'__lilbig> #twin_tys: ::lilbig::WireSafe,)*
+        {
+        }
+    })
+}
+
+/// Derive `FieldsByteOrderedWith` for a struct by swapping the byte-order of each of its fields,
+/// passing a context to the swaps of the fields that require one.
+///
+/// The type of the context is given by the `#[lilbig(ctx = "Type")]` attribute of the struct, and
+/// must implement `Clone`. Fields annotated with `#[lilbig(ctx)]` must implement
+/// `FieldsByteOrderedWith` of the context, and are passed a clone of it. Every other field must
+/// implement `FieldsByteOrdered`. The `len_field`, `skip`, and `with` attributes are supported as
+/// they are by `#[derive(FieldsByteOrdered)]`, as are the bounds of generic structs and the
+/// `bound` struct attribute. The fields of `#[repr(packed)]` structs are swapped through unaligned
+/// copies. Enums and unions are rejected.
+///
+/// Fields added by later versions of a format are gated on the context by the
+/// `#[lilbig(since = "2")]` field attribute, which only swaps the field if the context is greater
+/// than or equal to the given expression, and likewise removed fields by `#[lilbig(until = "4")]`,
+/// which only swaps the field if the context is less than the given expression. The context must
+/// implement `PartialOrd` of the type of the expressions. Gated fields are left unmodified
+/// otherwise.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::{ByteOrder, FieldsByteOrdered, FieldsByteOrderedWith};
+///
+/// /// Entry whose `checksum` field only exists from version 2 onwards.
+/// struct Entry {
+///     id: u32,
+///     checksum: u32,
+/// }
+///
+/// impl FieldsByteOrderedWith<u16> for Entry {
+///     fn swap_field_orders_with(&mut self, version: u16) {
+///         self.id.swap_field_orders();
+///         if version >= 2 {
+///             self.checksum.swap_field_orders();
+///         }
+///     }
+/// }
+///
+/// #[derive(FieldsByteOrderedWith)]
+/// #[lilbig(ctx = "u16")]
+/// struct Directory {
+///     count: u32,
+///     #[lilbig(ctx, len_field = "count")]
+///     entries: [Entry; 2],
+/// }
+///
+/// let mut directory = Directory {
+///     count: 1_u32.to_be(),
+///     entries: [
+///         Entry { id: 7_u32.to_be(), checksum: 0xaabbccdd },
+///         Entry { id: 0, checksum: 0 },
+///     ],
+/// };
+/// directory.order_fields_ne_with(ByteOrder::Be, 1);
+/// assert_eq!(directory.entries[0].id, 7);
+/// assert_eq!(directory.entries[0].checksum, 0xaabbccdd);
+/// ```
+///
+/// Gating fields on the version of a format:
+/// ```
+/// use lilbig::{ByteOrder, FieldsByteOrderedWith};
+///
+/// #[derive(FieldsByteOrderedWith)]
+/// #[lilbig(ctx = "u16")]
+/// struct Record {
+///     id: u32,
+///     #[lilbig(since = "2")]
+///     checksum: u32,
+///     #[lilbig(until = "3")]
+///     legacy_flags: u16,
+/// }
+///
+/// let mut record = Record {
+///     id: 7_u32.to_be(),
+///     checksum: 0xaabbccdd,
+///     legacy_flags: 1_u16.to_be(),
+/// };
+/// record.order_fields_ne_with(ByteOrder::Be, 1);
+/// assert_eq!(record.id, 7);
+/// assert_eq!(record.checksum, 0xaabbccdd);
+/// assert_eq!(record.legacy_flags, 1);
+/// ```
+#[proc_macro_derive(FieldsByteOrderedWith, attributes(lilbig))]
+pub fn derive_fields_byte_ordered_with(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_fields_byte_ordered_with(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expand the `FieldsByteOrderedWith` derive for `input`.
+fn expand_fields_byte_ordered_with(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`FieldsByteOrderedWith` may only be derived for structs",
+        ));
+    };
+    let access = if is_packed(input)? {
+        Access::Packed
+    } else {
+        Access::Field
+    };
+    let container = ContainerAttrs::parse(input)?;
+    let Some(ctx_ty) = &container.ctx else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`FieldsByteOrderedWith` requires the type of its context to be given by \
+             `#[lilbig(ctx = \"Type\")]`",
+        ));
+    };
+
+    let ctx = Ident::new("ctx", proc_macro2::Span::mixed_site());
+    let SwapBody {
+        captures,
+        swaps,
+        limited_swaps,
+        bounds,
+        ..
+    } = SwapBody::new(&data.fields, Some((&ctx, ctx_ty)), access)?;
+
+    let ident = &input.ident;
+    let generics = bounded_generics(&input.generics, &container, bounds);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::lilbig::FieldsByteOrderedWith<#ctx_ty> for #ident #ty_generics
+            #where_clause
+        {
+            #[inline]
+            fn swap_field_orders_with(&mut self, #ctx: #ctx_ty) {
+                #(#captures)*
+                #(#swaps)*
+                #(#limited_swaps)*
+                let _ = #ctx;
+            }
+        }
+    })
+}
+
+/// Derive `SwapLayout` for a struct by concatenating the tables of each of its fields, offset by
+/// the field's position within the struct.
+///
+/// Every field of the struct must itself implement `SwapLayout`, and the struct must implement
+/// `FieldsByteOrdered`. The implementations for generic structs are bounded as they are by
+/// `#[derive(FieldsByteOrdered)]`, and the `bound` struct attribute is likewise supported.
+/// `#[repr(packed)]` structs are supported. Enums and unions are rejected.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::layout::{FieldLayout, SwapLayout};
+/// use lilbig::FieldsByteOrdered;
+///
+/// #[derive(FieldsByteOrdered, SwapLayout)]
+/// #[repr(C)]
+/// struct Extent {
+///     start: u32,
+///     length: u16,
+///     flags: [u8; 2],
+/// }
+///
+/// let fields = Extent::swap_fields().collect::<Vec<_>>();
+/// assert_eq!(
+///     fields,
+///     [
+///         FieldLayout::new(0, 4),
+///         FieldLayout::new(4, 2),
+///         FieldLayout::new(6, 1),
+///         FieldLayout::new(7, 1),
+///     ],
+/// );
+/// ```
+#[proc_macro_derive(SwapLayout, attributes(lilbig))]
+pub fn derive_swap_layout(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_swap_layout(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expand the `SwapLayout` derive for `input`.
+fn expand_swap_layout(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`SwapLayout` may only be derived for structs",
+        ));
+    };
+    let container = ContainerAttrs::parse(input)?;
+
+    // Padding, skipped, and pinned fields are not swapped, and are thus absent from the table.
+    let mut swapped = Vec::new();
+    for (field, member) in data.fields.iter().zip(members(&data.fields)) {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.len_field.is_some() || attrs.tag.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`SwapLayout` may not be derived for structs with `len_field` or `tag` fields, as \
+                 their tables depend on the values of the fields",
+            ));
+        }
+        if attrs.is_gated() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`SwapLayout` may not be derived for structs with `since` or `until` fields, as \
+                 their tables depend on a context",
+            ));
+        }
+        if attrs.with.is_some() || attrs.preserve_bits.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`SwapLayout` may not be derived for structs with `with` or `preserve_bits` \
+                 fields, as their tables are unknown",
+            ));
+        }
+        if !attrs.padding && !attrs.skip && attrs.order.is_none() {
+            swapped.push((member, &field.ty));
+        }
+    }
+
+    let tys = swapped.iter().map(|(_, ty)| ty).collect::<Vec<_>>();
+    let bounds = tys
+        .iter()
+        .map(|&&ty| (ty.clone(), quote!(::lilbig::layout::SwapLayout)))
+        .collect();
+    let lookups = swapped.iter().map(|(member, ty)| {
+        quote! {
+            if index < <#ty as ::lilbig::layout::SwapLayout>::SWAP_FIELD_COUNT {
+                return <#ty as ::lilbig::layout::SwapLayout>::swap_field(index).map(|field| {
+                    field.offset_by(::core::mem::offset_of!(Self, #member))
+                });
+            }
+            let index = index - <#ty as ::lilbig::layout::SwapLayout>::SWAP_FIELD_COUNT;
+        }
+    });
+
+    let ident = &input.ident;
+    let generics = bounded_generics(&input.generics, &container, bounds);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::lilbig::layout::SwapLayout for #ident #ty_generics #where_clause {
+            const SWAP_FIELD_COUNT: usize =
+                0 #(+ <#tys as ::lilbig::layout::SwapLayout>::SWAP_FIELD_COUNT)*;
+
+            fn swap_field(
+                index: usize,
+            ) -> ::core::option::Option<::lilbig::layout::FieldLayout> {
+                #(#lookups)*
+                let _ = index;
+                ::core::option::Option::None
+            }
+        }
+    })
+}
+
+/// Derive `SwapPermutation` for a struct by applying the permutation of each of its fields, offset
+/// by the field's position within the struct, to the bytes of the struct.
+///
+/// Every field of the struct must itself implement `SwapPermutation` or be an array of such a
+/// type, and the struct must implement `FieldsByteOrdered` and `WireSafe`. The permutation is
+/// computed at compile time. Enums, unions, and generic structs are rejected.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::layout::{self, SwapPermutation};
+/// use lilbig::{FieldsByteOrdered, WireSafe};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, FieldsByteOrdered, SwapPermutation)]
+/// #[repr(C)]
+/// struct Extent {
+///     start: u32,
+///     length: u16,
+///     flags: [u8; 2],
+/// }
+///
+/// unsafe impl WireSafe for Extent {}
+///
+/// assert_eq!(Extent::SWAP_PERMUTATION, [3, 2, 1, 0, 5, 4, 6, 7]);
+///
+/// let extent = Extent { start: 0x01020304, length: 0x0506, flags: [7, 8] };
+/// let mut extents = [extent; 3];
+/// layout::swap_permuted(&mut extents);
+/// assert_eq!(extents, [Extent { start: 0x04030201, length: 0x0605, flags: [7, 8] }; 3]);
+/// ```
+#[proc_macro_derive(SwapPermutation)]
+pub fn derive_swap_permutation(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_swap_permutation(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expand the `SwapPermutation` derive for `input`.
+fn expand_swap_permutation(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`SwapPermutation` may only be derived for structs",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`SwapPermutation` may not be derived for generic structs, as their permutations are \
+             sized by the struct",
+        ));
+    }
+
+    // Padding, skipped, and pinned fields are not swapped, and thus keep the identity permutation.
+    let ident = &input.ident;
+    let mut permutes = Vec::new();
+    for (field, member) in data.fields.iter().zip(members(&data.fields)) {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.len_field.is_some() || attrs.tag.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`SwapPermutation` may not be derived for structs with `len_field` or `tag` \
+                 fields, as their permutations depend on the values of the fields",
+            ));
+        }
+        if attrs.is_gated() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`SwapPermutation` may not be derived for structs with `since` or `until` \
+                 fields, as their permutations depend on a context",
+            ));
+        }
+        if attrs.with.is_some() || attrs.preserve_bits.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`SwapPermutation` may not be derived for structs with `with` or `preserve_bits` \
+                 fields, as their permutations are unknown",
+            ));
+        }
+        if !attrs.padding && !attrs.skip && attrs.order.is_none() {
+            permutes.push(permute_type(
+                &field.ty,
+                quote!(::core::mem::offset_of!(#ident, #member)),
+            ));
+        }
+    }
+    Ok(quote! {
+        #[automatically_derived]
+        impl ::lilbig::layout::SwapPermutation for #ident {
+            const SWAP_PERMUTATION: &'static [usize] = &{
+                let mut permutation = ::lilbig::layout::identity_permutation::<
+                    { ::core::mem::size_of::<#ident>() },
+                >();
+                #(#permutes)*
+                permutation
+            };
+        }
+    })
+}
+
+/// Generate the statements applying the permutation of a field of type `ty` at `offset` to the
+/// permutation of the struct containing it, expanding arrays into the permutations of their
+/// elements.
+fn permute_type(ty: &Type, offset: TokenStream2) -> TokenStream2 {
+    match ty {
+        Type::Array(array) => {
+            let elem = &array.elem;
+            let len = &array.len;
+            let permute = permute_type(elem, quote!(offset));
+            quote! {{
+                let mut element = 0;
+                while element < #len {
+                    let offset = #offset + element * ::core::mem::size_of::<#elem>();
+                    #permute
+                    element += 1;
+                }
+            }}
+        }
+        Type::Group(group) => permute_type(&group.elem, offset),
+        Type::Paren(paren) => permute_type(&paren.elem, offset),
+        _ => quote! {
+            ::lilbig::layout::permute_field(
+                &mut permutation,
+                #offset,
+                <#ty as ::lilbig::layout::SwapPermutation>::SWAP_PERMUTATION,
+            );
+        },
+    }
+}
+
+/// Derive `SwapSubset` for a struct by swapping each of its fields individually by the index at
+/// which it is declared.
+///
+/// The struct must implement `FieldsByteOrdered`, and its fields are swapped as they are by
+/// `#[derive(FieldsByteOrdered)]`, such that fields annotated with `#[lilbig(padding)]`,
+/// `#[lilbig(skip)]`, or `#[lilbig(order = "...")]` are left unmodified while those annotated with
+/// `#[lilbig(with = "path")]` or `#[lilbig(preserve_bits = MASK)]` are swapped accordingly. Fields
+/// annotated with `len_field`, `tag`, `ctx`, `since`, or `until` depend on other fields or on a
+/// context, and are rejected. The implementations for generic structs are bounded as they are by
+/// `#[derive(FieldsByteOrdered)]`, and the `bound` struct attribute is likewise supported.
+/// `#[repr(packed)]` structs are supported. Enums and unions are rejected.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::subset::SwapSubset;
+/// use lilbig::FieldsByteOrdered;
+///
+/// #[derive(FieldsByteOrdered, SwapSubset)]
+/// struct Stamp(u16, #[lilbig(padding)] [u8; 2], u32);
+///
+/// let mut stamp = Stamp(0x0102, [3, 4], 0x05060708);
+/// stamp.swap_fields_at(&[1, 2]);
+/// assert_eq!(stamp.0, 0x0102);
+/// assert_eq!(stamp.1, [3, 4]);
+/// assert_eq!(stamp.2, 0x08070605);
+/// assert_eq!(Stamp::FIELD_NAMES, ["0", "1", "2"]);
+/// ```
+#[proc_macro_derive(SwapSubset, attributes(lilbig))]
+pub fn derive_swap_subset(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_swap_subset(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expand the `SwapSubset` derive for `input`.
+fn expand_swap_subset(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`SwapSubset` may only be derived for structs",
+        ));
+    };
+    let access = if is_packed(input)? {
+        Access::Packed
+    } else {
+        Access::Field
+    };
+    let container = ContainerAttrs::parse(input)?;
+
+    let mut names = Vec::new();
+    let mut arms = Vec::new();
+    for (index, (field, member)) in data.fields.iter().zip(members(&data.fields)).enumerate() {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.len_field.is_some() || attrs.tag.is_some() || attrs.ctx || attrs.is_gated() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`SwapSubset` may not be derived for structs with `len_field`, `tag`, `ctx`, \
+                 `since`, or `until` fields, as their swaps depend on other fields or on a context",
+            ));
+        }
+        names.push(match &member {
+            Member::Named(ident) => ident.unraw().to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        });
+        let swap = if attrs.padding || attrs.skip || attrs.order.is_some() {
+            quote!()
+        } else if let Some(with) = &attrs.with {
+            access_field(&member, access, quote!(#with(__lilbig_field);))
+        } else if let Some(mask) = &attrs.preserve_bits {
+            access_field(
+                &member,
+                access,
+                quote!(::lilbig::masked::swap_preserving_bits(__lilbig_field, #mask);),
+            )
+        } else {
+            access_field(
+                &member,
+                access,
+                quote!(::lilbig::FieldsByteOrdered::swap_field_orders(__lilbig_field);),
+            )
+        };
+        arms.push(quote!(#index => { #swap true }));
+    }
+    // The bounds are those of the `FieldsByteOrdered` implementation, which every swapped field
+    // requires.
+    let SwapBody { bounds, .. } = SwapBody::new(&data.fields, None, access)?;
+
+    let ident = &input.ident;
+    let generics = bounded_generics(&input.generics, &container, bounds);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::lilbig::subset::SwapSubset for #ident #ty_generics #where_clause {
+            const FIELD_NAMES: &'static [&'static ::core::primitive::str] = &[#(#names),*];
+
+            #[inline]
+            fn swap_field_order(&mut self, index: ::core::primitive::usize) -> bool {
+                match index {
+                    #(#arms)*
+                    _ => false,
+                }
+            }
+        }
+    })
+}
+
+/// Derive `ReprOrdered` for a fieldless enum by converting it to and from the integer given by its
+/// `#[repr]` attribute.
+///
+/// The enum must have an explicit representation of a fixed-width integer, such as `#[repr(u32)]`,
+/// and each of its variants must be fieldless. Integers are converted to the enum by matching them
+/// against the discriminants of its variants. Structs, unions, and generic enums are rejected.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::repr::{OrderedRepr, ReprOrdered};
+/// use lilbig::FieldsByteOrdered;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, ReprOrdered)]
+/// #[repr(u16)]
+/// enum Opcode {
+///     Read = 1,
+///     Write,
+///     Ack = 4,
+/// }
+///
+/// assert_eq!(Opcode::Write.to_repr(), 2);
+/// assert_eq!(Opcode::from_repr(4), Some(Opcode::Ack));
+/// assert_eq!(Opcode::from_repr(3), None);
+///
+/// let mut opcode = OrderedRepr::new(Opcode::Read);
+/// opcode.swap_field_orders();
+/// assert_eq!(opcode.repr(), 0x0100);
+/// ```
+#[proc_macro_derive(ReprOrdered)]
+pub fn derive_repr_ordered(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_repr_ordered(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expand the `ReprOrdered` derive for `input`.
+fn expand_repr_ordered(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`ReprOrdered` may only be derived for enums",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`ReprOrdered` may not be derived for generic enums",
+        ));
+    }
+    let Some(repr) = repr_int(input)? else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`ReprOrdered` requires the enum to be annotated with the representation of a \
+             fixed-width integer, such as `#[repr(u32)]`",
+        ));
+    };
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "`ReprOrdered` may only be derived for enums whose variants are fieldless",
+        ));
+    }
+
+    let ident = &input.ident;
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| &variant.ident)
+        .collect::<Vec<_>>();
+    let discriminants = (0..variants.len())
+        .map(|index| quote::format_ident!("__LILBIG_DISCRIMINANT_{}", index))
+        .collect::<Vec<_>>();
+    Ok(quote! {
+        #[automatically_derived]
+        impl ::lilbig::repr::ReprOrdered for #ident {
+            type Repr = #repr;
+
+            #[inline]
+            fn to_repr(self) -> #repr {
+                self as #repr
+            }
+
+            #[inline]
+            fn from_repr(repr: #repr) -> ::core::option::Option<Self> {
+                #(const #discriminants: #repr = #ident::#variants as #repr;)*
+                match repr {
+                    #(#discriminants => ::core::option::Option::Some(Self::#variants),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    })
+}
+
+/// Derive `OrderedView` for a struct by generating views that decode and encode each of its fields
+/// within a buffer of a runtime byte-order.
+///
+/// For a struct `Foo`, the views `FooView<'a>` over a `&'a [u8]` and `FooViewMut<'a>` over a
+/// `&'a mut [u8]` are generated with the visibility of the struct. Each view provides a getter
+/// prefixed with `get_` for each field, which decodes the field at its offset within the struct,
+/// and the mutable view additionally provides a setter prefixed with `set_`, which encodes the
+/// field. The prefixes keep the accessors apart from the views' own methods, such that fields may
+/// be named `order` or `as_bytes`. The getters and setters have the visibility of their fields.
+/// Fields pinned by `#[lilbig(order = "...")]` are decoded and encoded in their pinned byte-order
+/// rather than that of the view.
+///
+/// The struct must be `#[repr(C)]` such that its layout matches that of the buffer, and must have
+/// named fields, each of which must implement both `FieldsByteOrdered` and `WireSafe`. Fields
+/// annotated with `#[lilbig(padding)]` or `#[lilbig(skip)]` have no accessors, while the other
+/// field attributes are rejected. Enums, unions, tuple structs, and generic structs are rejected.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::view::OrderedView;
+/// use lilbig::ByteOrder;
+///
+/// #[derive(OrderedView)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: [u8; 4],
+///     version: u16,
+///     #[lilbig(padding)]
+///     reserved: [u8; 2],
+///     length: u64,
+/// }
+///
+/// let mut bytes = [0u8; 16];
+/// bytes[..4].copy_from_slice(b"LILB");
+/// bytes[4] = 3;
+///
+/// let mut header = Header::view_mut(&mut bytes, ByteOrder::Le).unwrap();
+/// assert_eq!(header.get_magic(), *b"LILB");
+/// assert_eq!(header.get_version(), 3);
+/// header.set_length(0x0102);
+/// assert_eq!(header.as_view().get_length(), 0x0102);
+/// assert_eq!(bytes[8..10], [0x02, 0x01]);
+/// ```
+///
+/// Fields sharing their names with the methods of the views:
+/// ```
+/// use lilbig::view::OrderedView;
+/// use lilbig::ByteOrder;
+///
+/// #[derive(OrderedView)]
+/// #[repr(C)]
+/// struct Trade {
+///     order: u32,
+///     as_bytes: u16,
+///     as_view: u16,
+/// }
+///
+/// let mut bytes = [0x00, 0x00, 0x00, 0x2a, 0x00, 0x01, 0x00, 0x02];
+/// let mut trade = Trade::view_mut(&mut bytes, ByteOrder::Be).unwrap();
+/// assert_eq!(trade.order(), ByteOrder::Be);
+/// assert_eq!(trade.get_order(), 42);
+/// trade.set_order(7);
+/// assert_eq!(trade.as_view().get_as_bytes(), 1);
+/// assert_eq!(trade.get_as_view(), 2);
+/// assert_eq!(trade.as_view().as_bytes()[..4], [0x00, 0x00, 0x00, 0x07]);
+/// ```
+#[proc_macro_derive(OrderedView, attributes(lilbig))]
+pub fn derive_ordered_view(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_ordered_view(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expand the `OrderedView` derive for `input`.
+fn expand_ordered_view(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(syn::DataStruct {
+        fields: Fields::Named(fields),
+        ..
+    }) = &input.data
+    else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`OrderedView` may only be derived for structs with named fields",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`OrderedView` may not be derived for generic structs",
+        ));
+    }
+    if !reprs(input)?.iter().any(|repr| repr == "C") {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`OrderedView` requires the struct to be `#[repr(C)]`",
+        ));
+    }
+
+    let ident = &input.ident;
+    let mut getters = Vec::new();
+    let mut setters = Vec::new();
+    for field in &fields.named {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.padding || attrs.skip {
+            continue;
+        }
+        if attrs.len_field.is_some()
+            || attrs.tag.is_some()
+            || attrs.with.is_some()
+            || attrs.ctx
+            || attrs.preserve_bits.is_some()
+            || attrs.is_gated()
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`OrderedView` does not support `len_field`, `tag`, `with`, `ctx`, \
+                 `preserve_bits`, `since`, or `until` fields",
+            ));
+        }
+        let vis = &field.vis;
+        let ty = &field.ty;
+        let Some(name) = &field.ident else {
+            continue;
+        };
+        let order = match &attrs.order {
+            Some(order) => quote!(::lilbig::ByteOrder::#order),
+            None => quote!(self.order),
+        };
+        let getter = quote::format_ident!("get_{}", name.unraw());
+        let setter = quote::format_ident!("set_{}", name.unraw());
+        let getter_doc = format!("Decode the `{}` field of the viewed struct.", name.unraw());
+        let setter_doc = format!(
+            "Encode `value` as the `{}` field of the viewed struct.",
+            name.unraw(),
+        );
+        getters.push(quote! {
+            #[doc = #getter_doc]
+            #[inline]
+            #[must_use]
+            #vis fn #getter(&self) -> #ty {
+                ::lilbig::view::read_field(
+                    self.bytes,
+                    ::core::mem::offset_of!(#ident, #name),
+                    #order,
+                )
+                .unwrap_or_else(::lilbig::WireSafe::zeroed)
+            }
+        });
+        setters.push(quote! {
+            #[doc = #setter_doc]
+            #[inline]
+            #vis fn #setter(&mut self, value: #ty) {
+                let _ = ::lilbig::view::write_field(
+                    self.bytes,
+                    ::core::mem::offset_of!(#ident, #name),
+                    value,
+                    #order,
+                );
+            }
+        });
+    }
+
+    let vis = &input.vis;
+    let view = quote::format_ident!("{}View", ident);
+    let view_mut = quote::format_ident!("{}ViewMut", ident);
+    let view_doc = format!("View decoding the fields of [`{ident}`] from a borrowed buffer.");
+    let view_mut_doc = format!(
+        "View decoding and encoding the fields of [`{ident}`] within a mutably borrowed buffer.",
+    );
+    // The length of the buffer is checked upon creating a view, so the accessors never fail.
+    Ok(quote! {
+        #[doc = #view_doc]
+        #[derive(Debug, Clone, Copy)]
+        #vis struct #view<'a> {
+            bytes: &'a [u8],
+            order: ::lilbig::ByteOrder,
+        }
+
+        impl<'a> #view<'a> {
+            /// Retrieve the byte-order of the viewed struct.
+            #[inline]
+            #[must_use]
+            #vis fn order(&self) -> ::lilbig::ByteOrder {
+                self.order
+            }
+
+            /// Retrieve the bytes of the viewed struct.
+            #[inline]
+            #[must_use]
+            #vis fn as_bytes(&self) -> &'a [u8] {
+                self.bytes
+            }
+
+            #(#getters)*
+        }
+
+        #[doc = #view_mut_doc]
+        #[derive(Debug)]
+        #vis struct #view_mut<'a> {
+            bytes: &'a mut [u8],
+            order: ::lilbig::ByteOrder,
+        }
+
+        impl<'a> #view_mut<'a> {
+            /// Retrieve the byte-order of the viewed struct.
+            #[inline]
+            #[must_use]
+            #vis fn order(&self) -> ::lilbig::ByteOrder {
+                self.order
+            }
+
+            /// Reborrow the view as an immutable view.
+            #[inline]
+            #[must_use]
+            #vis fn as_view(&self) -> #view<'_> {
+                #view {
+                    bytes: self.bytes,
+                    order: self.order,
+                }
+            }
+
+            #(#getters)*
+            #(#setters)*
+        }
+
+        #[automatically_derived]
+        impl ::lilbig::view::OrderedView for #ident {
+            type View<'a> = #view<'a>;
+            type ViewMut<'a> = #view_mut<'a>;
+
+            #[inline]
+            fn view(
+                bytes: &[u8],
+                order: ::lilbig::ByteOrder,
+            ) -> ::core::option::Option<Self::View<'_>> {
+                let bytes = bytes.get(..::core::mem::size_of::<Self>())?;
+                ::core::option::Option::Some(#view { bytes, order })
+            }
+
+            #[inline]
+            fn view_mut(
+                bytes: &mut [u8],
+                order: ::lilbig::ByteOrder,
+            ) -> ::core::option::Option<Self::ViewMut<'_>> {
+                let bytes = bytes.get_mut(..::core::mem::size_of::<Self>())?;
+                ::core::option::Option::Some(#view_mut { bytes, order })
+            }
+        }
+    })
+}
+
+/// How the statements generated by [`SwapBody`] access the fields they swap.
+#[derive(Clone, Copy)]
+enum Access {
+    /// Fields are accessed through `self`.
+    Field,
+    /// Fields of a `#[repr(packed)]` struct are accessed through `self` by unaligned copies.
+    Packed,
+    /// Fields of an enum variant are accessed through the mutable references bound to them by
+    /// [`variant_pattern()`].
+    Binding,
+}
+
+/// Statements swapping the byte-order of the fields of a struct or enum variant.
+struct SwapBody {
+    /// Statements capturing the counts read before any field is swapped.
+    captures: Vec<TokenStream2>,
+    /// Statements swapping the fields that are not limited by a count.
+    swaps: Vec<TokenStream2>,
+    /// Statements swapping the fields that are limited by a count or selected by a tag, which
+    /// follow every other swap such that the counts and tags have been swapped.
+    limited_swaps: Vec<TokenStream2>,
+    /// Expressions evaluating whether each field is order invariant.
+    invariants: Vec<TokenStream2>,
+    /// Statements zeroing the padding fields and the padding nested within the other fields.
+    zero_padding: Vec<TokenStream2>,
+    /// Types of the fields along with the traits required of them.
+    bounds: Vec<(Type, TokenStream2)>,
+    /// Statements converting the pinned fields and the pinned fields nested within the other
+    /// fields.
+    pinned_swaps: Vec<TokenStream2>,
+    /// Expressions evaluating whether each field is or holds a pinned field.
+    pinned: Vec<TokenStream2>,
+}
+
+impl SwapBody {
+    /// Generate the statements swapping the byte-order of `fields`, passing the context `ctx`, if
+    /// any, to the swaps of fields annotated with `#[lilbig(ctx)]`. The context is given along with
+    /// its type, and the fields are accessed as given by `access`.
+    fn new(fields: &Fields, ctx: Option<(&Ident, &Type)>, access: Access) -> syn::Result<Self> {
+        let mut body = Self {
+            captures: Vec::new(),
+            swaps: Vec::new(),
+            limited_swaps: Vec::new(),
+            invariants: Vec::new(),
+            zero_padding: Vec::new(),
+            bounds: Vec::new(),
+            pinned_swaps: Vec::new(),
+            pinned: Vec::new(),
+        };
+        for (index, (field, member)) in fields.iter().zip(members(fields)).enumerate() {
+            let attrs = FieldAttrs::parse(field)?;
+            if attrs.skip {
+                continue;
+            }
+            let ty = &field.ty;
+            if attrs.padding || attrs.tag.is_some() {
+                body.bounds.push((ty.clone(), quote!(::lilbig::WireSafe)));
+            } else if attrs.preserve_bits.is_some() {
+                body.bounds.push((
+                    ty.clone(),
+                    quote!(::lilbig::FieldsByteOrdered + ::lilbig::WireSafe),
+                ));
+            } else if attrs.len_field.is_none() && attrs.with.is_none() {
+                match (attrs.ctx, ctx) {
+                    (true, Some((_, ctx_ty))) => body
+                        .bounds
+                        .push((ty.clone(), quote!(::lilbig::FieldsByteOrderedWith<#ctx_ty>))),
+                    _ => body
+                        .bounds
+                        .push((ty.clone(), quote!(::lilbig::FieldsByteOrdered))),
+                }
+            }
+            if attrs.padding {
+                let place = field_place(&member, access);
+                body.zero_padding.push(quote! {
+                    #place = ::lilbig::WireSafe::zeroed();
+                });
+                continue;
+            }
+            if attrs.tag.is_none() && !attrs.ctx && attrs.with.is_none() {
+                body.zero_padding.push(access_field(
+                    &member,
+                    access,
+                    quote!(::lilbig::FieldsByteOrdered::zero_padding(__lilbig_field);),
+                ));
+            }
+            // Fields gated on the context are only swapped while the context lies within their
+            // range.
+            let gate =
+                match ctx {
+                    Some((ctx, _)) => {
+                        let since = attrs
+                            .since
+                            .iter()
+                            .map(|since| quote!(::core::cmp::PartialOrd::ge(&#ctx, &(#since))));
+                        let until = attrs
+                            .until
+                            .iter()
+                            .map(|until| quote!(::core::cmp::PartialOrd::lt(&#ctx, &(#until))));
+                        quote!(true #(&& #since)* #(&& #until)*)
+                    }
+                    None if attrs.is_gated() => return Err(syn::Error::new_spanned(
+                        field,
+                        "`since` and `until` fields are only supported by `FieldsByteOrderedWith`",
+                    )),
+                    None => quote!(true),
+                };
+            let gated = |swap: TokenStream2| {
+                if attrs.is_gated() {
+                    quote! {
+                        if #gate {
+                            #swap
+                        }
+                    }
+                } else {
+                    swap
+                }
+            };
+            let swap_ungated = |place: TokenStream2| match (&attrs.with, attrs.ctx, ctx) {
+                (Some(with), _, _) => Ok(quote! {
+                    #with(#place);
+                }),
+                (None, _, _) if attrs.preserve_bits.is_some() => {
+                    let mask = &attrs.preserve_bits;
+                    Ok(quote! {
+                        ::lilbig::masked::swap_preserving_bits(#place, #mask);
+                    })
+                }
+                (None, true, Some((ctx, _))) => Ok(quote! {
+                    ::lilbig::FieldsByteOrderedWith::swap_field_orders_with(
+                        #place,
+                        ::core::clone::Clone::clone(&#ctx),
+                    );
+                }),
+                (None, true, None) => Err(syn::Error::new_spanned(
+                    field,
+                    "`ctx` fields are only supported by `FieldsByteOrderedWith`",
+                )),
+                (None, false, _) => Ok(quote! {
+                    ::lilbig::FieldsByteOrdered::swap_field_orders(#place);
+                }),
+            };
+            let swap = |place: TokenStream2| swap_ungated(place).map(gated);
+
+            if let Some((tag, arms)) = &attrs.tag {
+                if attrs.ctx {
+                    return Err(syn::Error::new_spanned(
+                        field,
+                        "`ctx` and `tag` may not be combined",
+                    ));
+                }
+                // Fields selected by a tag are conservatively assumed to vary with byte-order.
+                body.invariants.push(quote!(false));
+                check_sibling(fields, &tag.member, "tag")?;
+                let tag_place = field_place(&tag.member, access);
+                let value = quote::format_ident!("__lilbig_tag_{}", index);
+                let read_tag = quote! {
+                    let #value = #tag_place;
+                };
+                if tag.before {
+                    body.captures.push(read_tag);
+                } else {
+                    body.limited_swaps.push(read_tag);
+                }
+                let ty = &field.ty;
+                let arms = arms.iter().map(|TagArm { pat, ty: overlay }| {
+                    quote! {
+                        #pat => {
+                            const {
+                                ::core::assert!(
+                                    ::core::mem::size_of::<#overlay>()
+                                        <= ::core::mem::size_of::<#ty>(),
+                                    "overlay is larger than the field containing it",
+                                );
+                            }
+                            let _ = ::lilbig::overlay::swap_overlay::<#overlay>(bytes);
+                        }
+                    }
+                });
+                body.limited_swaps.push(gated(access_field(
+                    &member,
+                    access,
+                    quote! {
+                        let bytes = ::lilbig::WireSafe::as_bytes_mut(__lilbig_field);
+                        #[allow(unreachable_patterns)]
+                        match #value {
+                            #(#arms)*
+                            _ => {}
+                        }
+                    },
+                )));
+                continue;
+            }
+
+            if let Some(order) = &attrs.order {
+                if ctx.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        field,
+                        "`order` fields are only supported by `FieldsByteOrdered`",
+                    ));
+                }
+                // Pinned fields are left unmodified by swaps, and are instead converted between
+                // their pinned byte-order and the machine's native byte-order.
+                body.pinned_swaps.push(access_field(
+                    &member,
+                    access,
+                    quote! {
+                        ::lilbig::FieldsByteOrdered::order_fields_ne(
+                            __lilbig_field,
+                            ::lilbig::ByteOrder::#order,
+                        );
+                    },
+                ));
+                body.pinned.push(quote!(true));
+                continue;
+            }
+
+            let Some(len_field) = &attrs.len_field else {
+                let swap = access_field(&member, access, swap(quote!(__lilbig_field))?);
+                if attrs.ctx || attrs.with.is_some() {
+                    body.swaps.push(swap);
+                    body.invariants.push(quote!(false));
+                    continue;
+                }
+                let pinned_swap = access_field(
+                    &member,
+                    access,
+                    quote!(::lilbig::FieldsByteOrdered::swap_pinned_field_orders(__lilbig_field);),
+                );
+                body.pinned_swaps.push(quote! {
+                    if <#ty as ::lilbig::FieldsByteOrdered>::HAS_PINNED_FIELDS {
+                        #pinned_swap
+                    }
+                });
+                body.pinned.push(quote! {
+                    <#ty as ::lilbig::FieldsByteOrdered>::HAS_PINNED_FIELDS
+                });
+                // Swaps of order invariant fields are elided at compile time.
+                body.swaps.push(quote! {
+                    if !<#ty as ::lilbig::FieldsByteOrdered>::IS_ORDER_INVARIANT {
+                        #swap
+                    }
+                });
+                body.invariants.push(quote! {
+                    <#ty as ::lilbig::FieldsByteOrdered>::IS_ORDER_INVARIANT
+                });
+                continue;
+            };
+            // Fields limited by a count are conservatively assumed to vary with byte-order.
+            body.invariants.push(quote!(false));
+            check_sibling(fields, &len_field.member, "len_field")?;
+            let count = field_place(&len_field.member, access);
+            let len = quote::format_ident!("__lilbig_len_{}", index);
+            let read_len = quote! {
+                let #len: ::core::primitive::usize = {
+                    let __lilbig_count = #count;
+                    if __lilbig_count < ::core::default::Default::default() {
+                        0
+                    } else {
+                        ::core::convert::TryFrom::try_from(__lilbig_count)
+                            .unwrap_or(::core::primitive::usize::MAX)
+                    }
+                };
+            };
+            if len_field.before {
+                body.captures.push(read_len);
+            } else {
+                body.limited_swaps.push(read_len);
+            }
+            let swap = swap(quote!(element))?;
+            body.limited_swaps.push(access_field(
+                &member,
+                access,
+                quote! {
+                    let elements = ::core::iter::IntoIterator::into_iter(__lilbig_field);
+                    for element in ::core::iter::Iterator::take(elements, #len) {
+                        #swap
+                    }
+                },
+            ));
+        }
+        Ok(body)
+    }
+}
+
+/// Retrieve the ident to which [`variant_pattern()`] binds the field `member`.
+fn field_binding(member: &Member) -> Ident {
+    match member {
+        Member::Named(ident) => quote::format_ident!("__lilbig_{}", ident.unraw()),
+        Member::Unnamed(index) => quote::format_ident!("__lilbig_{}", index.index),
+    }
+}
+
+/// Generate a pattern matching the variant `variant` of `Self`, binding a mutable reference to each
+/// of its fields.
+fn variant_pattern(variant: &Variant) -> TokenStream2 {
+    let ident = &variant.ident;
+    let members = members(&variant.fields).collect::<Vec<_>>();
+    let bindings = members.iter().map(field_binding);
+    quote!(Self::#ident { #(#members: ref mut #bindings,)* })
+}
+
+/// Generate the place expression of the field `member` accessed as given by `access`.
+///
+/// The place may be read from and assigned to, but not referenced when `access` is
+/// [`Access::Packed`].
+fn field_place(member: &Member, access: Access) -> TokenStream2 {
+    match access {
+        Access::Field | Access::Packed => quote!(self.#member),
+        Access::Binding => {
+            let binding = field_binding(member);
+            quote!((*#binding))
+        }
+    }
+}
+
+/// Wrap `body` such that it is given a mutable reference to the field `member` as
+/// `__lilbig_field`, accessing the field as given by `access`.
+///
+/// References may not be taken to the fields of `#[repr(packed)]` structs, so the field is instead
+/// copied out through an unaligned read, modified, and copied back through an unaligned write. The
+/// copy is held by `ManuallyDrop` such that the field keeps sole ownership of its value should
+/// `body` panic.
+fn access_field(member: &Member, access: Access, body: TokenStream2) -> TokenStream2 {
+    if !matches!(access, Access::Packed) {
+        let place = field_place(member, access);
+        return quote! {
+            {
+                let __lilbig_field = &mut #place;
+                #body
+            }
+        };
+    }
+    quote! {
+        {
+            let place = ::core::ptr::addr_of_mut!(self.#member);
+            // SAFETY: `place` is derived from a mutable reference to `self` and is thus valid for
+            // unaligned reads and writes. The copy is written back before `self` is next accessed,
+            // and is never dropped.
+            let mut value =
+                ::core::mem::ManuallyDrop::new(unsafe { ::core::ptr::read_unaligned(place) });
+            {
+                let __lilbig_field = &mut *value;
+                #body
+            }
+            unsafe {
+                ::core::ptr::write_unaligned(place, ::core::mem::ManuallyDrop::into_inner(value));
+            }
+        }
+    }
+}
+
+/// Options of a struct given by its `#[lilbig(...)]` attributes.
+#[derive(Default)]
+struct ContainerAttrs {
+    /// The type of the context passed to the swaps of the struct's fields.
+    ctx: Option<Type>,
+    /// The where predicates replacing the inferred bounds of the derived implementations.
+    bound: Option<Vec<WherePredicate>>,
+    /// The selection of the active field of a union.
+    active: Option<Active>,
+    /// The name of the wire twin of the struct.
+    twin: Option<Ident>,
+    /// The wrapper of the fields of the wire twin, either `Le` or `Be`.
+    twin_order: Option<Ident>,
+}
+
+/// Selection of the active field of a union.
+enum Active {
+    /// The named field is always active.
+    Field(Ident),
+    /// The field of the arm matching the value returned by the selector is active.
+    Selector(syn::Expr, Vec<ActiveArm>),
+}
+
+/// Arm of a `match(...)` attribute selecting the active field of a union.
+struct ActiveArm {
+    /// Pattern matching the values returned by the selector.
+    pat: Pat,
+    /// The active field.
+    field: Ident,
+}
+
+impl Parse for ActiveArm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pat = Pat::parse_multi(input)?;
+        input.parse::<Token![=>]>()?;
+        let field = input.parse()?;
+        Ok(Self { pat, field })
+    }
+}
+
+impl ContainerAttrs {
+    /// Parse the `#[lilbig(...)]` attributes of `input`.
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+        let mut active = None;
+        let mut arms = None;
+        for attr in &input.attrs {
+            if !attr.path().is_ident("lilbig") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("ctx") {
+                    attrs.ctx = Some(meta.value()?.parse::<LitStr>()?.parse::<Type>()?);
+                    Ok(())
+                } else if meta.path.is_ident("bound") {
+                    let predicates = meta
+                        .value()?
+                        .parse::<LitStr>()?
+                        .parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+                    attrs.bound = Some(predicates.into_iter().collect());
+                    Ok(())
+                } else if meta.path.is_ident("twin") {
+                    attrs.twin = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("twin_order") {
+                    attrs.twin_order = Some(parse_order(&meta, "twin_order")?);
+                    Ok(())
+                } else if meta.path.is_ident("unsafe_active") {
+                    active = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else if meta.path.is_ident("match") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let parsed = Punctuated::<ActiveArm, Token![,]>::parse_terminated(&content)?;
+                    arms = Some(parsed.into_iter().collect());
+                    Ok(())
+                } else {
+                    Err(meta.error("unrecognized `lilbig` attribute"))
+                }
+            })?;
+        }
+        attrs.active = match (active, arms) {
+            (Some(active), Some(arms)) => Some(Active::Selector(active.parse()?, arms)),
+            (Some(active), None) => Some(Active::Field(active.parse()?)),
+            (None, Some(_)) => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "`match` requires `unsafe_active`",
+                ))
+            }
+            (None, None) => None,
+        };
+        Ok(attrs)
+    }
+}
+
+/// Options of a field given by its `#[lilbig(...)]` attributes.
+#[derive(Default)]
+struct FieldAttrs {
+    /// The field holding the number of elements of this field to swap.
+    len_field: Option<SiblingField>,
+    /// The field whose value selects the overlay of this field to swap.
+    tag: Option<(SiblingField, Vec<TagArm>)>,
+    /// Whether the context is passed to the swap of this field.
+    ctx: bool,
+    /// Whether the field is padding, which is zeroed rather than swapped.
+    padding: bool,
+    /// Whether the field is exempt from swapping.
+    skip: bool,
+    /// The function swapping the field in place of its `FieldsByteOrdered` implementation.
+    with: Option<syn::Path>,
+    /// The variant of `ByteOrder` to which the field is pinned.
+    order: Option<Ident>,
+    /// The mask of the bits of the field left unmodified by swaps.
+    preserve_bits: Option<syn::Expr>,
+    /// The least context with which the field is swapped.
+    since: Option<syn::Expr>,
+    /// The least context with which the field is no longer swapped.
+    until: Option<syn::Expr>,
+}
+
+/// Field of the same struct whose value determines how another field is swapped.
+struct SiblingField {
+    /// Member through which the field is accessed.
+    member: Member,
+    /// Whether the field is read before its byte-order is swapped.
+    before: bool,
+}
+
+/// Arm of a `match(...)` attribute selecting the overlay swapped for matching tags.
+struct TagArm {
+    /// Pattern matching the tags.
+    pat: Pat,
+    /// The type of the overlay.
+    ty: Type,
+}
+
+impl Parse for TagArm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pat = Pat::parse_multi(input)?;
+        input.parse::<Token![=>]>()?;
+        let ty = input.parse()?;
+        Ok(Self { pat, ty })
+    }
+}
+
+impl FieldAttrs {
+    /// Parse the `#[lilbig(...)]` attributes of `field`.
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut len_field = None;
+        let mut len_read = None;
+        let mut tag = None;
+        let mut tag_read = None;
+        let mut arms = None;
+        let mut ctx = false;
+        let mut padding = false;
+        let mut skip = false;
+        let mut with = None;
+        let mut order = None;
+        let mut preserve_bits = None;
+        let mut since = None;
+        let mut until = None;
+        for attr in &field.attrs {
+            if !attr.path().is_ident("lilbig") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("len_field") {
+                    len_field = Some(meta.value()?.parse::<LitStr>()?.parse::<Member>()?);
+                } else if meta.path.is_ident("len_read") {
+                    len_read = Some(parse_read(&meta, "len_read")?);
+                } else if meta.path.is_ident("tag") {
+                    tag = Some(meta.value()?.parse::<LitStr>()?.parse::<Member>()?);
+                } else if meta.path.is_ident("tag_read") {
+                    tag_read = Some(parse_read(&meta, "tag_read")?);
+                } else if meta.path.is_ident("match") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let parsed = Punctuated::<TagArm, Token![,]>::parse_terminated(&content)?;
+                    arms = Some(parsed.into_iter().collect::<Vec<_>>());
+                } else if meta.path.is_ident("ctx") {
+                    ctx = true;
+                } else if meta.path.is_ident("padding") {
+                    padding = true;
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                } else if meta.path.is_ident("with") {
+                    with = Some(meta.value()?.parse::<LitStr>()?.parse::<syn::Path>()?);
+                } else if meta.path.is_ident("order") {
+                    order = Some(parse_order(&meta, "order")?);
+                } else if meta.path.is_ident("preserve_bits") {
+                    preserve_bits = Some(meta.value()?.parse::<syn::Expr>()?);
+                } else if meta.path.is_ident("since") {
+                    since = Some(meta.value()?.parse::<LitStr>()?.parse::<syn::Expr>()?);
+                } else if meta.path.is_ident("until") {
+                    until = Some(meta.value()?.parse::<LitStr>()?.parse::<syn::Expr>()?);
+                } else {
+                    return Err(meta.error("unrecognized `lilbig` attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        let requires = |present: bool, attr: &str, required: &str| {
+            if present {
+                Err(syn::Error::new_spanned(
+                    field,
+                    format!("`{attr}` requires `{required}`"),
+                ))
+            } else {
+                Ok(())
+            }
+        };
+        requires(
+            len_read.is_some() && len_field.is_none(),
+            "len_read",
+            "len_field",
+        )?;
+        requires(tag_read.is_some() && tag.is_none(), "tag_read", "tag")?;
+        requires(arms.is_some() && tag.is_none(), "match", "tag")?;
+        requires(tag.is_some() && arms.is_none(), "tag", "match")?;
+        if len_field.is_some() && tag.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`len_field` and `tag` may not be combined",
+            ));
+        }
+        if with.is_some() && (tag.is_some() || ctx) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`with` may not be combined with `tag` or `ctx`",
+            ));
+        }
+        if padding && (len_field.is_some() || tag.is_some() || ctx || with.is_some()) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`padding` may not be combined with other attributes",
+            ));
+        }
+        if order.is_some()
+            && (len_field.is_some() || tag.is_some() || ctx || padding || with.is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`order` may not be combined with other attributes",
+            ));
+        }
+        if preserve_bits.is_some()
+            && (len_field.is_some()
+                || tag.is_some()
+                || ctx
+                || padding
+                || with.is_some()
+                || order.is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`preserve_bits` may not be combined with other attributes",
+            ));
+        }
+        if (since.is_some() || until.is_some()) && (padding || order.is_some()) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`since` and `until` may not be combined with `padding` or `order`",
+            ));
+        }
+        if skip
+            && (len_field.is_some()
+                || tag.is_some()
+                || ctx
+                || padding
+                || with.is_some()
+                || order.is_some()
+                || preserve_bits.is_some()
+                || since.is_some()
+                || until.is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`skip` may not be combined with other attributes",
+            ));
+        }
+        Ok(Self {
+            len_field: len_field.map(|member| SiblingField {
+                member,
+                before: len_read.unwrap_or(false),
+            }),
+            tag: tag.zip(arms).map(|(member, arms)| {
+                let tag = SiblingField {
+                    member,
+                    before: tag_read.unwrap_or(false),
+                };
+                (tag, arms)
+            }),
+            ctx,
+            padding,
+            skip,
+            with,
+            order,
+            preserve_bits,
+            since,
+            until,
+        })
+    }
+
+    /// Check whether the field is gated on the context by `since` or `until`.
+    fn is_gated(&self) -> bool {
+        self.since.is_some() || self.until.is_some()
+    }
+}
+
+/// Parse the value of the `name` attribute stating whether a sibling field is read before or after
+/// its byte-order is swapped, returning whether it is read before.
+fn parse_read(meta: &ParseNestedMeta, name: &str) -> syn::Result<bool> {
+    let value = meta.value()?.parse::<LitStr>()?;
+    match value.value().as_str() {
+        "before" => Ok(true),
+        "after" => Ok(false),
+        _ => Err(syn::Error::new_spanned(
+            value,
+            format!("`{name}` must be either \"before\" or \"after\""),
+        )),
+    }
+}
+
+/// Parse the value of the `name` attribute giving a byte-order, either `"le"` or `"be"`, returning
+/// the corresponding variant of `ByteOrder`.
+fn parse_order(meta: &ParseNestedMeta, name: &str) -> syn::Result<Ident> {
+    let value = meta.value()?.parse::<LitStr>()?;
+    let variant = match value.value().as_str() {
+        "le" => "Le",
+        "be" => "Be",
+        _ => {
+            return Err(syn::Error::new_spanned(
+                value,
+                format!("`{name}` must be either \"le\" or \"be\""),
+            ))
+        }
+    };
+    Ok(Ident::new(variant, value.span()))
+}
+
+/// Check that `member`, given by the `attr` attribute, names one of `fields`.
+fn check_sibling(fields: &Fields, member: &Member, attr: &str) -> syn::Result<()> {
+    if members(fields).any(|sibling| sibling == *member) {
+        Ok(())
+    } else {
+        Err(syn::Error::new_spanned(
+            member,
+            format!("`{attr}` must name a field of the struct"),
+        ))
+    }
+}
+
+/// Add the bounds of a derived implementation to `generics`.
+///
+/// The bounds are those given by the `bound` attribute of `container` if present, or otherwise
+/// those of `bounds` whose types mention a type parameter of `generics`.
+fn bounded_generics(
+    generics: &Generics,
+    container: &ContainerAttrs,
+    bounds: Vec<(Type, TokenStream2)>,
+) -> Generics {
+    let mut generics = generics.clone();
+    let predicates = match &container.bound {
+        Some(predicates) => predicates.clone(),
+        None => {
+            let params = generics
+                .type_params()
+                .map(|param| param.ident.clone())
+                .collect::<Vec<_>>();
+            bounds
+                .into_iter()
+                .filter(|(ty, _)| mentions(quote!(#ty), &params))
+                .map(|(ty, bound)| syn::parse_quote!(#ty: #bound))
+                .collect()
+        }
+    };
+    generics.make_where_clause().predicates.extend(predicates);
+    generics
+}
+
+/// Check whether `tokens` mention any of `idents`.
+fn mentions(tokens: TokenStream2, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&ident),
+        TokenTree::Group(group) => mentions(group.stream(), idents),
+        TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+    })
+}
+
+/// Iterate over the members through which each of `fields` is accessed.
+fn members(fields: &Fields) -> impl Iterator<Item = Member> + '_ {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        })
+}
+
+/// Retrieve the representations given by the `#[repr]` attributes of `input`, such as `C` and
+/// `packed`, without their arguments.
+fn reprs(input: &DeriveInput) -> syn::Result<Vec<Ident>> {
+    let mut reprs = Vec::new();
+    for attr in &input.attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                reprs.push(ident.clone());
+            }
+            // Skip the arguments of `packed(N)` and `align(N)`.
+            if meta.input.peek(syn::token::Paren) {
+                let args;
+                syn::parenthesized!(args in meta.input);
+                args.parse::<TokenStream2>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(reprs)
+}
+
+/// Retrieve the fixed-width integer given by the `#[repr]` attribute of `input`, if any.
+fn repr_int(input: &DeriveInput) -> syn::Result<Option<Ident>> {
+    const INTS: [&str; 10] = [
+        "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128",
+    ];
+    Ok(reprs(input)?
+        .into_iter()
+        .find(|repr| INTS.iter().any(|int| repr == int)))
+}
+
+/// Check whether `input` is annotated with `#[repr(packed)]` or `#[repr(packed(N))]`.
+fn is_packed(input: &DeriveInput) -> syn::Result<bool> {
+    Ok(reprs(input)?.iter().any(|repr| repr == "packed"))
+}
@@ -0,0 +1,48 @@
+/*
+ * C declarations for the exports of the `lilbig` crate's `capi` feature.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#ifndef LILBIG_H
+#define LILBIG_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Status returned by `lilbig_swap_buffer` upon success. */
+#define LILBIG_OK 0
+/* Status returned by `lilbig_swap_buffer` when provided a null pointer for a non-empty buffer. */
+#define LILBIG_ERR_NULL (-1)
+/* Status returned by `lilbig_swap_buffer` when provided an element width of zero. */
+#define LILBIG_ERR_WIDTH (-2)
+/* Status returned by `lilbig_swap_buffer` when the buffer length is not a multiple of the width. */
+#define LILBIG_ERR_LENGTH (-3)
+
+/* Unconditionally swap the byte-order of a 16-bit value. */
+uint16_t lilbig_swap16(uint16_t value);
+
+/* Unconditionally swap the byte-order of a 32-bit value. */
+uint32_t lilbig_swap32(uint32_t value);
+
+/* Unconditionally swap the byte-order of a 64-bit value. */
+uint64_t lilbig_swap64(uint64_t value);
+
+/*
+ * Unconditionally swap the byte-order of every `width` byte wide element within the `len` bytes
+ * pointed to by `ptr`. Returns `LILBIG_OK` upon success, otherwise one of the `LILBIG_ERR_*`
+ * statuses is returned and the buffer is left unmodified.
+ */
+int lilbig_swap_buffer(uint8_t *ptr, size_t len, size_t width);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* LILBIG_H */
@@ -0,0 +1,289 @@
+//! Command line tool converting files between byte-orders.
+
+use lilbig::layout::{FieldLayout, TypeLayout};
+use lilbig::{bulk, ByteOrder};
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::process::ExitCode;
+
+/// Usage message printed by `--help` and upon invalid arguments.
+const USAGE: &str = "\
+Usage: lilbig (--width <N> | --layout <SCHEMA>) --from <ORDER> --to <ORDER> [INPUT [OUTPUT]]
+
+Convert a file of fixed-size records between byte-orders, reading from standard input and writing
+to standard output when INPUT or OUTPUT are omitted or `-`.
+
+Options:
+  --width <N>        Records are single elements of N bytes.
+  --layout <SCHEMA>  Records are described by SCHEMA.
+  --from <ORDER>     Byte-order of the input: `le`, `be`, or `native`.
+  --to <ORDER>       Byte-order of the output: `le`, `be`, or `native`.
+  --help             Print this message.
+
+A schema is a comma separated list of items laid out one after another without implicit padding:
+  u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64, f80
+                     A field of the named type.
+  pad N              N bytes that are left unmodified.
+  [ITEMS; N]         N repetitions of ITEMS.
+For example: `u32, u16, pad 2, [u64; 4]`.";
+
+/// Approximate number of bytes converted at a time.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Description of the records of a file.
+enum Records {
+    /// Records are single elements of a width in bytes.
+    Width(usize),
+    /// Records are described by a schema, along with the size of a record.
+    Layout(usize, String),
+}
+
+impl Records {
+    /// Retrieve the size of a record in bytes.
+    fn size(&self) -> usize {
+        match self {
+            Self::Width(width) => *width,
+            Self::Layout(size, _) => *size,
+        }
+    }
+
+    /// Collect the fields of a record, which are empty for records of a width.
+    ///
+    /// The number of fields is bounded by the size of a record, so they should only be collected
+    /// once the input is known to hold a whole record.
+    fn fields(&self) -> Result<Vec<FieldLayout>, String> {
+        let mut fields = Vec::new();
+        if let Self::Layout(size, schema) = self {
+            parse_schema(schema, 0, Some(&mut fields))?;
+            TypeLayout::try_new(*size, &fields).map_err(|err| format!("invalid schema: {err}"))?;
+        }
+        Ok(fields)
+    }
+
+    /// Swap the byte-order of each record within `bytes`, whose `fields` are those collected by
+    /// [`Records::fields()`].
+    fn swap(&self, fields: &[FieldLayout], bytes: &mut [u8]) -> Result<(), String> {
+        let result = match self {
+            Self::Width(width) => bulk::try_swap_elements(bytes, *width),
+            Self::Layout(size, _) => TypeLayout::new(*size, fields).try_swap_records(bytes),
+        };
+        result.map_err(|err| err.to_string())
+    }
+}
+
+/// Arguments of the tool.
+struct Args {
+    /// Description of the records being converted.
+    records: Records,
+    /// Byte-order of the input.
+    from: ByteOrder,
+    /// Byte-order of the output.
+    to: ByteOrder,
+    /// Path of the input, or [`None`] for standard input.
+    input: Option<String>,
+    /// Path of the output, or [`None`] for standard output.
+    output: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("lilbig: {err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("lilbig: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parse the tool's arguments, returning [`None`] if help was requested.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut records = None;
+    let mut from = None;
+    let mut to = None;
+    let mut paths = Vec::new();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for `{arg}`"))
+        };
+        match arg.as_str() {
+            "--help" | "-h" => return Ok(None),
+            "--width" => {
+                let width = value()?;
+                let width = width
+                    .parse()
+                    .ok()
+                    .filter(|&width| width != 0)
+                    .ok_or_else(|| format!("invalid width `{width}`"))?;
+                records = Some(Records::Width(width));
+            }
+            "--layout" => {
+                let schema = value()?;
+                let size = parse_schema(&schema, 0, None)?;
+                if size == 0 {
+                    return Err("schema describes empty records".into());
+                }
+                records = Some(Records::Layout(size, schema));
+            }
+            "--from" => from = Some(parse_order(&value()?)?),
+            "--to" => to = Some(parse_order(&value()?)?),
+            _ if arg.starts_with("--") => return Err(format!("unknown option `{arg}`")),
+            _ => paths.push(arg),
+        }
+    }
+
+    let records = records.ok_or("one of `--width` or `--layout` is required")?;
+    let from = from.ok_or("`--from` is required")?;
+    let to = to.ok_or("`--to` is required")?;
+    if paths.len() > 2 {
+        return Err("too many paths".into());
+    }
+    let mut paths = paths
+        .into_iter()
+        .map(|path| Some(path).filter(|path| path != "-"));
+    Ok(Some(Args {
+        records,
+        from,
+        to,
+        input: paths.next().flatten(),
+        output: paths.next().flatten(),
+    }))
+}
+
+/// Parse a byte-order argument.
+fn parse_order(order: &str) -> Result<ByteOrder, String> {
+    order
+        .parse()
+        .map_err(|_| format!("invalid byte-order `{order}`"))
+}
+
+/// Parse the items of `schema` starting at `offset`, appending their fields to `fields` if given.
+///
+/// Returns the offset following the last item. Repetitions are only expanded when collecting
+/// fields, so the size of a schema is determined without allocating for each of its fields.
+fn parse_schema(
+    schema: &str,
+    mut offset: usize,
+    mut fields: Option<&mut Vec<FieldLayout>>,
+) -> Result<usize, String> {
+    for item in split_top_level(schema, ',') {
+        let item = item.trim();
+        if let Some(repeated) = item
+            .strip_prefix('[')
+            .and_then(|item| item.strip_suffix(']'))
+        {
+            // The count follows the last separator, as any nested separators precede it.
+            let (items, count) = repeated
+                .rsplit_once(';')
+                .and_then(|(items, count)| Some((items, count.trim().parse::<usize>().ok()?)))
+                .ok_or_else(|| format!("invalid repetition `{item}`"))?;
+            let size = parse_schema(items, 0, None)?;
+            match fields.as_deref_mut() {
+                // Repetitions of empty items hold no fields, however many there are.
+                Some(fields) if size != 0 => {
+                    for _ in 0..count {
+                        offset = parse_schema(items, offset, Some(fields))?;
+                    }
+                }
+                _ => {
+                    offset = size
+                        .checked_mul(count)
+                        .and_then(|size| offset.checked_add(size))
+                        .ok_or("schema is too large")?;
+                }
+            }
+        } else if let Some(padding) = item.strip_prefix("pad") {
+            let padding: usize = padding
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid padding `{item}`"))?;
+            offset = offset.checked_add(padding).ok_or("schema is too large")?;
+        } else {
+            let field = match item {
+                "u8" | "i8" => FieldLayout::new(offset, 1),
+                "u16" | "i16" => FieldLayout::new(offset, 2),
+                "u32" | "i32" | "f32" => FieldLayout::new(offset, 4),
+                "u64" | "i64" | "f64" => FieldLayout::new(offset, 8),
+                "f80" => FieldLayout::opaque(offset, 10),
+                "u128" | "i128" => FieldLayout::new(offset, 16),
+                _ => return Err(format!("invalid schema item `{item}`")),
+            };
+            if let Some(fields) = fields.as_deref_mut() {
+                fields.push(field);
+            }
+            offset = offset
+                .checked_add(field.width)
+                .ok_or("schema is too large")?;
+        }
+    }
+    Ok(offset)
+}
+
+/// Split `text` at each `separator` that is not enclosed within brackets.
+fn split_top_level(text: &str, separator: char) -> impl Iterator<Item = &str> {
+    let mut depth = 0usize;
+    text.split(move |c| {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        c == separator && depth == 0
+    })
+}
+
+/// Convert the input to the output.
+fn run(args: &Args) -> Result<(), String> {
+    let mut input: Box<dyn Read> = match &args.input {
+        Some(path) => Box::new(File::open(path).map_err(|err| format!("{path}: {err}"))?),
+        None => Box::new(io::stdin().lock()),
+    };
+    let output: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(File::create(path).map_err(|err| format!("{path}: {err}"))?),
+        None => Box::new(io::stdout().lock()),
+    };
+    let mut output = BufWriter::new(output);
+
+    // The buffer grows as the input is read rather than being allocated up front, so records
+    // larger than the input are rejected without allocating for them.
+    let size = args.records.size();
+    let chunk_size = (CHUNK_SIZE / size).max(1) * size;
+    let mut buffer = Vec::new();
+    let mut fields = None;
+    loop {
+        buffer.clear();
+        (&mut input)
+            .take(chunk_size as u64)
+            .read_to_end(&mut buffer)
+            .map_err(|err| err.to_string())?;
+        if buffer.is_empty() {
+            break;
+        }
+        if !buffer.len().is_multiple_of(size) {
+            return Err(format!(
+                "input does not end on a whole record of {size} bytes"
+            ));
+        }
+        // The schema is validated even when the records are left unmodified.
+        let fields = match &fields {
+            Some(fields) => fields,
+            None => fields.insert(args.records.fields()?),
+        };
+        if args.from != args.to {
+            args.records.swap(fields, &mut buffer)?;
+        }
+        output.write_all(&buffer).map_err(|err| err.to_string())?;
+    }
+    output.flush().map_err(|err| err.to_string())
+}
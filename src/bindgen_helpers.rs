@@ -0,0 +1,331 @@
+//! Build-script helpers for generating [`FieldsByteOrdered`](crate::FieldsByteOrdered)
+//! implementations for the plain-data structs found in [bindgen](https://docs.rs/bindgen) output.
+//!
+//! A struct is considered plain-data when it is declared `#[repr(C)]`, has no generic parameters,
+//! is not packed, and each of its fields is a primitive, a `core::ffi` C type, a type alias thereof,
+//! an array thereof, or another plain-data struct found within the same bindings. All other structs
+//! (including those containing pointers or bindgen bitfield units) are skipped.
+//!
+//! The generated implementations refer to the structs by their unqualified names and thus must be
+//! included within the same module as the bindings that they were generated from.
+//!
+//! Build scripts are compiled against their own copy of this crate, whose features need not match
+//! those of the copy that the bindings are compiled against. Should the latter enable the
+//! `strict-usize` or `no-float` features, the matching [`ImplOptions`] must be given to
+//! [`generate_impls_with()`] such that no implementation refers to the types they remove.
+//!
+//! # Examples
+//! Usage within a build script:
+//! ```no_run
+//! # let bindings = String::new();
+//! use std::path::PathBuf;
+//!
+//! // `bindings` is the output of `bindgen::Bindings::to_string()`.
+//! let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+//! let generated = lilbig::bindgen_helpers::generate_impls(&bindings).unwrap();
+//! for name in &generated.skipped {
+//!     println!("cargo:warning=no FieldsByteOrdered implementation generated for `{name}`");
+//! }
+//! std::fs::write(out_dir.join("bindings.rs"), &bindings).unwrap();
+//! std::fs::write(out_dir.join("bindings_lilbig.rs"), &generated.source).unwrap();
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::{BTreeMap, BTreeSet};
+use std::string::{String, ToString};
+use std::vec::Vec;
+use syn::{Item, ItemStruct, Type};
+
+/// The [`FieldsByteOrdered`](crate::FieldsByteOrdered) implementations generated for a set of
+/// bindings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedImpls {
+    /// Rust source code containing the generated implementations.
+    pub source: String,
+    /// The names of the structs for which an implementation was generated.
+    pub implemented: Vec<String>,
+    /// The names of the structs for which no implementation could be generated.
+    pub skipped: Vec<String>,
+}
+
+/// Options controlling which types [`generate_impls_with()`] considers to be plain-data.
+///
+/// The options mirror the features of the crate that remove implementations of
+/// [`FieldsByteOrdered`](crate::FieldsByteOrdered), and default to those features being disabled.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::bindgen_helpers::{generate_impls_with, ImplOptions};
+///
+/// let bindings = r#"
+///     #[repr(C)]
+///     pub struct sample {
+///         pub level: f32,
+///     }
+///     #[repr(C)]
+///     pub struct count {
+///         pub value: usize,
+///     }
+/// "#;
+///
+/// let options = ImplOptions::new().with_no_float();
+/// let generated = generate_impls_with(bindings, options).unwrap();
+/// assert_eq!(generated.implemented, ["count"]);
+/// assert_eq!(generated.skipped, ["sample"]);
+///
+/// let options = ImplOptions::new().with_strict_usize();
+/// let generated = generate_impls_with(bindings, options).unwrap();
+/// assert_eq!(generated.implemented, ["sample"]);
+/// assert_eq!(generated.skipped, ["count"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ImplOptions {
+    /// Whether `usize` and `isize` lack implementations, as with the `strict-usize` feature.
+    strict_usize: bool,
+    /// Whether floating point types lack implementations, as with the `no-float` feature.
+    no_float: bool,
+}
+
+impl ImplOptions {
+    /// Create options for bindings compiled against the crate with neither the `strict-usize` nor
+    /// the `no-float` feature enabled.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            strict_usize: false,
+            no_float: false,
+        }
+    }
+
+    /// Retrieve the options with `usize` and `isize` treated as lacking implementations, as they
+    /// do with the `strict-usize` feature enabled.
+    #[inline]
+    #[must_use]
+    pub const fn with_strict_usize(self) -> Self {
+        Self {
+            strict_usize: true,
+            ..self
+        }
+    }
+
+    /// Retrieve the options with floating point types treated as lacking implementations, as they
+    /// do with the `no-float` feature enabled.
+    #[inline]
+    #[must_use]
+    pub const fn with_no_float(self) -> Self {
+        Self {
+            no_float: true,
+            ..self
+        }
+    }
+
+    /// Check whether the type named `name` implements
+    /// [`FieldsByteOrdered`](crate::FieldsByteOrdered) under these options.
+    fn is_plain_name(&self, name: &str) -> bool {
+        PLAIN_TYPE_NAMES.contains(&name)
+            || (!self.strict_usize && USIZE_TYPE_NAMES.contains(&name))
+            || (!self.no_float && FLOAT_TYPE_NAMES.contains(&name))
+    }
+}
+
+/// Generate [`FieldsByteOrdered`](crate::FieldsByteOrdered) implementations for all the plain-data
+/// structs found within the Rust source code of `bindings`.
+///
+/// This is [`generate_impls_with()`] given the default [`ImplOptions`].
+///
+/// # Errors
+/// Returns an error if `bindings` is not valid Rust source code.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// let bindings = r#"
+///     pub type __u32 = ::std::os::raw::c_uint;
+///     #[repr(C)]
+///     pub struct header {
+///         pub magic: __u32,
+///         pub counts: [u16; 4usize],
+///         pub valid: bool,
+///     }
+///     #[repr(C)]
+///     pub struct node {
+///         pub next: *mut node,
+///     }
+/// "#;
+///
+/// let generated = lilbig::bindgen_helpers::generate_impls(bindings).unwrap();
+/// assert_eq!(generated.implemented, ["header"]);
+/// assert_eq!(generated.skipped, ["node"]);
+/// ```
+pub fn generate_impls(bindings: &str) -> syn::Result<GeneratedImpls> {
+    generate_impls_with(bindings, ImplOptions::new())
+}
+
+/// Generate [`FieldsByteOrdered`](crate::FieldsByteOrdered) implementations for all the plain-data
+/// structs found within the Rust source code of `bindings`, considering the types that `options`
+/// treat as lacking implementations not to be plain-data.
+///
+/// # Errors
+/// Returns an error if `bindings` is not valid Rust source code.
+pub fn generate_impls_with(bindings: &str, options: ImplOptions) -> syn::Result<GeneratedImpls> {
+    let file = syn::parse_file(bindings)?;
+
+    let mut aliases = BTreeMap::new();
+    let mut candidates = Vec::new();
+    let mut skipped = Vec::new();
+    for item in file.items {
+        match item {
+            Item::Type(item) if item.generics.params.is_empty() => {
+                aliases.insert(item.ident.to_string(), *item.ty);
+            }
+            Item::Struct(item) => {
+                if is_candidate(&item) {
+                    candidates.push(item);
+                } else {
+                    skipped.push(item.ident.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Structs may only be implemented when all the structs they contain are also implemented, so
+    // repeatedly discard candidates until no more are discarded.
+    loop {
+        let names: BTreeSet<String> = candidates.iter().map(|c| c.ident.to_string()).collect();
+        let (retained, discarded): (Vec<_>, Vec<_>) = candidates.into_iter().partition(|c| {
+            c.fields
+                .iter()
+                .all(|f| is_plain(&f.ty, options, &aliases, &names, 0))
+        });
+        candidates = retained;
+        if discarded.is_empty() {
+            break;
+        }
+        skipped.extend(discarded.into_iter().map(|d| d.ident.to_string()));
+    }
+
+    let impls: TokenStream = candidates.iter().map(generate_impl).collect();
+    Ok(GeneratedImpls {
+        source: impls.to_string(),
+        implemented: candidates.iter().map(|c| c.ident.to_string()).collect(),
+        skipped,
+    })
+}
+
+/// Check whether a struct's declaration permits it to be implemented regardless of its fields.
+fn is_candidate(item: &ItemStruct) -> bool {
+    let mut repr_c = false;
+    let mut packed = false;
+    for attr in item.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            repr_c |= meta.path.is_ident("C");
+            packed |= meta.path.is_ident("packed");
+            // Consume any arguments such as those of `packed(N)` or `align(N)`.
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+    }
+    repr_c && !packed && item.generics.params.is_empty()
+}
+
+/// Maximum depth of type aliases that will be followed before giving up on a type.
+const MAX_ALIAS_DEPTH: usize = 32;
+
+/// Check whether a type is one that implements [`FieldsByteOrdered`](crate::FieldsByteOrdered).
+fn is_plain(
+    ty: &Type,
+    options: ImplOptions,
+    aliases: &BTreeMap<String, Type>,
+    structs: &BTreeSet<String>,
+    depth: usize,
+) -> bool {
+    match ty {
+        Type::Array(array) => is_plain(&array.elem, options, aliases, structs, depth),
+        Type::Paren(paren) => is_plain(&paren.elem, options, aliases, structs, depth),
+        Type::Group(group) => is_plain(&group.elem, options, aliases, structs, depth),
+        Type::Path(path) if path.qself.is_none() => {
+            let Some(segment) = path.path.segments.last() else {
+                return false;
+            };
+            if !segment.arguments.is_none() {
+                return false;
+            }
+            let name = segment.ident.to_string();
+            if options.is_plain_name(&name) || structs.contains(&name) {
+                return true;
+            }
+            match aliases.get(&name) {
+                Some(aliased) if depth < MAX_ALIAS_DEPTH => {
+                    is_plain(aliased, options, aliases, structs, depth + 1)
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Names of the primitive and C types that implement
+/// [`FieldsByteOrdered`](crate::FieldsByteOrdered) regardless of the crate's features.
+const PLAIN_TYPE_NAMES: &[&str] = &[
+    "bool",
+    "u8",
+    "i8",
+    "u16",
+    "i16",
+    "u32",
+    "i32",
+    "u64",
+    "i64",
+    "u128",
+    "i128",
+    "c_char",
+    "c_schar",
+    "c_uchar",
+    "c_short",
+    "c_ushort",
+    "c_int",
+    "c_uint",
+    "c_long",
+    "c_ulong",
+    "c_longlong",
+    "c_ulonglong",
+];
+
+/// Names of the types whose implementations are removed by the `strict-usize` feature.
+const USIZE_TYPE_NAMES: &[&str] = &["usize", "isize"];
+
+/// Names of the types whose implementations are removed by the `no-float` feature.
+const FLOAT_TYPE_NAMES: &[&str] = &["f32", "f64", "c_float", "c_double"];
+
+/// Generate the [`FieldsByteOrdered`](crate::FieldsByteOrdered) implementation for a struct.
+fn generate_impl(item: &ItemStruct) -> TokenStream {
+    let ident = &item.ident;
+    let swaps = item.fields.iter().enumerate().map(|(index, field)| {
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = syn::Index::from(index);
+                quote!(#index)
+            }
+        };
+        quote! {
+            ::lilbig::FieldsByteOrdered::swap_field_orders(&mut self.#member);
+        }
+    });
+    quote! {
+        impl ::lilbig::FieldsByteOrdered for #ident {
+            #[inline]
+            fn swap_field_orders(&mut self) {
+                #(#swaps)*
+            }
+        }
+    }
+}
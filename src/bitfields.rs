@@ -0,0 +1,168 @@
+//! Interoperation with the types generated by bitfield crates.
+//!
+//! Bitfield crates, such as [bitfield-struct](https://crates.io/crates/bitfield-struct) and
+//! [modular-bitfield](https://crates.io/crates/modular-bitfield), generate types that pack their
+//! fields into a single integer. The byte-order of such a type is that of its packed integer, so
+//! the [`impl_bitfield!`](crate::impl_bitfield) macro implements the crate's traits for a type by
+//! converting it to and from its packed integer. The [`Bitfield`] trait it implements further
+//! provides conversions to and from packed integers encoded in a specified byte-order.
+//!
+//! # Examples
+//! Joining a bitfield to a derived struct:
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # fn main() {
+//! use bitfield_struct::bitfield;
+//! use lilbig::{ByteOrder, FieldsByteOrdered};
+//!
+//! #[bitfield(u16)]
+//! struct Control {
+//!     #[bits(4)]
+//!     channel: u8,
+//!     enabled: bool,
+//!     #[bits(11)]
+//!     divider: u16,
+//! }
+//!
+//! lilbig::impl_bitfield!(Control: u16);
+//!
+//! #[derive(FieldsByteOrdered)]
+//! #[repr(C)]
+//! struct Registers {
+//!     status: u16,
+//!     control: Control,
+//! }
+//!
+//! let mut registers = Registers {
+//!     status: 0x0102_u16.to_be(),
+//!     control: Control::from(0x1234_u16.to_be()),
+//! };
+//! registers.order_fields_ne(ByteOrder::Be);
+//! assert_eq!(registers.status, 0x0102);
+//! assert_eq!(registers.control.channel(), 0x4);
+//! assert_eq!(registers.control.divider(), 0x1234 >> 5);
+//! # }
+//! # #[cfg(not(feature = "derive"))]
+//! # fn main() {}
+//! ```
+
+use crate::{ByteOrder, ByteOrdered};
+
+/// Trait for bitfield types whose fields are packed into an integer.
+///
+/// This is implemented by the [`impl_bitfield!`](crate::impl_bitfield) macro.
+///
+/// # Examples
+/// Decoding a bitfield whose packed integer is always big-endian:
+/// ```
+/// use bitfield_struct::bitfield;
+/// use lilbig::bitfields::Bitfield;
+/// use lilbig::ByteOrder;
+///
+/// #[bitfield(u32)]
+/// struct Header {
+///     #[bits(24)]
+///     length: u32,
+///     kind: u8,
+/// }
+///
+/// lilbig::impl_bitfield!(Header: u32);
+///
+/// let bits = u32::from_ne_bytes([0x03, 0x00, 0x00, 0x10]);
+/// let header = Header::from_packed_in(bits, ByteOrder::Be);
+/// assert_eq!(header.kind(), 0x03);
+/// assert_eq!(header.length(), 0x10);
+/// assert_eq!(header.into_packed_in(ByteOrder::Be), bits);
+/// ```
+pub trait Bitfield: Copy {
+    /// The integer into which the fields are packed.
+    type Packed: ByteOrdered + Copy;
+
+    /// Create a value from its packed integer, which is in the machine's native byte-order.
+    #[must_use]
+    fn from_packed(packed: Self::Packed) -> Self;
+
+    /// Retrieve the packed integer of `self` in the machine's native byte-order.
+    #[must_use]
+    fn into_packed(self) -> Self::Packed;
+
+    /// Create a value from its packed integer, which is encoded in `order`.
+    #[inline]
+    #[must_use]
+    fn from_packed_in(packed: Self::Packed, order: ByteOrder) -> Self {
+        Self::from_packed(packed.ordered_ne(order))
+    }
+
+    /// Retrieve the packed integer of `self` encoded in `order`.
+    #[inline]
+    #[must_use]
+    fn into_packed_in(self, order: ByteOrder) -> Self::Packed {
+        self.into_packed().ordered_as(ByteOrder::NATIVE, order)
+    }
+}
+
+/// Implement [`Bitfield`], [`ByteOrdered`], and [`FieldsByteOrdered`](crate::FieldsByteOrdered)
+/// for bitfield types by converting them to and from their packed integers.
+///
+/// Each type is given along with its packed integer as `Type: Integer`. The type must be [`Copy`]
+/// and convertible to and from its packed integer through [`From`], as is the case for the types
+/// generated by bitfield-struct and for those generated by modular-bitfield with a `#[repr]` of an
+/// integer.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use bitfield_struct::bitfield;
+/// use lilbig::{ByteOrder, ByteOrdered};
+///
+/// #[bitfield(u16)]
+/// struct Mode {
+///     #[bits(12)]
+///     level: u16,
+///     #[bits(4)]
+///     flags: u8,
+/// }
+///
+/// lilbig::impl_bitfield!(Mode: u16);
+///
+/// let mode = Mode::new().with_level(0x123).ordered_be(ByteOrder::NATIVE);
+/// assert_eq!(u16::from(mode), 0x0123_u16.to_be());
+/// ```
+#[macro_export]
+macro_rules! impl_bitfield {
+    ($($ty: ty: $packed: ty),+ $(,)?) => {
+        $(
+            impl $crate::bitfields::Bitfield for $ty {
+                type Packed = $packed;
+
+                #[inline]
+                fn from_packed(packed: $packed) -> Self {
+                    <Self as ::core::convert::From<$packed>>::from(packed)
+                }
+
+                #[inline]
+                fn into_packed(self) -> $packed {
+                    <$packed as ::core::convert::From<Self>>::from(self)
+                }
+            }
+
+            impl $crate::ByteOrdered for $ty {
+                #[inline]
+                fn swapped_order(self) -> Self {
+                    <Self as $crate::bitfields::Bitfield>::from_packed(
+                        $crate::ByteOrdered::swapped_order(
+                            <Self as $crate::bitfields::Bitfield>::into_packed(self),
+                        ),
+                    )
+                }
+            }
+
+            impl $crate::FieldsByteOrdered for $ty {
+                #[inline]
+                fn swap_field_orders(&mut self) {
+                    *self = $crate::ByteOrdered::swapped_order(*self);
+                }
+            }
+        )+
+    };
+}
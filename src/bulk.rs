@@ -0,0 +1,217 @@
+//! Bulk byte-order swapping of contiguous buffers of fixed-width elements.
+//!
+//! The functions in this module operate on raw bytes rather than typed values. This makes them
+//! useful when the width of the elements within a buffer is only known at runtime, such as when
+//! the buffer was read from a file or received from a foreign function interface.
+
+use crate::WidthError;
+
+/// Swap the byte-order of each chunk of a byte slice by reinterpreting each chunk as an integer of
+/// the same width.
+macro_rules! swap_chunks {
+    ($bytes: expr, $ty: ty) => {
+        $bytes
+            .chunks_exact_mut(core::mem::size_of::<$ty>())
+            .for_each(|chunk| {
+                let mut array = [0u8; core::mem::size_of::<$ty>()];
+                array.copy_from_slice(chunk);
+                chunk.copy_from_slice(&<$ty>::from_ne_bytes(array).swap_bytes().to_ne_bytes());
+            })
+    };
+}
+
+/// Unconditionally swap the byte-order of every `width` byte wide element within `bytes`.
+///
+/// Element widths of `2`, `4`, `8`, and `16` are dispatched to specialized implementations that
+/// the compiler is able to vectorize. All other widths fall back to reversing each element's bytes
+/// individually.
+///
+/// # Panics
+/// Panics if `width` is zero or if the length of `bytes` is not a multiple of `width`. See
+/// [`try_swap_elements()`] for a non-panicking alternative.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::bulk::swap_elements;
+///
+/// let mut bytes = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+/// swap_elements(&mut bytes, 4);
+/// assert_eq!(bytes, [0x04, 0x03, 0x02, 0x01, 0x08, 0x07, 0x06, 0x05]);
+///
+/// swap_elements(&mut bytes, 2);
+/// assert_eq!(bytes, [0x03, 0x04, 0x01, 0x02, 0x07, 0x08, 0x05, 0x06]);
+/// ```
+#[cfg(not(feature = "no-panic"))]
+pub fn swap_elements(bytes: &mut [u8], width: usize) {
+    if let Err(err) = try_swap_elements(bytes, width) {
+        panic!("{err}");
+    }
+}
+
+/// Unconditionally swap the byte-order of every `width` byte wide element within `bytes`.
+///
+/// This is the non-panicking alternative of `swap_elements()`.
+///
+/// # Errors
+/// Returns [`WidthError::Zero`] if `width` is zero, or [`WidthError::IndivisibleLength`] if the
+/// length of `bytes` is not a multiple of `width`. The contents of `bytes` are left unmodified upon
+/// error.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::bulk::try_swap_elements;
+/// use lilbig::WidthError;
+///
+/// let mut bytes = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+/// assert_eq!(try_swap_elements(&mut bytes, 3), Ok(()));
+/// assert_eq!(bytes, [0x03, 0x02, 0x01, 0x06, 0x05, 0x04]);
+///
+/// assert_eq!(try_swap_elements(&mut bytes, 4), Err(WidthError::IndivisibleLength));
+/// assert_eq!(bytes, [0x03, 0x02, 0x01, 0x06, 0x05, 0x04]);
+/// ```
+pub fn try_swap_elements(bytes: &mut [u8], width: usize) -> Result<(), WidthError> {
+    if width == 0 {
+        return Err(WidthError::Zero);
+    }
+    if !bytes.len().is_multiple_of(width) {
+        return Err(WidthError::IndivisibleLength);
+    }
+    swap_elements_unchecked(bytes, width);
+    Ok(())
+}
+
+/// Implementation of [`try_swap_elements()`] that assumes its arguments have already been
+/// validated.
+///
+/// Elements trailing beyond the last multiple of `width` are left unmodified.
+pub(crate) fn swap_elements_unchecked(bytes: &mut [u8], width: usize) {
+    match width {
+        0 | 1 => {}
+        2 => swap_chunks!(bytes, u16),
+        4 => swap_chunks!(bytes, u32),
+        8 => swap_chunks!(bytes, u64),
+        16 => swap_chunks!(bytes, u128),
+        _ => bytes.chunks_exact_mut(width).for_each(<[u8]>::reverse),
+    }
+}
+
+/// Resumable converter swapping the byte-order of a buffer's elements across multiple calls.
+///
+/// Each call to [`step()`](IncrementalSwapper::step) processes a bounded number of bytes and
+/// remembers where it stopped. This permits large buffers to be converted across multiple
+/// iterations of a main loop without any single iteration exceeding a latency budget.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::bulk::IncrementalSwapper;
+///
+/// let mut bytes = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a];
+/// let mut swapper = IncrementalSwapper::try_new(&mut bytes, 2).unwrap();
+///
+/// // Budgets are rounded down to a whole number of elements.
+/// assert_eq!(swapper.step(5), 4);
+/// assert_eq!(swapper.position(), 4);
+/// assert_eq!(swapper.step(4), 4);
+/// assert_eq!(swapper.step(4), 2);
+/// assert!(swapper.is_finished());
+/// assert_eq!(swapper.step(4), 0);
+///
+/// assert_eq!(bytes, [0x02, 0x01, 0x04, 0x03, 0x06, 0x05, 0x08, 0x07, 0x0a, 0x09]);
+/// ```
+#[derive(Debug)]
+pub struct IncrementalSwapper<'a> {
+    /// The buffer being converted.
+    bytes: &'a mut [u8],
+    /// The width of the buffer's elements.
+    width: usize,
+    /// The offset of the first byte of the buffer that has yet to be converted.
+    position: usize,
+}
+
+impl<'a> IncrementalSwapper<'a> {
+    /// Create a converter that will swap the byte-order of every `width` byte wide element within
+    /// `bytes`.
+    ///
+    /// # Panics
+    /// Panics under the same conditions as [`swap_elements()`](fn@swap_elements). See
+    /// [`try_new()`](IncrementalSwapper::try_new) for a non-panicking alternative.
+    #[cfg(not(feature = "no-panic"))]
+    #[must_use]
+    pub fn new(bytes: &'a mut [u8], width: usize) -> Self {
+        match Self::try_new(bytes, width) {
+            Ok(swapper) => swapper,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Create a converter that will swap the byte-order of every `width` byte wide element within
+    /// `bytes`.
+    ///
+    /// # Errors
+    /// Returns an error under the same conditions as [`try_swap_elements()`].
+    pub fn try_new(bytes: &'a mut [u8], width: usize) -> Result<Self, WidthError> {
+        if width == 0 {
+            return Err(WidthError::Zero);
+        }
+        if !bytes.len().is_multiple_of(width) {
+            return Err(WidthError::IndivisibleLength);
+        }
+        Ok(Self {
+            bytes,
+            width,
+            position: 0,
+        })
+    }
+
+    /// Convert at most `max_bytes` of the remaining bytes of the buffer, returning the number of
+    /// bytes that were converted.
+    ///
+    /// Only whole elements are converted, so a budget smaller than the width of the buffer's
+    /// elements results in no progress being made.
+    pub fn step(&mut self, max_bytes: usize) -> usize {
+        let budget = max_bytes.min(self.remaining());
+        let len = budget - budget % self.width;
+        let end = self.position + len;
+        if let Some(bytes) = self.bytes.get_mut(self.position..end) {
+            swap_elements_unchecked(bytes, self.width);
+            self.position = end;
+        }
+        len
+    }
+
+    /// Convert all the remaining bytes of the buffer.
+    pub fn finish(&mut self) {
+        self.step(self.remaining());
+    }
+
+    /// Retrieve the offset of the first byte of the buffer that has yet to be converted.
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Retrieve the number of bytes of the buffer that have yet to be converted.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    /// Check whether the entire buffer has been converted.
+    #[inline]
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Retrieve the buffer being converted, regardless of whether its conversion has finished.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> &'a mut [u8] {
+        self.bytes
+    }
+}
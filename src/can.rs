@@ -0,0 +1,258 @@
+//! Extraction and injection of signals packed within the payload of CAN frames.
+//!
+//! Signals are described as they are within DBC files: by the position of their start bit, their
+//! length in bits, their signedness, and their byte-order. DBC files refer to little-endian
+//! signals as *Intel* signals (`@1`) and to big-endian signals as *Motorola* signals (`@0`).
+//!
+//! Bits are numbered such that bit `n` is bit `n % 8` of byte `n / 8`, with bit `0` of a byte being
+//! its least significant bit. The start bit of an Intel signal is its least significant bit, whereas
+//! the start bit of a Motorola signal is its most significant bit.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! use lilbig::can::Signal;
+//! use lilbig::ByteOrder;
+//!
+//! // An unsigned 12-bit Intel signal starting at bit 4 and a signed 10-bit Motorola signal
+//! // starting at bit 39.
+//! let speed = Signal::try_new(4, 12, ByteOrder::Le)?;
+//! let angle = Signal::try_new(39, 10, ByteOrder::Be)?.signed();
+//!
+//! let mut frame = [0u8; 8];
+//! speed.inject(&mut frame, 0xabc);
+//! angle.inject(&mut frame, -3);
+//! assert_eq!(frame, [0xc0, 0xab, 0x00, 0x00, 0xff, 0x40, 0x00, 0x00]);
+//!
+//! assert_eq!(speed.extract(&frame), 0xabc);
+//! assert_eq!(angle.extract(&frame), -3);
+//! # Ok::<(), lilbig::can::SignalError>(())
+//! ```
+
+use crate::ByteOrder;
+
+/// The number of bits within the payload of a CAN frame.
+const FRAME_BITS: u16 = 64;
+
+/// Error returned when a signal's description is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SignalError {
+    /// The signal's length was zero or greater than 64 bits.
+    InvalidLength,
+    /// The signal extends beyond the payload of the frame.
+    OutOfBounds,
+}
+
+impl SignalError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::InvalidLength => "signal length must be between 1 and 64 bits",
+            Self::OutOfBounds => "signal must not extend beyond the frame's payload",
+        }
+    }
+}
+
+impl core::fmt::Display for SignalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for SignalError {}
+
+/// Description of a signal packed within the payload of a CAN frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signal {
+    /// Offset of the signal's least significant bit within the frame's payload when interpreted as
+    /// a [`u64`] encoded in the signal's byte-order.
+    shift: u8,
+    /// The signal's length in bits.
+    length: u8,
+    /// The signal's byte-order.
+    order: ByteOrder,
+    /// Whether the signal is a two's complement signed integer.
+    signed: bool,
+}
+
+impl Signal {
+    /// Create an unsigned signal described by its start bit, its length in bits, and its
+    /// byte-order.
+    ///
+    /// # Panics
+    /// Panics if the signal is invalid. See [`try_new()`](Signal::try_new) for a non-panicking
+    /// alternative.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::can::Signal;
+    /// use lilbig::ByteOrder;
+    ///
+    /// /// Engine speed signal of a powertrain status frame.
+    /// const ENGINE_SPEED: Signal = Signal::new(24, 16, ByteOrder::Le);
+    /// assert_eq!(ENGINE_SPEED.extract(&[0, 0, 0, 0x70, 0x17, 0, 0, 0]), 6000);
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    #[must_use]
+    pub const fn new(start_bit: u8, length: u8, order: ByteOrder) -> Self {
+        match Self::try_new(start_bit, length, order) {
+            Ok(signal) => signal,
+            Err(err) => panic!("{}", err.message()),
+        }
+    }
+
+    /// Create an unsigned signal described by its start bit, its length in bits, and its
+    /// byte-order.
+    ///
+    /// # Errors
+    /// Returns [`SignalError::InvalidLength`] if `length` is zero or greater than 64, or
+    /// [`SignalError::OutOfBounds`] if the signal extends beyond the payload of the frame.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::can::{Signal, SignalError};
+    /// use lilbig::ByteOrder;
+    ///
+    /// assert!(Signal::try_new(7, 16, ByteOrder::Be).is_ok());
+    /// assert_eq!(Signal::try_new(0, 0, ByteOrder::Le), Err(SignalError::InvalidLength));
+    /// assert_eq!(Signal::try_new(60, 8, ByteOrder::Le), Err(SignalError::OutOfBounds));
+    /// assert_eq!(Signal::try_new(56, 8, ByteOrder::Be), Err(SignalError::OutOfBounds));
+    /// ```
+    pub const fn try_new(start_bit: u8, length: u8, order: ByteOrder) -> Result<Self, SignalError> {
+        if length == 0 || length as u16 > FRAME_BITS {
+            return Err(SignalError::InvalidLength);
+        }
+        if start_bit as u16 >= FRAME_BITS {
+            return Err(SignalError::OutOfBounds);
+        }
+        let shift = match order {
+            ByteOrder::Le => {
+                if start_bit as u16 + length as u16 > FRAME_BITS {
+                    return Err(SignalError::OutOfBounds);
+                }
+                start_bit
+            }
+            ByteOrder::Be => {
+                // Position of the signal's most significant bit when the payload is interpreted as
+                // a big-endian `u64`.
+                let msb = (7 - start_bit / 8) * 8 + start_bit % 8;
+                if msb + 1 < length {
+                    return Err(SignalError::OutOfBounds);
+                }
+                msb + 1 - length
+            }
+        };
+        Ok(Self {
+            shift,
+            length,
+            order,
+            signed: false,
+        })
+    }
+
+    /// Mark the signal as being a two's complement signed integer.
+    #[inline]
+    #[must_use]
+    pub const fn signed(mut self) -> Self {
+        self.signed = true;
+        self
+    }
+
+    /// Check whether the signal is a two's complement signed integer.
+    #[inline]
+    #[must_use]
+    pub const fn is_signed(&self) -> bool {
+        self.signed
+    }
+
+    /// Retrieve the signal's length in bits.
+    #[inline]
+    #[must_use]
+    pub const fn length(&self) -> u8 {
+        self.length
+    }
+
+    /// Retrieve the signal's byte-order.
+    #[inline]
+    #[must_use]
+    pub const fn order(&self) -> ByteOrder {
+        self.order
+    }
+
+    /// Mask covering the bits of the signal once shifted to the least significant bits.
+    #[inline]
+    const fn mask(&self) -> u64 {
+        u64::MAX >> (64 - self.length as u32)
+    }
+
+    /// Interpret `frame` as a [`u64`] such that the signal's bits are contiguous.
+    #[inline]
+    fn load(&self, frame: &[u8; 8]) -> u64 {
+        match self.order {
+            ByteOrder::Le => u64::from_le_bytes(*frame),
+            ByteOrder::Be => u64::from_be_bytes(*frame),
+        }
+    }
+
+    /// Extract the raw bits of the signal from `frame`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::can::Signal;
+    /// use lilbig::ByteOrder;
+    ///
+    /// let intel = Signal::try_new(8, 16, ByteOrder::Le)?;
+    /// let motorola = Signal::try_new(15, 16, ByteOrder::Be)?;
+    ///
+    /// let frame = [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0];
+    /// assert_eq!(intel.extract_raw(&frame), 0x5634);
+    /// assert_eq!(motorola.extract_raw(&frame), 0x3456);
+    /// # Ok::<(), lilbig::can::SignalError>(())
+    /// ```
+    #[must_use]
+    pub fn extract_raw(&self, frame: &[u8; 8]) -> u64 {
+        (self.load(frame) >> self.shift) & self.mask()
+    }
+
+    /// Inject the raw bits of `value` into the signal's bits within `frame`, leaving all other bits
+    /// of `frame` unmodified.
+    ///
+    /// Bits of `value` beyond the length of the signal are discarded.
+    pub fn inject_raw(&self, frame: &mut [u8; 8], value: u64) {
+        let mask = self.mask() << self.shift;
+        let payload = (self.load(frame) & !mask) | ((value << self.shift) & mask);
+        *frame = match self.order {
+            ByteOrder::Le => payload.to_le_bytes(),
+            ByteOrder::Be => payload.to_be_bytes(),
+        };
+    }
+
+    /// Extract the value of the signal from `frame`, sign-extending it if the signal is signed.
+    ///
+    /// Unsigned 64-bit signals whose value exceeds [`i64::MAX`] are returned with their bits
+    /// reinterpreted as an [`i64`]. Use [`extract_raw()`](Signal::extract_raw) to retrieve such
+    /// signals without reinterpretation.
+    #[must_use]
+    pub fn extract(&self, frame: &[u8; 8]) -> i64 {
+        let raw = self.extract_raw(frame);
+        if self.signed {
+            let unused = 64 - self.length as u32;
+            ((raw << unused) as i64) >> unused
+        } else {
+            raw as i64
+        }
+    }
+
+    /// Inject `value` into the signal's bits within `frame` as a two's complement integer, leaving
+    /// all other bits of `frame` unmodified.
+    ///
+    /// Bits of `value` beyond the length of the signal are discarded.
+    pub fn inject(&self, frame: &mut [u8; 8], value: i64) {
+        self.inject_raw(frame, value as u64);
+    }
+}
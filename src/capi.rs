@@ -0,0 +1,74 @@
+//! C ABI exports of the crate's byte-order swapping utilities.
+//!
+//! The functions within this module are exported with unmangled symbol names so that they can be
+//! linked against from C. A matching header is provided at `include/lilbig.h`.
+//!
+//! Cargo does not permit the crate type of a library to vary by feature. Consumers wishing to link
+//! the exports into a C program should either depend upon this crate from their own `staticlib` or
+//! `cdylib` crate, or build this crate directly with a command such as:
+//! ```text
+//! cargo rustc --release --features capi --crate-type staticlib
+//! ```
+
+use core::ffi::c_int;
+
+/// Status returned by [`lilbig_swap_buffer()`] upon success.
+pub const LILBIG_OK: c_int = 0;
+
+/// Status returned by [`lilbig_swap_buffer()`] when provided a null pointer for a non-empty buffer.
+pub const LILBIG_ERR_NULL: c_int = -1;
+
+/// Status returned by [`lilbig_swap_buffer()`] when provided an element width of zero.
+pub const LILBIG_ERR_WIDTH: c_int = -2;
+
+/// Status returned by [`lilbig_swap_buffer()`] when provided a buffer length that is not a multiple
+/// of the element width.
+pub const LILBIG_ERR_LENGTH: c_int = -3;
+
+/// Unconditionally swap the byte-order of a 16-bit value.
+#[no_mangle]
+pub extern "C" fn lilbig_swap16(value: u16) -> u16 {
+    value.swap_bytes()
+}
+
+/// Unconditionally swap the byte-order of a 32-bit value.
+#[no_mangle]
+pub extern "C" fn lilbig_swap32(value: u32) -> u32 {
+    value.swap_bytes()
+}
+
+/// Unconditionally swap the byte-order of a 64-bit value.
+#[no_mangle]
+pub extern "C" fn lilbig_swap64(value: u64) -> u64 {
+    value.swap_bytes()
+}
+
+/// Unconditionally swap the byte-order of every `width` byte wide element within the `len` bytes
+/// pointed to by `ptr`.
+///
+/// Returns [`LILBIG_OK`] upon success. Otherwise one of the `LILBIG_ERR_*` statuses is returned and
+/// the buffer is left unmodified.
+///
+/// # Safety
+/// Unless `len` is zero, `ptr` must be valid for reads and writes of `len` bytes and the memory it
+/// points to must not be accessed through any other pointer for the duration of the call.
+#[no_mangle]
+pub unsafe extern "C" fn lilbig_swap_buffer(ptr: *mut u8, len: usize, width: usize) -> c_int {
+    if width == 0 {
+        return LILBIG_ERR_WIDTH;
+    }
+    if !len.is_multiple_of(width) {
+        return LILBIG_ERR_LENGTH;
+    }
+    if len == 0 {
+        return LILBIG_OK;
+    }
+    if ptr.is_null() {
+        return LILBIG_ERR_NULL;
+    }
+
+    // SAFETY: The caller guarantees that `ptr` is valid for reads and writes of `len` bytes.
+    let bytes = unsafe { core::slice::from_raw_parts_mut(ptr, len) };
+    crate::bulk::swap_elements_unchecked(bytes, width);
+    LILBIG_OK
+}
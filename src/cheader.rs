@@ -0,0 +1,596 @@
+//! Layouts of structs parsed from C headers, for catching Rust structs that have drifted from the C
+//! structs they mirror.
+//!
+//! [`parse_struct()`] computes the layout of a struct declared within a C header according to the
+//! C layout rules of the target, and flattens it into a table of fields just as [`SwapLayout`] does
+//! for Rust types. [`CStruct::diff()`] then compares the two, reporting each field whose offset or
+//! width differs along with any difference in the size of the structs.
+//!
+//! The parser understands the subset of C used by plain-data structs: the standard integer types,
+//! the `<stdint.h>` fixed-width types, `float`, `double`, `bool`, arrays of constant length, nested
+//! structs declared earlier within the same header, and typedefs of all of these. Comments and
+//! preprocessor directives are ignored. Pointers, bit-fields, unions, and packed structs are
+//! rejected.
+//!
+//! Layouts described by other sources, such as DWARF debugging information or a JSON description,
+//! may be converted to [`FieldLayout`]s and compared against a [`SwapLayout`] table with
+//! [`layout::diff_fields()`].
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # fn main() {
+//! use lilbig::cheader;
+//! use lilbig::layout::SwapLayout;
+//! use lilbig::FieldsByteOrdered;
+//!
+//! const HEADER: &str = "
+//!     #include <stdint.h>
+//!
+//!     /* Header of a sample block. */
+//!     struct block_header {
+//!         uint32_t magic;
+//!         uint16_t version;
+//!         uint16_t channels[2];
+//!         uint64_t timestamp;
+//!     };
+//! ";
+//!
+//! #[derive(FieldsByteOrdered, SwapLayout)]
+//! #[repr(C)]
+//! struct BlockHeader {
+//!     magic: u32,
+//!     version: u16,
+//!     channels: [u16; 2],
+//!     timestamp: u64,
+//! }
+//!
+//! let c_struct = cheader::parse_struct(HEADER, "block_header").unwrap();
+//! let diff = c_struct.diff::<BlockHeader>();
+//! assert!(diff.is_empty(), "{diff}");
+//!
+//! // A struct that has drifted from the header.
+//! #[derive(FieldsByteOrdered, SwapLayout)]
+//! #[repr(C)]
+//! struct StaleBlockHeader {
+//!     magic: u32,
+//!     version: u32,
+//!     timestamp: u64,
+//! }
+//!
+//! let diff = c_struct.diff::<StaleBlockHeader>();
+//! assert_eq!(
+//!     diff.to_string(),
+//!     "size: expected 24 bytes, found 16 bytes\n\
+//!      field 1: expected 2 bytes at offset 4, found 4 bytes at offset 4\n\
+//!      field 2: expected 2 bytes at offset 6, found 8 bytes at offset 8\n\
+//!      field 3: expected 2 bytes at offset 8, found no field\n\
+//!      field 4: expected 8 bytes at offset 16, found no field",
+//! );
+//! # }
+//! # #[cfg(not(feature = "derive"))]
+//! # fn main() {}
+//! ```
+//!
+//! Comparing a struct holding a `bool`:
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # fn main() {
+//! use lilbig::cheader;
+//! use lilbig::layout::SwapLayout;
+//! use lilbig::FieldsByteOrdered;
+//!
+//! #[derive(FieldsByteOrdered, SwapLayout)]
+//! #[repr(C)]
+//! struct Status {
+//!     ready: bool,
+//!     level: u8,
+//!     count: u16,
+//! }
+//!
+//! let header = "struct status { bool ready; uint8_t level; uint16_t count; };";
+//! let diff = cheader::parse_struct(header, "status").unwrap().diff::<Status>();
+//! assert!(diff.is_empty(), "{diff}");
+//! # }
+//! # #[cfg(not(feature = "derive"))]
+//! # fn main() {}
+//! ```
+
+use crate::layout::{self, FieldLayout, FieldMismatch, SwapLayout, TypeLayout};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::mem::{align_of, size_of};
+
+/// Maximum number of fields of primitive types within a parsed struct, which bounds the memory
+/// allocated for headers declaring huge arrays.
+const MAX_FIELDS: usize = 1 << 20;
+
+/// Error returned when a struct could not be parsed from a C header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HeaderError {
+    /// No struct of the requested name was declared within the header.
+    NotFound,
+    /// A member was of a type that is not supported, such as a pointer, a bit-field, a union, or a
+    /// struct not declared earlier within the header.
+    UnsupportedType,
+    /// The header could not be parsed.
+    Syntax,
+    /// A struct was too large to be laid out, either because its size overflowed a `usize` or
+    /// because it contained too many fields.
+    TooLarge,
+}
+
+impl HeaderError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::NotFound => "struct not found within header",
+            Self::UnsupportedType => "struct contains a member of an unsupported type",
+            Self::Syntax => "header could not be parsed",
+            Self::TooLarge => "struct is too large to be laid out",
+        }
+    }
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for HeaderError {}
+
+/// Layout of a struct parsed from a C header.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CStruct {
+    /// Size of the struct in bytes.
+    size: usize,
+    /// Alignment of the struct in bytes.
+    align: usize,
+    /// Fields of primitive types within the struct, with arrays and nested structs flattened.
+    fields: Vec<FieldLayout>,
+}
+
+impl CStruct {
+    /// Retrieve the size of the struct in bytes.
+    #[inline]
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Retrieve the alignment of the struct in bytes.
+    #[inline]
+    #[must_use]
+    pub fn align(&self) -> usize {
+        self.align
+    }
+
+    /// Retrieve the fields of primitive types within the struct, with arrays and nested structs
+    /// flattened in the same manner as a [`SwapLayout`] table.
+    #[inline]
+    #[must_use]
+    pub fn fields(&self) -> &[FieldLayout] {
+        &self.fields
+    }
+
+    /// Retrieve a runtime layout of records of the struct.
+    #[inline]
+    #[must_use]
+    pub fn layout(&self) -> TypeLayout<'_> {
+        TypeLayout::new(self.size, &self.fields)
+    }
+
+    /// Compare the struct against the table of `T`, treating the struct as expected.
+    #[must_use]
+    pub fn diff<T: SwapLayout>(&self) -> LayoutDiff {
+        let actual_size = size_of::<T>();
+        LayoutDiff {
+            size: (self.size != actual_size).then_some((self.size, actual_size)),
+            fields: layout::diff_fields(self.fields.iter().copied(), T::swap_fields()).collect(),
+        }
+    }
+}
+
+/// Differences between the layout of a C struct and that of a Rust type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LayoutDiff {
+    /// The expected and actual sizes in bytes, if they differ.
+    pub size: Option<(usize, usize)>,
+    /// The fields whose offsets or widths differ.
+    pub fields: Vec<FieldMismatch>,
+}
+
+impl LayoutDiff {
+    /// Check whether the layouts are identical.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.size.is_none() && self.fields.is_empty()
+    }
+}
+
+/// Lists each difference upon its own line.
+impl core::fmt::Display for LayoutDiff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut separator = "";
+        if let Some((expected, actual)) = self.size {
+            write!(f, "size: expected {expected} bytes, found {actual} bytes")?;
+            separator = "\n";
+        }
+        for field in &self.fields {
+            write!(f, "{separator}{field}")?;
+            separator = "\n";
+        }
+        Ok(())
+    }
+}
+
+/// Parse the struct named `name` from the C source of `header`.
+///
+/// The struct may be named either by its tag, as in `struct name { ... };`, or by a typedef, as in
+/// `typedef struct { ... } name;`. The declarations preceding it within the header are parsed as
+/// well, such that it may contain the structs and typedefs that they declare.
+///
+/// # Errors
+/// Returns [`HeaderError::NotFound`] if no struct named `name` is declared,
+/// [`HeaderError::UnsupportedType`] if it contains a member of an unsupported type,
+/// [`HeaderError::TooLarge`] if it or a preceding struct is too large to be laid out, or
+/// [`HeaderError::Syntax`] if the header could not be parsed.
+pub fn parse_struct(header: &str, name: &str) -> Result<CStruct, HeaderError> {
+    let tokens = tokenize(header)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        position: 0,
+        types: BTreeMap::new(),
+    };
+    while parser.peek().is_some() {
+        parser.parse_declaration()?;
+        match parser.types.remove(name) {
+            Some(CType::Struct(c_struct)) => return Ok(c_struct),
+            Some(CType::Unsupported) => return Err(HeaderError::UnsupportedType),
+            _ => {}
+        }
+    }
+    Err(HeaderError::NotFound)
+}
+
+/// Type of a member of a C struct.
+#[derive(Debug, Clone)]
+enum CType {
+    /// Primitive type of a size and alignment in bytes.
+    Primitive(usize, usize),
+    /// Struct type.
+    Struct(CStruct),
+    /// Type that is not supported, such as a pointer or a struct containing one.
+    Unsupported,
+}
+
+impl CType {
+    /// Retrieve the size and alignment of the type in bytes.
+    fn size_align(&self) -> Result<(usize, usize), HeaderError> {
+        match self {
+            Self::Primitive(size, align) => Ok((*size, *align)),
+            Self::Struct(c_struct) => Ok((c_struct.size, c_struct.align)),
+            Self::Unsupported => Err(HeaderError::UnsupportedType),
+        }
+    }
+
+    /// Retrieve the number of fields of primitive types within the type.
+    fn field_count(&self) -> usize {
+        match self {
+            Self::Primitive(..) => 1,
+            Self::Struct(c_struct) => c_struct.fields.len(),
+            Self::Unsupported => 0,
+        }
+    }
+
+    /// Append the fields of the type at `offset` to `fields`.
+    ///
+    /// # Errors
+    /// Returns [`HeaderError::TooLarge`] if `fields` would hold more than [`MAX_FIELDS`] fields.
+    fn push_fields(&self, offset: usize, fields: &mut Vec<FieldLayout>) -> Result<(), HeaderError> {
+        if fields.len() + self.field_count() > MAX_FIELDS {
+            return Err(HeaderError::TooLarge);
+        }
+        match self {
+            Self::Primitive(size, _) => fields.push(FieldLayout::new(offset, *size)),
+            Self::Struct(c_struct) => {
+                fields.extend(c_struct.fields.iter().map(|field| field.offset_by(offset)))
+            }
+            Self::Unsupported => {}
+        }
+        Ok(())
+    }
+}
+
+/// Check whether `token` is a keyword of a primitive type specifier.
+fn is_primitive_keyword(token: &str) -> bool {
+    matches!(
+        token,
+        "signed" | "unsigned" | "char" | "short" | "int" | "long" | "float" | "double"
+    )
+}
+
+/// Check whether `token` is an identifier.
+fn is_identifier(token: &str) -> bool {
+    token.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+}
+
+/// Retrieve the primitive type named by the words of a type specifier, such as `unsigned long`.
+fn primitive(words: &[&str]) -> CType {
+    /// Create a primitive type of the same size and alignment as `T`.
+    fn of<T>() -> CType {
+        CType::Primitive(size_of::<T>(), align_of::<T>())
+    }
+
+    let words = words
+        .iter()
+        .filter(|&&word| !matches!(word, "signed" | "unsigned" | "int"))
+        .copied()
+        .collect::<Vec<_>>();
+    match words.as_slice() {
+        ["char"] | ["int8_t"] | ["uint8_t"] | ["bool"] | ["_Bool"] => of::<u8>(),
+        ["short"] | ["int16_t"] | ["uint16_t"] => of::<u16>(),
+        [] => of::<core::ffi::c_int>(),
+        ["int32_t"] | ["uint32_t"] => of::<i32>(),
+        ["long"] => of::<core::ffi::c_long>(),
+        ["long", "long"] => of::<core::ffi::c_longlong>(),
+        ["int64_t"] | ["uint64_t"] => of::<i64>(),
+        ["float"] => of::<f32>(),
+        ["double"] => of::<f64>(),
+        ["size_t"] | ["ssize_t"] | ["ptrdiff_t"] | ["intptr_t"] | ["uintptr_t"] => of::<usize>(),
+        _ => CType::Unsupported,
+    }
+}
+
+/// Split `header` into tokens, discarding comments and preprocessor directives.
+fn tokenize(header: &str) -> Result<Vec<&str>, HeaderError> {
+    let mut tokens = Vec::new();
+    let mut rest = header;
+    let mut line_start = true;
+    while let Some(c) = rest.chars().next() {
+        let len = if c == '\n' {
+            line_start = true;
+            1
+        } else if c.is_whitespace() {
+            c.len_utf8()
+        } else if (line_start && c == '#') || rest.starts_with("//") {
+            rest.find('\n').unwrap_or(rest.len())
+        } else if rest.starts_with("/*") {
+            rest.find("*/").ok_or(HeaderError::Syntax)? + 2
+        } else {
+            line_start = false;
+            let len = if c.is_ascii_alphanumeric() || c == '_' {
+                rest.find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .unwrap_or(rest.len())
+            } else {
+                c.len_utf8()
+            };
+            tokens.push(rest.get(..len).ok_or(HeaderError::Syntax)?);
+            len
+        };
+        rest = rest.get(len..).ok_or(HeaderError::Syntax)?;
+    }
+    Ok(tokens)
+}
+
+/// Parser of the declarations within a C header.
+struct Parser<'a> {
+    /// Tokens of the header.
+    tokens: &'a [&'a str],
+    /// Position of the next token.
+    position: usize,
+    /// Structs and typedefs declared so far, by name.
+    types: BTreeMap<&'a str, CType>,
+}
+
+impl<'a> Parser<'a> {
+    /// Retrieve the next token without consuming it.
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.position).copied()
+    }
+
+    /// Consume the next token.
+    fn next(&mut self) -> Result<&'a str, HeaderError> {
+        let token = self.peek().ok_or(HeaderError::Syntax)?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    /// Consume the next token if it is `token`.
+    fn eat(&mut self, token: &str) -> bool {
+        let matches = self.peek() == Some(token);
+        if matches {
+            self.position += 1;
+        }
+        matches
+    }
+
+    /// Consume the next token, which must be `token`.
+    fn expect(&mut self, token: &str) -> Result<(), HeaderError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(HeaderError::Syntax)
+        }
+    }
+
+    /// Skip the tokens through the end of the current declaration, or through the closing brace
+    /// matching the next opening brace if `body`.
+    fn skip(&mut self, body: bool) -> Result<(), HeaderError> {
+        let mut depth = 0usize;
+        loop {
+            match self.next()? {
+                "{" => depth += 1,
+                "}" => {
+                    depth = depth.checked_sub(1).ok_or(HeaderError::Syntax)?;
+                    if body && depth == 0 {
+                        return Ok(());
+                    }
+                }
+                ";" if !body && depth == 0 => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Parse a top-level declaration, recording any struct or typedef that it declares.
+    fn parse_declaration(&mut self) -> Result<(), HeaderError> {
+        let typedef = self.eat("typedef");
+        if !typedef && self.peek() != Some("struct") {
+            return self.skip(false);
+        }
+        let ty = self.parse_type()?;
+        // Declarations of variables and functions, and typedefs of pointers and function pointers,
+        // declare no types of interest.
+        if !typedef || !self.peek().is_some_and(is_identifier) {
+            return self.skip(false);
+        }
+        let name = self.next()?;
+        let ty = self.parse_array(ty)?;
+        self.expect(";")?;
+        self.types.insert(name, ty);
+        Ok(())
+    }
+
+    /// Parse a type specifier, recording the struct that it defines, if any.
+    fn parse_type(&mut self) -> Result<CType, HeaderError> {
+        while self.eat("const") || self.eat("volatile") {}
+        if self.eat("struct") {
+            let tag = match self.peek() {
+                Some("{") => None,
+                _ => Some(self.next()?),
+            };
+            let ty = if self.peek() == Some("{") {
+                let start = self.position;
+                let ty = match self.parse_body() {
+                    Ok(c_struct) => CType::Struct(c_struct),
+                    Err(HeaderError::UnsupportedType) => {
+                        self.position = start;
+                        self.skip(true)?;
+                        CType::Unsupported
+                    }
+                    Err(err) => return Err(err),
+                };
+                if let Some(tag) = tag {
+                    self.types.insert(tag, ty.clone());
+                }
+                ty
+            } else {
+                let tag = tag.ok_or(HeaderError::Syntax)?;
+                match self.types.get(tag) {
+                    Some(ty @ CType::Struct(_)) => ty.clone(),
+                    _ => CType::Unsupported,
+                }
+            };
+            while self.eat("const") || self.eat("volatile") {}
+            return Ok(ty);
+        }
+
+        let mut words = Vec::new();
+        while let Some(token) = self.peek() {
+            if is_primitive_keyword(token) {
+                words.push(self.next()?);
+            } else if words.is_empty() && is_identifier(token) {
+                words.push(self.next()?);
+                break;
+            } else {
+                break;
+            }
+        }
+        while self.eat("const") || self.eat("volatile") {}
+        Ok(match words.as_slice() {
+            [name] if !is_primitive_keyword(name) => self
+                .types
+                .get(name)
+                .cloned()
+                .unwrap_or_else(|| primitive(&words)),
+            _ => primitive(&words),
+        })
+    }
+
+    /// Parse the body of a struct, beginning with its opening brace.
+    fn parse_body(&mut self) -> Result<CStruct, HeaderError> {
+        self.expect("{")?;
+        let mut c_struct = CStruct {
+            size: 0,
+            align: 1,
+            fields: Vec::new(),
+        };
+        while !self.eat("}") {
+            if self.peek() == Some("union") {
+                return Err(HeaderError::UnsupportedType);
+            }
+            let ty = self.parse_type()?;
+            loop {
+                if self.peek() == Some("*") {
+                    return Err(HeaderError::UnsupportedType);
+                }
+                let _name = self.next()?;
+                if self.peek() == Some(":") {
+                    return Err(HeaderError::UnsupportedType);
+                }
+                let ty = self.parse_array(ty.clone())?;
+                let (size, align) = ty.size_align()?;
+                let offset = c_struct
+                    .size
+                    .checked_next_multiple_of(align)
+                    .ok_or(HeaderError::TooLarge)?;
+                ty.push_fields(offset, &mut c_struct.fields)?;
+                c_struct.size = offset.checked_add(size).ok_or(HeaderError::TooLarge)?;
+                c_struct.align = c_struct.align.max(align);
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            self.expect(";")?;
+        }
+        c_struct.size = c_struct
+            .size
+            .checked_next_multiple_of(c_struct.align)
+            .ok_or(HeaderError::TooLarge)?;
+        Ok(c_struct)
+    }
+
+    /// Parse the array dimensions following a declarator, if any, applying them to `ty`.
+    fn parse_array(&mut self, ty: CType) -> Result<CType, HeaderError> {
+        let mut dimensions = Vec::new();
+        while self.eat("[") {
+            let len = self.next()?;
+            let len = len
+                .trim_end_matches(['u', 'U', 'l', 'L'])
+                .parse::<usize>()
+                .map_err(|_| HeaderError::Syntax)?;
+            self.expect("]")?;
+            dimensions.push(len);
+        }
+        // The innermost dimension is the last to be declared. The fields of each element are
+        // counted before any are pushed, such that a huge dimension is rejected without first
+        // allocating its fields, and elements without fields are not iterated.
+        dimensions.into_iter().rev().try_fold(ty, |ty, len| {
+            let (size, align) = ty.size_align()?;
+            let array_size = size.checked_mul(len).ok_or(HeaderError::TooLarge)?;
+            let field_count = ty
+                .field_count()
+                .checked_mul(len)
+                .filter(|&count| count <= MAX_FIELDS)
+                .ok_or(HeaderError::TooLarge)?;
+            let mut fields = Vec::with_capacity(field_count);
+            if field_count > 0 {
+                // The offsets do not overflow, as none exceeds `array_size`.
+                for index in 0..len {
+                    ty.push_fields(index * size, &mut fields)?;
+                }
+            }
+            Ok(CType::Struct(CStruct {
+                size: array_size,
+                align,
+                fields,
+            }))
+        })
+    }
+}
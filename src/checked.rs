@@ -0,0 +1,238 @@
+//! Runtime tracking of the byte-order of values.
+//!
+//! The most common mistake when converting between byte-orders is converting a value twice, which
+//! silently restores its original byte-order. [`OrderTracked`] holds a value along with the
+//! byte-order it is currently stored in and whether it has been normalized to the machine's native
+//! byte-order, and reports normalizing the value a second time or reading it in a byte-order other
+//! than the one it is stored in. Each check has a panicking variant and a fallible `try_` variant,
+//! and violations are additionally logged when the `trace` feature is enabled.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! # #[cfg(all(feature = "checked", not(feature = "no-panic")))]
+//! # fn main() {
+//! use lilbig::checked::{CheckedError, OrderTracked};
+//! use lilbig::ByteOrder;
+//!
+//! let mut length = OrderTracked::new(0x1234_u16.to_be(), ByteOrder::Be);
+//! assert_eq!(length.try_get(), Err(CheckedError::WrongOrder));
+//!
+//! length.normalize();
+//! assert_eq!(length.get(), 0x1234);
+//! assert_eq!(length.try_normalize(), Err(CheckedError::NormalizedTwice));
+//!
+//! length.encode(ByteOrder::Be);
+//! assert_eq!(length.get_in(ByteOrder::Be), 0x1234_u16.to_be());
+//! # }
+//! # #[cfg(not(all(feature = "checked", not(feature = "no-panic"))))]
+//! # fn main() {}
+//! ```
+
+use crate::{ByteOrder, FieldsByteOrdered};
+
+/// Error returned when a tracked value is converted or read inconsistently with its byte-order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CheckedError {
+    /// A value was normalized to the machine's native byte-order after it had already been
+    /// normalized.
+    NormalizedTwice,
+    /// A value was read in a byte-order other than the one it is stored in.
+    WrongOrder,
+}
+
+impl CheckedError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::NormalizedTwice => "value normalized to the native byte-order twice",
+            Self::WrongOrder => "value read in a byte-order other than the one it is stored in",
+        }
+    }
+}
+
+impl core::fmt::Display for CheckedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for CheckedError {}
+
+/// Value held along with the byte-order it is currently stored in and whether it has been
+/// normalized to the machine's native byte-order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderTracked<T> {
+    /// Held value in the tracked byte-order.
+    value: T,
+    /// Byte-order the value is currently stored in.
+    order: ByteOrder,
+    /// Whether the value has been normalized since it was last encoded in a foreign byte-order.
+    normalized: bool,
+}
+
+impl<T: FieldsByteOrdered> OrderTracked<T> {
+    /// Create a tracker of `value`, which is stored in `order` and has yet to be normalized.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: T, order: ByteOrder) -> Self {
+        Self {
+            value,
+            order,
+            normalized: false,
+        }
+    }
+
+    /// Create a tracker of `value`, which is in the machine's native byte-order and is thereby
+    /// considered normalized.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_ne(value: T) -> Self {
+        Self {
+            value,
+            order: ByteOrder::NATIVE,
+            normalized: true,
+        }
+    }
+
+    /// Retrieve the byte-order the held value is currently stored in.
+    #[inline(always)]
+    #[must_use]
+    pub const fn order(&self) -> ByteOrder {
+        self.order
+    }
+
+    /// Retrieve whether the held value has been normalized to the machine's native byte-order.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_normalized(&self) -> bool {
+        self.normalized
+    }
+
+    /// Convert the held value to the machine's native byte-order.
+    ///
+    /// # Panics
+    /// Panics if the value has already been normalized. See
+    /// [`try_normalize()`](OrderTracked::try_normalize) for a non-panicking alternative.
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    pub fn normalize(&mut self) {
+        if let Err(err) = self.try_normalize() {
+            panic!("{err}");
+        }
+    }
+
+    /// Convert the held value to the machine's native byte-order.
+    ///
+    /// This is the non-panicking alternative of `normalize()`.
+    ///
+    /// # Errors
+    /// Returns [`CheckedError::NormalizedTwice`] without modifying the value if it has already been
+    /// normalized.
+    pub fn try_normalize(&mut self) -> Result<(), CheckedError> {
+        if self.normalized {
+            return Err(Self::violation(CheckedError::NormalizedTwice));
+        }
+        self.encode(ByteOrder::NATIVE);
+        Ok(())
+    }
+
+    /// Convert the held value to `order`, such as before writing it.
+    ///
+    /// The value is considered normalized thereafter only if `order` is the machine's native
+    /// byte-order.
+    #[inline]
+    pub fn encode(&mut self, order: ByteOrder) {
+        self.value.order_fields_as(self.order, order);
+        self.order = order;
+        self.normalized = order == ByteOrder::NATIVE;
+    }
+
+    /// Retrieve a copy of the held value, which must be in the machine's native byte-order.
+    ///
+    /// # Panics
+    /// Panics if the value is not in the machine's native byte-order. See
+    /// [`try_get()`](OrderTracked::try_get) for a non-panicking alternative.
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[must_use]
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.get_in(ByteOrder::NATIVE)
+    }
+
+    /// Retrieve a copy of the held value, which must be in the machine's native byte-order.
+    ///
+    /// This is the non-panicking alternative of `get()`.
+    ///
+    /// # Errors
+    /// Returns [`CheckedError::WrongOrder`] if the value is not in the machine's native byte-order.
+    #[inline]
+    pub fn try_get(&self) -> Result<T, CheckedError>
+    where
+        T: Clone,
+    {
+        self.try_get_in(ByteOrder::NATIVE)
+    }
+
+    /// Retrieve a copy of the held value, which must be stored in `order`.
+    ///
+    /// # Panics
+    /// Panics if the value is not stored in `order`. See
+    /// [`try_get_in()`](OrderTracked::try_get_in) for a non-panicking alternative.
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[must_use]
+    pub fn get_in(&self, order: ByteOrder) -> T
+    where
+        T: Clone,
+    {
+        match self.try_get_in(order) {
+            Ok(value) => value,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Retrieve a copy of the held value, which must be stored in `order`.
+    ///
+    /// This is the non-panicking alternative of `get_in()`.
+    ///
+    /// # Errors
+    /// Returns [`CheckedError::WrongOrder`] if the value is not stored in `order`.
+    pub fn try_get_in(&self, order: ByteOrder) -> Result<T, CheckedError>
+    where
+        T: Clone,
+    {
+        if self.order != order {
+            return Err(Self::violation(CheckedError::WrongOrder));
+        }
+        Ok(self.value.clone())
+    }
+
+    /// Replace the held value with `value`, which is in the machine's native byte-order, storing it
+    /// in the tracked byte-order.
+    #[inline]
+    pub fn set_ne(&mut self, mut value: T) {
+        value.order_fields_as(ByteOrder::NATIVE, self.order);
+        self.value = value;
+    }
+
+    /// Retrieve the held value and its byte-order without converting the value.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_parts(self) -> (T, ByteOrder) {
+        (self.value, self.order)
+    }
+
+    /// Report the violation `err` detected while tracking the byte-order of the held value.
+    #[inline]
+    fn violation(err: CheckedError) -> CheckedError {
+        #[cfg(feature = "trace")]
+        crate::trace::violation::<T>(err);
+        err
+    }
+}
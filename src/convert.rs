@@ -0,0 +1,78 @@
+//! Free functions converting values between a fixed byte-order and the machine's native
+//! byte-order.
+//!
+//! These are shorthands for the methods of [`ByteOrdered`] and [`FieldsByteOrdered`] whose names
+//! state the direction of each conversion, such that the byte-order of a value is evident where it
+//! is converted.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! use lilbig::convert;
+//!
+//! let length = convert::be_to_ne(0x1234_u16.to_be());
+//! assert_eq!(length, 0x1234);
+//! assert_eq!(convert::ne_to_le(length), 0x1234_u16.to_le());
+//!
+//! let mut samples = [1_u32.to_le(), 2_u32.to_le()];
+//! convert::le_to_ne_slice(&mut samples);
+//! assert_eq!(samples, [1, 2]);
+//! ```
+
+use crate::{ByteOrder, ByteOrdered, FieldsByteOrdered};
+
+/// Convert `value` from little-endian to the machine's native byte-order.
+#[inline]
+#[must_use]
+pub fn le_to_ne<T: ByteOrdered>(value: T) -> T {
+    value.ordered_ne(ByteOrder::Le)
+}
+
+/// Convert `value` from big-endian to the machine's native byte-order.
+#[inline]
+#[must_use]
+pub fn be_to_ne<T: ByteOrdered>(value: T) -> T {
+    value.ordered_ne(ByteOrder::Be)
+}
+
+/// Convert `value` from the machine's native byte-order to little-endian.
+#[inline]
+#[must_use]
+pub fn ne_to_le<T: ByteOrdered>(value: T) -> T {
+    value.ordered_le(ByteOrder::NATIVE)
+}
+
+/// Convert `value` from the machine's native byte-order to big-endian.
+#[inline]
+#[must_use]
+pub fn ne_to_be<T: ByteOrdered>(value: T) -> T {
+    value.ordered_be(ByteOrder::NATIVE)
+}
+
+/// Convert the fields of each element of `values` from little-endian to the machine's native
+/// byte-order.
+#[inline]
+pub fn le_to_ne_slice<T: FieldsByteOrdered>(values: &mut [T]) {
+    values.order_fields_ne(ByteOrder::Le);
+}
+
+/// Convert the fields of each element of `values` from big-endian to the machine's native
+/// byte-order.
+#[inline]
+pub fn be_to_ne_slice<T: FieldsByteOrdered>(values: &mut [T]) {
+    values.order_fields_ne(ByteOrder::Be);
+}
+
+/// Convert the fields of each element of `values` from the machine's native byte-order to
+/// little-endian.
+#[inline]
+pub fn ne_to_le_slice<T: FieldsByteOrdered>(values: &mut [T]) {
+    values.order_fields_le(ByteOrder::NATIVE);
+}
+
+/// Convert the fields of each element of `values` from the machine's native byte-order to
+/// big-endian.
+#[inline]
+pub fn ne_to_be_slice<T: FieldsByteOrdered>(values: &mut [T]) {
+    values.order_fields_be(ByteOrder::NATIVE);
+}
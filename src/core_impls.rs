@@ -1,6 +1,11 @@
-//! Implementations of [`ByteOrdered`] and [`FieldsByteOrdered`] on [`core`] types.
+//! Implementations of [`ByteOrdered`], [`FieldsByteOrdered`], and [`WireSafe`] on [`core`] types.
 
-use crate::{ByteOrdered, FieldsByteOrdered};
+use crate::{ByteOrdered, FieldsByteOrdered, FieldsByteOrderedWith, WireSafe};
+use core::marker::PhantomData;
+use core::ops::{Bound, ControlFlow};
+#[cfg(feature = "simd")]
+use core::simd::num::{SimdInt, SimdUint};
+use core::task::Poll;
 
 /// Implement both [`ByteOrdered`] and [`FieldsByteOrdered`] as NOPs for a set of types.
 macro_rules! impl_ordered_nop {
@@ -16,6 +21,8 @@ macro_rules! impl_ordered_nop {
         /// Provided for completeness. Single byte values satisfy all byte-orders thus this
         /// fucntion applies no modifications to `self`.
         impl FieldsByteOrdered for $ty {
+            const IS_ORDER_INVARIANT: bool = true;
+
             #[inline(always)]
             fn swap_field_orders(&mut self) {}
         })+
@@ -36,6 +43,8 @@ macro_rules! impl_ordered_int {
         impl FieldsByteOrdered for $ty {
             #[inline]
             fn swap_field_orders(&mut self) {
+                #[cfg(feature = "swap-trace")]
+                crate::swap_trace::swap(self);
                 *self = self.swap_bytes();
             }
         })+
@@ -43,6 +52,7 @@ macro_rules! impl_ordered_int {
 }
 
 /// Implement both [`ByteOrdered`] and [`FieldsByteOrdered`] for a set of core floating point types.
+#[cfg(not(feature = "no-float"))]
 macro_rules! impl_ordered_float {
     ($($ty: ty),+) => {
         $(
@@ -57,29 +67,79 @@ macro_rules! impl_ordered_float {
         impl FieldsByteOrdered for $ty {
             #[inline]
             fn swap_field_orders(&mut self) {
+                #[cfg(feature = "swap-trace")]
+                crate::swap_trace::swap(self);
                 *self = Self::from_bits(self.to_bits().swap_bytes());
             }
         })+
     };
 }
 
-impl_ordered_nop!(i8, u8);
-impl_ordered_int!(i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+impl_ordered_nop!(i8, u8, bool);
+impl_ordered_int!(i16, u16, i32, u32, i64, u64, i128, u128);
+#[cfg(not(feature = "strict-usize"))]
+impl_ordered_int!(isize, usize);
+#[cfg(not(feature = "no-float"))]
 impl_ordered_float!(f32, f64);
 
 /// Unwraps to an implementation of [`FieldsByteOrdered::swap_field_orders()`] that swaps the
 /// byte-order of `self`'s elements by applying [`FieldsByteOrdered::swap_field_orders()`] over the
-/// items yielded by `self.iter_mut()`.
+/// items yielded by `self.iter_mut()`, and likewise of [`FieldsByteOrdered::zero_padding()`] and
+/// [`FieldsByteOrdered::swap_pinned_field_orders()`].
 macro_rules! impl_iter_mut_swap_fields {
     () => {
+        const IS_ORDER_INVARIANT: bool = T::IS_ORDER_INVARIANT;
+        const HAS_PINNED_FIELDS: bool = T::HAS_PINNED_FIELDS;
+
         /// Unconditionally swap the byte-order of all `self`'s elements.
         #[inline]
         fn swap_field_orders(&mut self) {
-            self.iter_mut().for_each(T::swap_field_orders);
+            if !T::IS_ORDER_INVARIANT {
+                self.iter_mut().for_each(T::swap_field_orders);
+            }
+        }
+
+        /// Zero the padding of all `self`'s elements.
+        #[inline]
+        fn zero_padding(&mut self) {
+            self.iter_mut().for_each(T::zero_padding);
+        }
+
+        /// Convert the pinned fields of all `self`'s elements.
+        #[inline]
+        fn swap_pinned_field_orders(&mut self) {
+            if T::HAS_PINNED_FIELDS {
+                self.iter_mut().for_each(T::swap_pinned_field_orders);
+            }
         }
     };
 }
 
+/// Implement both [`ByteOrdered`] and [`FieldsByteOrdered`] for portable SIMD vectors of a set of
+/// core integer types.
+#[cfg(feature = "simd")]
+macro_rules! impl_ordered_simd {
+    ($($ty: ty),+) => {
+        $(/// Unconditionally swap the byte-order of each of `self`'s lanes.
+        impl<const N: usize> ByteOrdered for core::simd::Simd<$ty, N> {
+            #[inline]
+            fn swapped_order(self) -> Self {
+                self.swap_bytes()
+            }
+        }
+        /// Unconditionally swap the byte-order of each of `self`'s lanes.
+        impl<const N: usize> FieldsByteOrdered for core::simd::Simd<$ty, N> {
+            #[inline]
+            fn swap_field_orders(&mut self) {
+                *self = self.swap_bytes();
+            }
+        })+
+    };
+}
+
+#[cfg(feature = "simd")]
+impl_ordered_simd!(i16, u16, i32, u32, i64, u64);
+
 impl<T: FieldsByteOrdered> FieldsByteOrdered for [T] {
     impl_iter_mut_swap_fields!();
 }
@@ -87,3 +147,145 @@ impl<T: FieldsByteOrdered> FieldsByteOrdered for [T] {
 impl<T: FieldsByteOrdered, const N: usize> FieldsByteOrdered for [T; N] {
     impl_iter_mut_swap_fields!();
 }
+
+/// Unconditionally swap the byte-order of all `self`'s elements as determined by `ctx`, which is
+/// cloned for each element.
+impl<T: FieldsByteOrderedWith<Ctx>, Ctx: Clone> FieldsByteOrderedWith<Ctx> for [T] {
+    #[inline]
+    fn swap_field_orders_with(&mut self, ctx: Ctx) {
+        self.iter_mut()
+            .for_each(|element| element.swap_field_orders_with(ctx.clone()));
+    }
+}
+
+/// Unconditionally swap the byte-order of all `self`'s elements as determined by `ctx`, which is
+/// cloned for each element.
+impl<T: FieldsByteOrderedWith<Ctx>, Ctx: Clone, const N: usize> FieldsByteOrderedWith<Ctx>
+    for [T; N]
+{
+    #[inline]
+    fn swap_field_orders_with(&mut self, ctx: Ctx) {
+        self.as_mut_slice().swap_field_orders_with(ctx);
+    }
+}
+
+/// Provided for completeness. The unit type holds no bytes thus this function applies no
+/// modifications to `self`.
+impl FieldsByteOrdered for () {
+    const IS_ORDER_INVARIANT: bool = true;
+
+    #[inline(always)]
+    fn swap_field_orders(&mut self) {}
+}
+
+/// Provided for completeness. Phantom data holds no bytes thus this function applies no
+/// modifications to `self`.
+impl<T: ?Sized> FieldsByteOrdered for PhantomData<T> {
+    const IS_ORDER_INVARIANT: bool = true;
+
+    #[inline(always)]
+    fn swap_field_orders(&mut self) {}
+}
+
+/// Swap the byte-order of the fields of whichever value is held.
+impl<B: FieldsByteOrdered, C: FieldsByteOrdered> FieldsByteOrdered for ControlFlow<B, C> {
+    const IS_ORDER_INVARIANT: bool = B::IS_ORDER_INVARIANT && C::IS_ORDER_INVARIANT;
+    const HAS_PINNED_FIELDS: bool = B::HAS_PINNED_FIELDS || C::HAS_PINNED_FIELDS;
+
+    #[inline]
+    fn swap_field_orders(&mut self) {
+        match self {
+            Self::Continue(value) => value.swap_field_orders(),
+            Self::Break(value) => value.swap_field_orders(),
+        }
+    }
+
+    #[inline]
+    fn zero_padding(&mut self) {
+        match self {
+            Self::Continue(value) => value.zero_padding(),
+            Self::Break(value) => value.zero_padding(),
+        }
+    }
+
+    #[inline]
+    fn swap_pinned_field_orders(&mut self) {
+        match self {
+            Self::Continue(value) => value.swap_pinned_field_orders(),
+            Self::Break(value) => value.swap_pinned_field_orders(),
+        }
+    }
+}
+
+/// Swap the byte-order of the fields of the bound's value, if any.
+impl<T: FieldsByteOrdered> FieldsByteOrdered for Bound<T> {
+    const IS_ORDER_INVARIANT: bool = T::IS_ORDER_INVARIANT;
+    const HAS_PINNED_FIELDS: bool = T::HAS_PINNED_FIELDS;
+
+    #[inline]
+    fn swap_field_orders(&mut self) {
+        if let Self::Included(value) | Self::Excluded(value) = self {
+            value.swap_field_orders();
+        }
+    }
+
+    #[inline]
+    fn zero_padding(&mut self) {
+        if let Self::Included(value) | Self::Excluded(value) = self {
+            value.zero_padding();
+        }
+    }
+
+    #[inline]
+    fn swap_pinned_field_orders(&mut self) {
+        if let Self::Included(value) | Self::Excluded(value) = self {
+            value.swap_pinned_field_orders();
+        }
+    }
+}
+
+/// Swap the byte-order of the fields of the ready value, if any.
+impl<T: FieldsByteOrdered> FieldsByteOrdered for Poll<T> {
+    const IS_ORDER_INVARIANT: bool = T::IS_ORDER_INVARIANT;
+    const HAS_PINNED_FIELDS: bool = T::HAS_PINNED_FIELDS;
+
+    #[inline]
+    fn swap_field_orders(&mut self) {
+        if let Self::Ready(value) = self {
+            value.swap_field_orders();
+        }
+    }
+
+    #[inline]
+    fn zero_padding(&mut self) {
+        if let Self::Ready(value) = self {
+            value.zero_padding();
+        }
+    }
+
+    #[inline]
+    fn swap_pinned_field_orders(&mut self) {
+        if let Self::Ready(value) = self {
+            value.swap_pinned_field_orders();
+        }
+    }
+}
+
+/// Implement [`WireSafe`] for a set of core types.
+macro_rules! impl_wire_safe {
+    ($($ty: ty),+) => {
+        // SAFETY: The primitive integer and floating point types contain no padding and every bit
+        // pattern is a valid value.
+        $(unsafe impl WireSafe for $ty {})+
+    };
+}
+
+impl_wire_safe!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+#[cfg(not(feature = "no-float"))]
+impl_wire_safe!(f32, f64);
+#[cfg(not(feature = "strict-usize"))]
+impl_wire_safe!(isize, usize);
+
+// SAFETY: Arrays contain no padding between their elements, so an array of `WireSafe` elements is
+// itself `WireSafe`.
+unsafe impl<T: WireSafe, const N: usize> WireSafe for [T; N] {}
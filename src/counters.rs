@@ -0,0 +1,74 @@
+//! Per-type counters of conversions between byte-orders.
+//!
+//! Struct-level conversions performed by the `order_fields_*` methods of [`FieldsByteOrdered`]
+//! that swap the byte-order of a value are counted against the name of the value's type.
+//! Conversions that leave a value unmodified are not counted. The counters are shared by all
+//! threads and may be retrieved at any time to find hot or redundant conversion paths.
+//!
+//! [`FieldsByteOrdered`]: crate::FieldsByteOrdered
+//!
+//! # Examples
+//! Counting the conversions of a type:
+//! ```
+//! use lilbig::counters::{self, SwapCount};
+//! use lilbig::{ByteOrder, FieldsByteOrdered};
+//!
+//! let mut samples = [0x0102u16; 8];
+//! samples.order_fields_be(ByteOrder::Le);
+//! samples.order_fields_be(ByteOrder::Be);
+//! samples.order_fields_le(ByteOrder::Be);
+//!
+//! assert_eq!(counters::count_of::<[u16; 8]>(), SwapCount { values: 2, bytes: 32 });
+//! counters::reset();
+//! assert_eq!(counters::count_of::<[u16; 8]>(), SwapCount::default());
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::vec::Vec;
+
+/// Counters of the conversions of the values of a type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SwapCount {
+    /// Number of values whose byte-order was swapped.
+    pub values: u64,
+    /// Number of bytes within the values whose byte-order was swapped.
+    pub bytes: u64,
+}
+
+/// Counters of each type that has been converted, keyed by the name of the type.
+static COUNTS: Mutex<BTreeMap<&'static str, SwapCount>> = Mutex::new(BTreeMap::new());
+
+/// Lock the counters, disregarding any poisoning as the counters are always left consistent.
+fn lock() -> MutexGuard<'static, BTreeMap<&'static str, SwapCount>> {
+    COUNTS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Count the conversion of `value`.
+pub(crate) fn conversion<T: ?Sized>(value: &T) {
+    let bytes = u64::try_from(core::mem::size_of_val(value)).unwrap_or(u64::MAX);
+    let mut counts = lock();
+    let count = counts.entry(core::any::type_name::<T>()).or_default();
+    count.values = count.values.saturating_add(1);
+    count.bytes = count.bytes.saturating_add(bytes);
+}
+
+/// Retrieve the counters of the conversions of values of `T`.
+#[must_use]
+pub fn count_of<T: ?Sized>() -> SwapCount {
+    lock()
+        .get(core::any::type_name::<T>())
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Retrieve the counters of every type that has been converted, ordered by the names of the types.
+#[must_use]
+pub fn snapshot() -> Vec<(&'static str, SwapCount)> {
+    lock().iter().map(|(&name, &count)| (name, count)).collect()
+}
+
+/// Reset the counters of every type.
+pub fn reset() {
+    lock().clear();
+}
@@ -0,0 +1,898 @@
+//! Cursors for reading and writing values of a runtime byte-order from and to byte buffers.
+//!
+//! [`OrderedReader`] reads values from a byte slice, converting them from the reader's byte-order
+//! to the machine's native byte-order. [`OrderedWriter`] writes values to a byte slice, converting
+//! them from the machine's native byte-order to the writer's byte-order. The byte-order of either
+//! cursor may be changed at any point, which suits formats whose byte-order is only known once a
+//! header has been read. [`SegmentedReader`] reads values from a sequence of byte slices, such as
+//! the two halves of a ring buffer, while [`SegmentedWriter`] writes values to a sequence of byte
+//! slices.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! use lilbig::cursor::{CursorError, OrderedReader, OrderedWriter};
+//! use lilbig::ByteOrder;
+//!
+//! let mut buffer = [0u8; 8];
+//! let mut writer = OrderedWriter::new(&mut buffer, ByteOrder::Be);
+//! writer.write(0x1234u16)?;
+//! writer.write_uint(0xabcdef, 3)?;
+//! writer.set_order(ByteOrder::Le);
+//! writer.write(0x5678u16)?;
+//! assert_eq!(writer.written(), [0x12, 0x34, 0xab, 0xcd, 0xef, 0x78, 0x56]);
+//!
+//! let mut reader = OrderedReader::new(&buffer, ByteOrder::Be);
+//! assert_eq!(reader.read::<u16>()?, 0x1234);
+//! assert_eq!(reader.read_uint(3)?, 0xabcdef);
+//! reader.set_order(ByteOrder::Le);
+//! assert_eq!(reader.read::<u16>()?, 0x5678);
+//! assert_eq!(reader.read::<u16>(), Err(CursorError::UnexpectedEnd));
+//! # Ok::<(), CursorError>(())
+//! ```
+
+use crate::{ByteOrder, FieldsByteOrdered, WireSafe};
+use core::mem::MaybeUninit;
+
+/// Error returned when a cursor operation fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CursorError {
+    /// The reader reached the end of its bytes before the operation completed.
+    UnexpectedEnd,
+    /// The writer reached the end of its bytes before the operation completed.
+    BufferFull,
+    /// The width of an integer was zero or greater than 8 bytes.
+    InvalidWidth,
+    /// A value could not be represented within the width available to it.
+    ValueOutOfRange,
+}
+
+impl CursorError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::UnexpectedEnd => "unexpected end of input",
+            Self::BufferFull => "insufficient space remaining in output buffer",
+            Self::InvalidWidth => "integer width must be between 1 and 8 bytes",
+            Self::ValueOutOfRange => "value does not fit within its width",
+        }
+    }
+}
+
+impl core::fmt::Display for CursorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for CursorError {}
+
+/// Check that `width` is a valid width in bytes for an integer read or written by a cursor.
+#[inline]
+const fn check_uint_width(width: usize) -> Result<(), CursorError> {
+    if width == 0 || width > 8 {
+        Err(CursorError::InvalidWidth)
+    } else {
+        Ok(())
+    }
+}
+
+/// Encode `value` as an unsigned integer of `width` bytes in `order`.
+///
+/// Returns the bytes of the value along with the offset of the first of its `width` bytes.
+fn encode_uint(
+    order: ByteOrder,
+    value: u64,
+    width: usize,
+) -> Result<([u8; 8], usize), CursorError> {
+    check_uint_width(width)?;
+    if width < 8 && value >> (width * 8) != 0 {
+        return Err(CursorError::ValueOutOfRange);
+    }
+    Ok(match order {
+        ByteOrder::Le => (value.to_le_bytes(), 0),
+        ByteOrder::Be => (value.to_be_bytes(), 8 - width),
+    })
+}
+
+/// Cursor reading values of a runtime byte-order from a byte slice.
+#[derive(Debug, Clone)]
+pub struct OrderedReader<'a> {
+    /// The bytes being read.
+    bytes: &'a [u8],
+    /// Offset of the next byte to be read.
+    position: usize,
+    /// The byte-order of the values being read.
+    order: ByteOrder,
+}
+
+impl<'a> OrderedReader<'a> {
+    /// Create a reader of the values within `bytes`, which are encoded in `order`.
+    #[inline]
+    #[must_use]
+    pub const fn new(bytes: &'a [u8], order: ByteOrder) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            order,
+        }
+    }
+
+    /// Retrieve the byte-order of the values being read.
+    #[inline]
+    #[must_use]
+    pub const fn order(&self) -> ByteOrder {
+        self.order
+    }
+
+    /// Change the byte-order of the values read from this point onwards.
+    #[inline]
+    pub fn set_order(&mut self, order: ByteOrder) {
+        self.order = order;
+    }
+
+    /// Retrieve the offset of the next byte to be read.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Retrieve the bytes that have yet to be read.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> &'a [u8] {
+        self.bytes.get(self.position..).unwrap_or_default()
+    }
+
+    /// Check whether all bytes have been read.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.remaining().is_empty()
+    }
+
+    /// Read the next `len` bytes without converting their byte-order.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::UnexpectedEnd`] if fewer than `len` bytes remain, in which case
+    /// nothing is consumed.
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], CursorError> {
+        let bytes = self
+            .remaining()
+            .get(..len)
+            .ok_or(CursorError::UnexpectedEnd)?;
+        self.position += len;
+        Ok(bytes)
+    }
+
+    /// Skip the next `len` bytes.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::UnexpectedEnd`] if fewer than `len` bytes remain, in which case
+    /// nothing is consumed.
+    pub fn skip(&mut self, len: usize) -> Result<(), CursorError> {
+        self.read_bytes(len).map(|_| ())
+    }
+
+    /// Read the next value, converting it from the reader's byte-order to the machine's native
+    /// byte-order.
+    ///
+    /// The value is read without regard for its alignment.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::UnexpectedEnd`] if too few bytes remain to hold the value, in which
+    /// case nothing is consumed.
+    pub fn read<T: FieldsByteOrdered + WireSafe>(&mut self) -> Result<T, CursorError> {
+        let bytes = self
+            .remaining()
+            .get(..core::mem::size_of::<T>())
+            .ok_or(CursorError::UnexpectedEnd)?;
+        let mut value = T::from_bytes(bytes).ok_or(CursorError::UnexpectedEnd)?;
+        value.order_fields_ne(self.order);
+        self.position += bytes.len();
+        Ok(value)
+    }
+
+    /// Read the next value into `slot`, converting it from the reader's byte-order to the
+    /// machine's native byte-order.
+    ///
+    /// This initializes `slot` in place, returning a reference to the now initialized value.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::UnexpectedEnd`] if too few bytes remain to hold the value, in which
+    /// case nothing is consumed and `slot` is left uninitialized.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use lilbig::cursor::{CursorError, OrderedReader};
+    /// use lilbig::ByteOrder;
+    ///
+    /// let mut reader = OrderedReader::new(&[0x12, 0x34, 0x56, 0x78], ByteOrder::Be);
+    /// let mut slot = MaybeUninit::<[u16; 2]>::uninit();
+    /// assert_eq!(*reader.read_struct_into(&mut slot)?, [0x1234, 0x5678]);
+    /// # Ok::<(), CursorError>(())
+    /// ```
+    pub fn read_struct_into<'s, T: FieldsByteOrdered + WireSafe>(
+        &mut self,
+        slot: &'s mut MaybeUninit<T>,
+    ) -> Result<&'s mut T, CursorError> {
+        self.read_slice_into(core::slice::from_mut(slot))?
+            .first_mut()
+            .ok_or(CursorError::UnexpectedEnd)
+    }
+
+    /// Read the next `slots.len()` values into `slots`, converting them from the reader's
+    /// byte-order to the machine's native byte-order.
+    ///
+    /// The values are copied directly into `slots` and converted in place, avoiding the cost of
+    /// first initializing large buffers only to overwrite them. A reference to the now initialized
+    /// values is returned.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::UnexpectedEnd`] if too few bytes remain to hold the values, in which
+    /// case nothing is consumed and `slots` are left uninitialized.
+    ///
+    /// # Examples
+    /// Filling a preallocated buffer:
+    /// ```
+    /// use lilbig::cursor::{CursorError, OrderedReader};
+    /// use lilbig::ByteOrder;
+    ///
+    /// let bytes = [0x00, 0x01, 0x00, 0x02, 0x00, 0x03];
+    /// let mut reader = OrderedReader::new(&bytes, ByteOrder::Be);
+    /// let mut samples = Vec::<u16>::with_capacity(3);
+    /// let read = reader.read_slice_into(samples.spare_capacity_mut())?.len();
+    /// // SAFETY: The first `read` elements of the spare capacity were initialized.
+    /// unsafe { samples.set_len(read) };
+    /// assert_eq!(samples, [1, 2, 3]);
+    /// # Ok::<(), CursorError>(())
+    /// ```
+    pub fn read_slice_into<'s, T: FieldsByteOrdered + WireSafe>(
+        &mut self,
+        slots: &'s mut [MaybeUninit<T>],
+    ) -> Result<&'s mut [T], CursorError> {
+        let len = core::mem::size_of_val(slots);
+        let bytes = self
+            .remaining()
+            .get(..len)
+            .ok_or(CursorError::UnexpectedEnd)?;
+        // SAFETY: `bytes` holds exactly as many bytes as `slots` and cannot overlap it, as `slots`
+        // is borrowed mutably. Implementors of `WireSafe` guarantee that every bit pattern is a
+        // valid value, so every slot is initialized once its bytes have been copied.
+        let values = unsafe {
+            core::ptr::copy_nonoverlapping(bytes.as_ptr(), slots.as_mut_ptr().cast::<u8>(), len);
+            &mut *(core::ptr::from_mut::<[MaybeUninit<T>]>(slots) as *mut [T])
+        };
+        values.order_fields_ne(self.order);
+        self.position += len;
+        Ok(values)
+    }
+
+    /// Read the next `width` bytes as an unsigned integer encoded in the reader's byte-order.
+    ///
+    /// This supports integers of widths lacking a primitive type, such as 24-bit integers.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::InvalidWidth`] if `width` is zero or greater than 8, or
+    /// [`CursorError::UnexpectedEnd`] if fewer than `width` bytes remain. Nothing is consumed
+    /// upon error.
+    pub fn read_uint(&mut self, width: usize) -> Result<u64, CursorError> {
+        check_uint_width(width)?;
+        let bytes = self
+            .remaining()
+            .get(..width)
+            .ok_or(CursorError::UnexpectedEnd)?;
+        let mut value = [0u8; 8];
+        let value = match self.order {
+            ByteOrder::Le => {
+                value
+                    .get_mut(..width)
+                    .ok_or(CursorError::InvalidWidth)?
+                    .copy_from_slice(bytes);
+                u64::from_le_bytes(value)
+            }
+            ByteOrder::Be => {
+                value
+                    .get_mut(8 - width..)
+                    .ok_or(CursorError::InvalidWidth)?
+                    .copy_from_slice(bytes);
+                u64::from_be_bytes(value)
+            }
+        };
+        self.position += width;
+        Ok(value)
+    }
+}
+
+/// Cursor reading values of a runtime byte-order from a sequence of byte slices.
+///
+/// The slices are read one after another as though they were a single contiguous buffer, with
+/// values straddling the boundaries between slices being reassembled. This allows the contents of
+/// ring buffers and scatter-gather receives to be read without first copying them into one buffer.
+///
+/// # Examples
+/// Reading values that straddle slices:
+/// ```
+/// use lilbig::cursor::{CursorError, SegmentedReader};
+/// use lilbig::ByteOrder;
+///
+/// let segments: [&[u8]; 3] = [&[0x12], &[0x34, 0xab, 0xcd], &[0xef, 0x78, 0x56]];
+/// let mut reader = SegmentedReader::new(&segments, ByteOrder::Be);
+/// assert_eq!(reader.read::<u16>()?, 0x1234);
+/// assert_eq!(reader.read_uint(3)?, 0xabcdef);
+/// reader.set_order(ByteOrder::Le);
+/// assert_eq!(reader.read::<u16>()?, 0x5678);
+/// assert_eq!(reader.read::<u16>(), Err(CursorError::UnexpectedEnd));
+/// # Ok::<(), CursorError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SegmentedReader<'a> {
+    /// The slices being read.
+    segments: &'a [&'a [u8]],
+    /// Index of the slice containing the next byte to be read.
+    segment: usize,
+    /// Offset of the next byte to be read within its slice.
+    offset: usize,
+    /// Offset of the next byte to be read from the start of the first slice.
+    position: usize,
+    /// The byte-order of the values being read.
+    order: ByteOrder,
+}
+
+impl<'a> SegmentedReader<'a> {
+    /// Create a reader of the values within the concatenation of `segments`, which are encoded in
+    /// `order`.
+    #[inline]
+    #[must_use]
+    pub const fn new(segments: &'a [&'a [u8]], order: ByteOrder) -> Self {
+        Self {
+            segments,
+            segment: 0,
+            offset: 0,
+            position: 0,
+            order,
+        }
+    }
+
+    /// Retrieve the byte-order of the values being read.
+    #[inline]
+    #[must_use]
+    pub const fn order(&self) -> ByteOrder {
+        self.order
+    }
+
+    /// Change the byte-order of the values read from this point onwards.
+    #[inline]
+    pub fn set_order(&mut self, order: ByteOrder) {
+        self.order = order;
+    }
+
+    /// Retrieve the offset of the next byte to be read from the start of the first slice.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Retrieve the number of bytes that have yet to be read.
+    #[must_use]
+    pub fn remaining_len(&self) -> usize {
+        self.remaining_segments().map(<[u8]>::len).sum()
+    }
+
+    /// Check whether all bytes have been read.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.remaining_segments().all(<[u8]>::is_empty)
+    }
+
+    /// Create an iterator over the unread portions of the slices.
+    fn remaining_segments(&self) -> impl Iterator<Item = &'a [u8]> + 'a {
+        let offset = self.offset;
+        self.segments
+            .get(self.segment..)
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .map(move |(index, segment)| {
+                let skipped = if index == 0 { offset } else { 0 };
+                segment.get(skipped..).unwrap_or_default()
+            })
+    }
+
+    /// Read the next `bytes.len()` bytes into `bytes` without converting their byte-order.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::UnexpectedEnd`] if fewer than `bytes.len()` bytes remain, in which
+    /// case nothing is consumed and the contents of `bytes` are unspecified.
+    pub fn read_bytes_into(&mut self, bytes: &mut [u8]) -> Result<(), CursorError> {
+        let mut segment = self.segment;
+        let mut offset = self.offset;
+        let mut filled = 0;
+        while filled < bytes.len() {
+            let available = self
+                .segments
+                .get(segment)
+                .ok_or(CursorError::UnexpectedEnd)?
+                .get(offset..)
+                .unwrap_or_default();
+            let len = available.len().min(bytes.len() - filled);
+            if let (Some(dst), Some(src)) =
+                (bytes.get_mut(filled..filled + len), available.get(..len))
+            {
+                dst.copy_from_slice(src);
+            }
+            filled += len;
+            if len == available.len() {
+                segment += 1;
+                offset = 0;
+            } else {
+                offset += len;
+            }
+        }
+        self.segment = segment;
+        self.offset = offset;
+        self.position += bytes.len();
+        Ok(())
+    }
+
+    /// Skip the next `len` bytes.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::UnexpectedEnd`] if fewer than `len` bytes remain, in which case
+    /// nothing is consumed.
+    pub fn skip(&mut self, len: usize) -> Result<(), CursorError> {
+        let mut segment = self.segment;
+        let mut offset = self.offset;
+        let mut skipped = 0;
+        while skipped < len {
+            let available = self
+                .segments
+                .get(segment)
+                .ok_or(CursorError::UnexpectedEnd)?
+                .len()
+                .saturating_sub(offset);
+            if available > len - skipped {
+                offset += len - skipped;
+                skipped = len;
+            } else {
+                skipped += available;
+                segment += 1;
+                offset = 0;
+            }
+        }
+        self.segment = segment;
+        self.offset = offset;
+        self.position += len;
+        Ok(())
+    }
+
+    /// Read the next value, converting it from the reader's byte-order to the machine's native
+    /// byte-order.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::UnexpectedEnd`] if too few bytes remain to hold the value, in which
+    /// case nothing is consumed.
+    pub fn read<T: FieldsByteOrdered + WireSafe>(&mut self) -> Result<T, CursorError> {
+        let mut value = T::zeroed();
+        self.read_bytes_into(value.as_bytes_mut())?;
+        value.order_fields_ne(self.order);
+        Ok(value)
+    }
+
+    /// Read the next `width` bytes as an unsigned integer encoded in the reader's byte-order.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::InvalidWidth`] if `width` is zero or greater than 8, or
+    /// [`CursorError::UnexpectedEnd`] if fewer than `width` bytes remain. Nothing is consumed
+    /// upon error.
+    pub fn read_uint(&mut self, width: usize) -> Result<u64, CursorError> {
+        check_uint_width(width)?;
+        let mut bytes = [0u8; 8];
+        let start = match self.order {
+            ByteOrder::Le => 0,
+            ByteOrder::Be => 8 - width,
+        };
+        self.read_bytes_into(
+            bytes
+                .get_mut(start..start + width)
+                .ok_or(CursorError::InvalidWidth)?,
+        )?;
+        Ok(match self.order {
+            ByteOrder::Le => u64::from_le_bytes(bytes),
+            ByteOrder::Be => u64::from_be_bytes(bytes),
+        })
+    }
+}
+
+/// Saved state of an [`OrderedWriter`] to which it may later be rolled back.
+///
+/// A checkpoint is consumed by either [`OrderedWriter::rollback()`] or
+/// [`OrderedWriter::commit()`].
+#[derive(Debug, PartialEq, Eq)]
+#[must_use = "a checkpoint should be rolled back to or committed"]
+pub struct Checkpoint {
+    /// Offset of the next byte to be written when the checkpoint was taken.
+    position: usize,
+    /// The byte-order of the values being written when the checkpoint was taken.
+    order: ByteOrder,
+}
+
+impl Checkpoint {
+    /// Retrieve the offset of the next byte to be written when the checkpoint was taken.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+/// Cursor writing values of a runtime byte-order to a byte slice.
+#[derive(Debug)]
+pub struct OrderedWriter<'a> {
+    /// The bytes being written.
+    bytes: &'a mut [u8],
+    /// Offset of the next byte to be written.
+    position: usize,
+    /// The byte-order of the values being written.
+    order: ByteOrder,
+}
+
+impl<'a> OrderedWriter<'a> {
+    /// Create a writer of values encoded in `order` to `bytes`.
+    #[inline]
+    #[must_use]
+    pub fn new(bytes: &'a mut [u8], order: ByteOrder) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            order,
+        }
+    }
+
+    /// Retrieve the byte-order of the values being written.
+    #[inline]
+    #[must_use]
+    pub const fn order(&self) -> ByteOrder {
+        self.order
+    }
+
+    /// Change the byte-order of the values written from this point onwards.
+    #[inline]
+    pub fn set_order(&mut self, order: ByteOrder) {
+        self.order = order;
+    }
+
+    /// Retrieve the offset of the next byte to be written.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Retrieve the bytes that have been written.
+    #[inline]
+    #[must_use]
+    pub fn written(&self) -> &[u8] {
+        self.bytes.get(..self.position).unwrap_or_default()
+    }
+
+    /// Consume the writer, retrieving the bytes that have been written.
+    #[inline]
+    #[must_use]
+    pub fn into_written(self) -> &'a mut [u8] {
+        let position = self.position;
+        self.bytes.get_mut(..position).unwrap_or_default()
+    }
+
+    /// Retrieve the number of bytes that may still be written.
+    #[inline]
+    #[must_use]
+    pub fn capacity_remaining(&self) -> usize {
+        self.bytes.len().saturating_sub(self.position)
+    }
+
+    /// Save the writer's position and byte-order such that everything written from this point
+    /// onwards may be abandoned by [`rollback()`](OrderedWriter::rollback).
+    ///
+    /// # Examples
+    /// Abandoning a frame whose payload is too long:
+    /// ```
+    /// use lilbig::cursor::{CursorError, OrderedWriter};
+    /// use lilbig::ByteOrder;
+    ///
+    /// fn write_frame(writer: &mut OrderedWriter<'_>, payload: &[u8]) -> Result<(), CursorError> {
+    ///     let checkpoint = writer.checkpoint();
+    ///     let result = writer.write(0xa5u8).and_then(|()| {
+    ///         let length = u8::try_from(payload.len()).map_err(|_| CursorError::ValueOutOfRange)?;
+    ///         writer.write(length)?;
+    ///         writer.write_bytes(payload)
+    ///     });
+    ///     match result {
+    ///         Ok(()) => writer.commit(checkpoint),
+    ///         Err(_) => writer.rollback(checkpoint),
+    ///     }
+    ///     result
+    /// }
+    ///
+    /// let mut buffer = [0u8; 8];
+    /// let mut writer = OrderedWriter::new(&mut buffer, ByteOrder::Be);
+    /// write_frame(&mut writer, b"hi")?;
+    /// assert_eq!(write_frame(&mut writer, b"hello"), Err(CursorError::BufferFull));
+    /// assert_eq!(writer.written(), [0xa5, 2, b'h', b'i']);
+    /// # Ok::<(), CursorError>(())
+    /// ```
+    #[inline]
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            position: self.position,
+            order: self.order,
+        }
+    }
+
+    /// Abandon everything written since `checkpoint` was taken, restoring the writer's position
+    /// and byte-order to those saved by the checkpoint.
+    ///
+    /// The abandoned bytes are zeroed such that no partially written values remain within the
+    /// buffer. Rolling back to a checkpoint beyond the writer's current position, as may happen
+    /// after rolling back to an earlier checkpoint, only restores the byte-order.
+    pub fn rollback(&mut self, checkpoint: Checkpoint) {
+        if let Some(abandoned) = self.bytes.get_mut(checkpoint.position..self.position) {
+            abandoned.fill(0);
+            self.position = checkpoint.position;
+        }
+        self.order = checkpoint.order;
+    }
+
+    /// Keep everything written since `checkpoint` was taken, discarding the checkpoint.
+    #[inline]
+    pub fn commit(&mut self, checkpoint: Checkpoint) {
+        let _ = checkpoint;
+    }
+
+    /// Write `bytes` without converting their byte-order.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::BufferFull`] if fewer than `bytes.len()` bytes may still be written,
+    /// in which case nothing is written.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), CursorError> {
+        self.bytes
+            .get_mut(self.position..)
+            .and_then(|remaining| remaining.get_mut(..bytes.len()))
+            .ok_or(CursorError::BufferFull)?
+            .copy_from_slice(bytes);
+        self.position += bytes.len();
+        Ok(())
+    }
+
+    /// Write `value`, converting it from the machine's native byte-order to the writer's
+    /// byte-order. The padding of `value` is zeroed before it is written.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::BufferFull`] if too few bytes may still be written to hold the value,
+    /// in which case nothing is written.
+    pub fn write<T: FieldsByteOrdered + WireSafe>(&mut self, value: T) -> Result<(), CursorError> {
+        let mut value = value;
+        value.zero_padding();
+        value.order_fields_as(ByteOrder::NATIVE, self.order);
+        self.write_bytes(value.as_bytes())
+    }
+
+    /// Write `value` as an unsigned integer of `width` bytes encoded in the writer's byte-order.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::InvalidWidth`] if `width` is zero or greater than 8,
+    /// [`CursorError::ValueOutOfRange`] if `value` does not fit within `width` bytes, or
+    /// [`CursorError::BufferFull`] if fewer than `width` bytes may still be written. Nothing is
+    /// written upon error.
+    pub fn write_uint(&mut self, value: u64, width: usize) -> Result<(), CursorError> {
+        let (bytes, start) = encode_uint(self.order, value, width)?;
+        let bytes = bytes
+            .get(start..start + width)
+            .ok_or(CursorError::InvalidWidth)?;
+        self.write_bytes(bytes)
+    }
+
+    /// Overwrite the previously written bytes at `offset` with `value` as an unsigned integer of
+    /// `width` bytes encoded in the writer's byte-order.
+    ///
+    /// This is intended for filling in length fields once the length of what follows them is
+    /// known. The writer's position is unaffected.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::InvalidWidth`] if `width` is zero or greater than 8,
+    /// [`CursorError::ValueOutOfRange`] if `value` does not fit within `width` bytes, or
+    /// [`CursorError::BufferFull`] if the overwritten bytes would extend beyond those written.
+    /// Nothing is written upon error.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::cursor::{CursorError, OrderedWriter};
+    /// use lilbig::ByteOrder;
+    ///
+    /// let mut buffer = [0u8; 8];
+    /// let mut writer = OrderedWriter::new(&mut buffer, ByteOrder::Be);
+    /// let length_offset = writer.position();
+    /// writer.write(0u16)?;
+    /// writer.write_bytes(b"abc")?;
+    /// writer.write_uint_at(length_offset, 3, 2)?;
+    /// assert_eq!(writer.written(), [0x00, 0x03, b'a', b'b', b'c']);
+    /// # Ok::<(), CursorError>(())
+    /// ```
+    pub fn write_uint_at(
+        &mut self,
+        offset: usize,
+        value: u64,
+        width: usize,
+    ) -> Result<(), CursorError> {
+        let (bytes, start) = encode_uint(self.order, value, width)?;
+        let bytes = bytes
+            .get(start..start + width)
+            .ok_or(CursorError::InvalidWidth)?;
+        let end = offset.checked_add(width).ok_or(CursorError::BufferFull)?;
+        if end > self.position {
+            return Err(CursorError::BufferFull);
+        }
+        self.bytes
+            .get_mut(offset..end)
+            .ok_or(CursorError::BufferFull)?
+            .copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// Cursor writing values of a runtime byte-order to a sequence of byte slices.
+///
+/// The slices are written one after another as though they were a single contiguous buffer, with
+/// values straddling the boundaries between slices being split across them. This allows values to
+/// be written directly into the fragments handed to vectored writes.
+///
+/// # Examples
+/// Writing values that straddle slices:
+/// ```
+/// use lilbig::cursor::{CursorError, SegmentedWriter};
+/// use lilbig::ByteOrder;
+///
+/// let mut header = [0u8; 3];
+/// let mut body = [0u8; 4];
+/// let mut segments = [&mut header[..], &mut body[..]];
+/// let mut writer = SegmentedWriter::new(&mut segments, ByteOrder::Be);
+/// writer.write(0x1234u16)?;
+/// writer.write_uint(0xabcdef, 3)?;
+/// writer.set_order(ByteOrder::Le);
+/// writer.write(0x5678u16)?;
+/// assert_eq!(writer.write(0u8), Err(CursorError::BufferFull));
+/// assert_eq!(header, [0x12, 0x34, 0xab]);
+/// assert_eq!(body, [0xcd, 0xef, 0x78, 0x56]);
+/// # Ok::<(), CursorError>(())
+/// ```
+#[derive(Debug)]
+pub struct SegmentedWriter<'a, 'b> {
+    /// The slices being written.
+    segments: &'a mut [&'b mut [u8]],
+    /// Index of the slice to contain the next byte to be written.
+    segment: usize,
+    /// Offset of the next byte to be written within its slice.
+    offset: usize,
+    /// Offset of the next byte to be written from the start of the first slice.
+    position: usize,
+    /// The byte-order of the values being written.
+    order: ByteOrder,
+}
+
+impl<'a, 'b> SegmentedWriter<'a, 'b> {
+    /// Create a writer of values encoded in `order` to the concatenation of `segments`.
+    #[inline]
+    #[must_use]
+    pub fn new(segments: &'a mut [&'b mut [u8]], order: ByteOrder) -> Self {
+        Self {
+            segments,
+            segment: 0,
+            offset: 0,
+            position: 0,
+            order,
+        }
+    }
+
+    /// Retrieve the byte-order of the values being written.
+    #[inline]
+    #[must_use]
+    pub const fn order(&self) -> ByteOrder {
+        self.order
+    }
+
+    /// Change the byte-order of the values written from this point onwards.
+    #[inline]
+    pub fn set_order(&mut self, order: ByteOrder) {
+        self.order = order;
+    }
+
+    /// Retrieve the offset of the next byte to be written from the start of the first slice.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Retrieve the number of bytes that may still be written.
+    #[must_use]
+    pub fn capacity_remaining(&self) -> usize {
+        self.segments
+            .get(self.segment..)
+            .unwrap_or_default()
+            .iter()
+            .map(|segment| segment.len())
+            .sum::<usize>()
+            .saturating_sub(self.offset)
+    }
+
+    /// Write `bytes` without converting their byte-order.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::BufferFull`] if fewer than `bytes.len()` bytes may still be written,
+    /// in which case nothing is written.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), CursorError> {
+        if bytes.len() > self.capacity_remaining() {
+            return Err(CursorError::BufferFull);
+        }
+        let mut written = 0;
+        while written < bytes.len() {
+            let Some(available) = self
+                .segments
+                .get_mut(self.segment)
+                .and_then(|segment| segment.get_mut(self.offset..))
+            else {
+                return Err(CursorError::BufferFull);
+            };
+            let len = available.len().min(bytes.len() - written);
+            if let (Some(dst), Some(src)) =
+                (available.get_mut(..len), bytes.get(written..written + len))
+            {
+                dst.copy_from_slice(src);
+            }
+            written += len;
+            if len == available.len() {
+                self.segment += 1;
+                self.offset = 0;
+            } else {
+                self.offset += len;
+            }
+        }
+        self.position += bytes.len();
+        Ok(())
+    }
+
+    /// Write `value`, converting it from the machine's native byte-order to the writer's
+    /// byte-order. The padding of `value` is zeroed before it is written.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::BufferFull`] if too few bytes may still be written to hold the value,
+    /// in which case nothing is written.
+    pub fn write<T: FieldsByteOrdered + WireSafe>(&mut self, value: T) -> Result<(), CursorError> {
+        let mut value = value;
+        value.zero_padding();
+        value.order_fields_as(ByteOrder::NATIVE, self.order);
+        self.write_bytes(value.as_bytes())
+    }
+
+    /// Write `value` as an unsigned integer of `width` bytes encoded in the writer's byte-order.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::InvalidWidth`] if `width` is zero or greater than 8,
+    /// [`CursorError::ValueOutOfRange`] if `value` does not fit within `width` bytes, or
+    /// [`CursorError::BufferFull`] if fewer than `width` bytes may still be written. Nothing is
+    /// written upon error.
+    pub fn write_uint(&mut self, value: u64, width: usize) -> Result<(), CursorError> {
+        let (bytes, start) = encode_uint(self.order, value, width)?;
+        let bytes = bytes
+            .get(start..start + width)
+            .ok_or(CursorError::InvalidWidth)?;
+        self.write_bytes(bytes)
+    }
+}
@@ -0,0 +1,329 @@
+//! Stream parsing of DICOM data elements with transfer syntax switching.
+//!
+//! The encoding of a DICOM data set is described by its transfer syntax, which determines both the
+//! byte-order of the data set and whether each data element explicitly states its value
+//! representation (VR). The file meta information at the start of a DICOM file is always encoded
+//! in explicit VR little-endian, and names the transfer syntax of the data set that follows it.
+//! [`DicomReader`] therefore allows its transfer syntax to be switched partway through a stream,
+//! and [`DicomReader::read_file_meta()`] performs that switch automatically.
+//!
+//! # Examples
+//! Reading a data set following its file meta information:
+//! ```
+//! use lilbig::cursor::CursorError;
+//! use lilbig::dicom::{DicomReader, Tag, TransferSyntax};
+//!
+//! let stream = [
+//!     // (0002,0010) UI "1.2.840.10008.1.2.2", explicit VR little-endian.
+//!     0x02, 0x00, 0x10, 0x00, b'U', b'I', 0x14, 0x00, b'1', b'.', b'2', b'.', b'8', b'4', b'0',
+//!     b'.', b'1', b'0', b'0', b'0', b'8', b'.', b'1', b'.', b'2', b'.', b'2', 0x00,
+//!     // (0028,0010) US 512, explicit VR big-endian.
+//!     0x00, 0x28, 0x00, 0x10, b'U', b'S', 0x00, 0x02, 0x02, 0x00,
+//! ];
+//!
+//! let mut reader = DicomReader::new(&stream, TransferSyntax::ExplicitVrLittleEndian);
+//! assert_eq!(reader.read_file_meta()?, Some(TransferSyntax::ExplicitVrBigEndian));
+//!
+//! let rows = reader.read_element()?;
+//! assert_eq!(rows.tag(), Tag::new(0x0028, 0x0010));
+//! assert_eq!(rows.vr(), Some(*b"US"));
+//! assert_eq!(rows.value_reader().read::<u16>()?, 512);
+//! assert!(reader.is_empty());
+//! # Ok::<(), CursorError>(())
+//! ```
+
+use crate::cursor::{CursorError, OrderedReader};
+use crate::{ByteOrder, FieldsByteOrdered, WireSafe};
+
+/// Length of a data element whose value is delimited rather than of a known length.
+const UNDEFINED_LENGTH: u32 = 0xffffffff;
+
+/// Group of the file meta information elements.
+const FILE_META_GROUP: u16 = 0x0002;
+
+/// Group of item and delimitation elements, which never state their value representation.
+const ITEM_GROUP: u16 = 0xfffe;
+
+/// Value representations whose explicit VR encoding uses a 32-bit length.
+const LONG_LENGTH_VRS: [[u8; 2]; 13] = [
+    *b"OB", *b"OD", *b"OF", *b"OL", *b"OV", *b"OW", *b"SQ", *b"SV", *b"UC", *b"UN", *b"UR", *b"UT",
+    *b"UV",
+];
+
+/// Transfer syntax describing the encoding of a DICOM data set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransferSyntax {
+    /// Implicit VR little-endian (`1.2.840.10008.1.2`).
+    ImplicitVrLittleEndian,
+    /// Explicit VR little-endian (`1.2.840.10008.1.2.1`), which is also the encoding of the data
+    /// sets of encapsulated (compressed) transfer syntaxes.
+    ExplicitVrLittleEndian,
+    /// Explicit VR big-endian (`1.2.840.10008.1.2.2`).
+    ExplicitVrBigEndian,
+}
+
+impl TransferSyntax {
+    /// Identify the transfer syntax named by `uid`, ignoring any trailing padding.
+    ///
+    /// Encapsulated transfer syntaxes are identified as [`TransferSyntax::ExplicitVrLittleEndian`],
+    /// as their data sets are encoded as such. Returns [`None`] if `uid` does not name a DICOM
+    /// transfer syntax, or if it names a deflated transfer syntax such as deflated explicit VR
+    /// little-endian (`1.2.840.10008.1.2.1.99`), whose data sets must be inflated before they may
+    /// be read.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::dicom::TransferSyntax;
+    ///
+    /// assert_eq!(
+    ///     TransferSyntax::from_uid(b"1.2.840.10008.1.2\0"),
+    ///     Some(TransferSyntax::ImplicitVrLittleEndian),
+    /// );
+    /// assert_eq!(
+    ///     TransferSyntax::from_uid(b"1.2.840.10008.1.2.4.50"),
+    ///     Some(TransferSyntax::ExplicitVrLittleEndian),
+    /// );
+    /// assert_eq!(TransferSyntax::from_uid(b"1.2.840.10008.1.2.1.99"), None);
+    /// assert_eq!(TransferSyntax::from_uid(b"1.2.3"), None);
+    /// ```
+    #[must_use]
+    pub fn from_uid(uid: &[u8]) -> Option<Self> {
+        let uid = uid.trim_ascii_end();
+        let uid = uid.strip_suffix(b"\0").unwrap_or(uid);
+        match uid {
+            b"1.2.840.10008.1.2" => Some(Self::ImplicitVrLittleEndian),
+            b"1.2.840.10008.1.2.2" => Some(Self::ExplicitVrBigEndian),
+            // Deflated explicit VR little-endian and JPIP referenced deflate.
+            b"1.2.840.10008.1.2.1.99" | b"1.2.840.10008.1.2.4.95" => None,
+            _ if uid.starts_with(b"1.2.840.10008.1.2.") => Some(Self::ExplicitVrLittleEndian),
+            _ => None,
+        }
+    }
+
+    /// Retrieve the byte-order of data sets of the transfer syntax.
+    #[inline]
+    #[must_use]
+    pub const fn order(self) -> ByteOrder {
+        match self {
+            Self::ImplicitVrLittleEndian | Self::ExplicitVrLittleEndian => ByteOrder::Le,
+            Self::ExplicitVrBigEndian => ByteOrder::Be,
+        }
+    }
+
+    /// Check whether the data elements of the transfer syntax explicitly state their value
+    /// representation.
+    #[inline]
+    #[must_use]
+    pub const fn is_explicit_vr(self) -> bool {
+        !matches!(self, Self::ImplicitVrLittleEndian)
+    }
+}
+
+/// Tag identifying a data element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(C)]
+pub struct Tag {
+    /// Group number of the tag.
+    pub group: u16,
+    /// Element number of the tag.
+    pub element: u16,
+}
+
+impl FieldsByteOrdered for Tag {
+    #[inline]
+    fn swap_field_orders(&mut self) {
+        self.group.swap_field_orders();
+        self.element.swap_field_orders();
+    }
+}
+
+// SAFETY: `Tag` is `repr(C)`, all of its fields are `WireSafe`, and its fields are arranged such
+// that it contains no padding.
+unsafe impl WireSafe for Tag {}
+
+impl Tag {
+    /// Tag of the transfer syntax UID within the file meta information.
+    pub const TRANSFER_SYNTAX_UID: Self = Self::new(0x0002, 0x0010);
+
+    /// Create a tag from its group and element numbers.
+    #[inline]
+    #[must_use]
+    pub const fn new(group: u16, element: u16) -> Self {
+        Self { group, element }
+    }
+}
+
+/// Data element read by a [`DicomReader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataElement<'a> {
+    /// The element's tag.
+    tag: Tag,
+    /// The element's explicitly stated value representation.
+    vr: Option<[u8; 2]>,
+    /// The element's value, which is empty for elements of undefined length.
+    value: &'a [u8],
+    /// Whether the element's length is undefined.
+    undefined_length: bool,
+    /// The byte-order of the element's value.
+    order: ByteOrder,
+}
+
+impl<'a> DataElement<'a> {
+    /// Retrieve the element's tag.
+    #[inline]
+    #[must_use]
+    pub const fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Retrieve the element's value representation, if it was explicitly stated.
+    #[inline]
+    #[must_use]
+    pub const fn vr(&self) -> Option<[u8; 2]> {
+        self.vr
+    }
+
+    /// Check whether the element's length is undefined.
+    ///
+    /// The contents of elements of undefined length (such as sequences) follow the element within
+    /// the stream as items terminated by a delimitation element, and the element's value is empty.
+    #[inline]
+    #[must_use]
+    pub const fn is_undefined_length(&self) -> bool {
+        self.undefined_length
+    }
+
+    /// Retrieve the bytes of the element's value.
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> &'a [u8] {
+        self.value
+    }
+
+    /// Create a reader of the contents of the element's value in the byte-order of the transfer
+    /// syntax from which the element was read.
+    #[inline]
+    #[must_use]
+    pub const fn value_reader(&self) -> OrderedReader<'a> {
+        OrderedReader::new(self.value, self.order)
+    }
+}
+
+/// Reader of the data elements of a DICOM stream whose transfer syntax may change partway through.
+///
+/// Elements are read one after another without descending into sequences. The items and
+/// delimitation elements of sequences of undefined length are read as elements of their own.
+#[derive(Debug, Clone)]
+pub struct DicomReader<'a> {
+    /// Reader of the stream.
+    reader: OrderedReader<'a>,
+    /// The transfer syntax of the elements being read.
+    syntax: TransferSyntax,
+}
+
+impl<'a> DicomReader<'a> {
+    /// Create a reader of the elements within `bytes`, which are encoded in `syntax`.
+    #[inline]
+    #[must_use]
+    pub const fn new(bytes: &'a [u8], syntax: TransferSyntax) -> Self {
+        Self {
+            reader: OrderedReader::new(bytes, syntax.order()),
+            syntax,
+        }
+    }
+
+    /// Retrieve the transfer syntax of the elements being read.
+    #[inline]
+    #[must_use]
+    pub const fn syntax(&self) -> TransferSyntax {
+        self.syntax
+    }
+
+    /// Change the transfer syntax of the elements read from this point onwards.
+    #[inline]
+    pub fn set_syntax(&mut self, syntax: TransferSyntax) {
+        self.syntax = syntax;
+        self.reader.set_order(syntax.order());
+    }
+
+    /// Retrieve the offset of the next element to be read.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.reader.position()
+    }
+
+    /// Check whether all elements have been read.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.reader.is_empty()
+    }
+
+    /// Read the next element.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::UnexpectedEnd`] if the element is truncated, in which case the
+    /// reader's position is unspecified.
+    pub fn read_element(&mut self) -> Result<DataElement<'a>, CursorError> {
+        let tag = self.reader.read::<Tag>()?;
+        let explicit = self.syntax.is_explicit_vr() && tag.group != ITEM_GROUP;
+        let (vr, length) = if explicit {
+            let vr = self.reader.read::<[u8; 2]>()?;
+            let length = if LONG_LENGTH_VRS.contains(&vr) {
+                self.reader.skip(2)?;
+                self.reader.read::<u32>()?
+            } else {
+                u32::from(self.reader.read::<u16>()?)
+            };
+            (Some(vr), length)
+        } else {
+            (None, self.reader.read::<u32>()?)
+        };
+
+        let undefined_length = length == UNDEFINED_LENGTH;
+        let value = if undefined_length {
+            &[]
+        } else {
+            let length = usize::try_from(length).map_err(|_| CursorError::UnexpectedEnd)?;
+            self.reader.read_bytes(length)?
+        };
+        Ok(DataElement {
+            tag,
+            vr,
+            value,
+            undefined_length,
+            order: self.syntax.order(),
+        })
+    }
+
+    /// Read the elements of the file meta information, then switch to the transfer syntax that it
+    /// names.
+    ///
+    /// The file meta information is read as explicit VR little-endian regardless of the reader's
+    /// current transfer syntax, and ends at the first element outside of group `0002`. Returns the
+    /// named transfer syntax, or [`None`] if the file meta information named no recognized transfer
+    /// syntax, in which case the reader's transfer syntax is left as explicit VR little-endian.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::UnexpectedEnd`] if an element of the file meta information is
+    /// truncated.
+    pub fn read_file_meta(&mut self) -> Result<Option<TransferSyntax>, CursorError> {
+        self.set_syntax(TransferSyntax::ExplicitVrLittleEndian);
+        let mut named = None;
+        while let Some(group) = self.reader.remaining().first_chunk::<2>() {
+            if u16::from_le_bytes(*group) != FILE_META_GROUP {
+                break;
+            }
+            let element = self.read_element()?;
+            if element.tag == Tag::TRANSFER_SYNTAX_UID {
+                named = TransferSyntax::from_uid(element.value);
+            }
+        }
+        if let Some(syntax) = named {
+            self.set_syntax(syntax);
+        }
+        Ok(named)
+    }
+}
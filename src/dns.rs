@@ -0,0 +1,167 @@
+//! DNS message header and resource record structs.
+//!
+//! Only the fixed-size portions of a DNS message are provided. Domain names, which precede the
+//! fixed fields of questions and resource records, are of variable length and are left to the user
+//! to skip or decode.
+//!
+//! Like the headers of the `net` module (when enabled), each struct is `#[repr(C)]`,
+//! free of padding, and implements both [`FieldsByteOrdered`](crate::FieldsByteOrdered) and
+//! [`WireSafe`](crate::WireSafe). DNS messages are always encoded in network byte-order
+//! (big-endian).
+//!
+//! # Examples
+//! Parsing the header and question of a query:
+//! ```
+//! use lilbig::dns::{DnsHeader, DnsQuestion};
+//! use lilbig::{ByteOrder, FieldsByteOrdered, WireSafe};
+//!
+//! let message = [
+//!     0xbe, 0xef, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Header.
+//!     0x03, b'c', b'o', b'm', 0x00, // Name.
+//!     0x00, 0x01, 0x00, 0x01, // Type and class.
+//! ];
+//!
+//! let (header, rest) = message.split_at(core::mem::size_of::<DnsHeader>());
+//! let mut header = DnsHeader::from_bytes(header).unwrap();
+//! header.order_fields_ne(ByteOrder::Be);
+//! assert_eq!(header.id, 0xbeef);
+//! assert!(!header.is_response());
+//! assert_eq!(header.opcode(), 0);
+//! assert_eq!(header.question_count, 1);
+//!
+//! let question = &rest[5..];
+//! let mut question = DnsQuestion::from_bytes(question).unwrap();
+//! question.order_fields_ne(ByteOrder::Be);
+//! assert_eq!((question.qtype, question.qclass), (1, 1));
+//! ```
+
+use crate::headers::impl_header;
+
+/// DNS message header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct DnsHeader {
+    /// Identifier used to match responses to queries.
+    pub id: u16,
+    /// Query/response bit, opcode, flags, and response code.
+    pub flags: u16,
+    /// Number of entries within the question section.
+    pub question_count: u16,
+    /// Number of resource records within the answer section.
+    pub answer_count: u16,
+    /// Number of resource records within the authority section.
+    pub authority_count: u16,
+    /// Number of resource records within the additional section.
+    pub additional_count: u16,
+}
+
+impl_header!(DnsHeader {
+    id,
+    flags,
+    question_count,
+    answer_count,
+    authority_count,
+    additional_count,
+});
+
+impl DnsHeader {
+    /// Check whether the message is a response rather than a query.
+    ///
+    /// The header's fields are expected to be in the machine's native byte-order.
+    #[inline]
+    #[must_use]
+    pub const fn is_response(&self) -> bool {
+        self.flags & 0x8000 != 0
+    }
+
+    /// Retrieve the kind of query of the message.
+    ///
+    /// The header's fields are expected to be in the machine's native byte-order.
+    #[inline]
+    #[must_use]
+    pub const fn opcode(&self) -> u8 {
+        ((self.flags >> 11) & 0xf) as u8
+    }
+
+    /// Retrieve the response code of the message.
+    ///
+    /// The header's fields are expected to be in the machine's native byte-order.
+    #[inline]
+    #[must_use]
+    pub const fn rcode(&self) -> u8 {
+        (self.flags & 0xf) as u8
+    }
+}
+
+/// Fixed fields of a question, which follow the question's name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct DnsQuestion {
+    /// Type of the records being queried.
+    pub qtype: u16,
+    /// Class of the records being queried.
+    pub qclass: u16,
+}
+
+impl_header!(DnsQuestion { qtype, qclass });
+
+/// Fixed fields of a resource record, which follow the record's name and precede its data.
+///
+/// The record's time to live is split into two 16-bit halves as it is not aligned to a 32-bit
+/// boundary within the record. Use [`ttl()`](DnsRecordFixed::ttl) and
+/// [`set_ttl()`](DnsRecordFixed::set_ttl) to access it as a whole.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::dns::DnsRecordFixed;
+/// use lilbig::{ByteOrder, FieldsByteOrdered, WireSafe};
+///
+/// let fixed = [0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x51, 0x80, 0x00, 0x04];
+/// let mut record = DnsRecordFixed::from_bytes(&fixed).unwrap();
+/// record.order_fields_ne(ByteOrder::Be);
+/// assert_eq!(record.ttl(), 86400);
+/// assert_eq!(record.rdlength, 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct DnsRecordFixed {
+    /// Type of the record.
+    pub rtype: u16,
+    /// Class of the record.
+    pub class: u16,
+    /// Upper 16 bits of the record's time to live in seconds.
+    pub ttl_high: u16,
+    /// Lower 16 bits of the record's time to live in seconds.
+    pub ttl_low: u16,
+    /// Length of the record's data in bytes.
+    pub rdlength: u16,
+}
+
+impl_header!(DnsRecordFixed {
+    rtype,
+    class,
+    ttl_high,
+    ttl_low,
+    rdlength,
+});
+
+impl DnsRecordFixed {
+    /// Retrieve the record's time to live in seconds.
+    ///
+    /// The record's fields are expected to be in the machine's native byte-order.
+    #[inline]
+    #[must_use]
+    pub const fn ttl(&self) -> u32 {
+        (self.ttl_high as u32) << 16 | self.ttl_low as u32
+    }
+
+    /// Set the record's time to live in seconds.
+    ///
+    /// The record's fields are expected to be in the machine's native byte-order.
+    #[inline]
+    pub fn set_ttl(&mut self, ttl: u32) {
+        self.ttl_high = (ttl >> 16) as u16;
+        self.ttl_low = ttl as u16;
+    }
+}
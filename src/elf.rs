@@ -0,0 +1,382 @@
+//! ELF file, program, and section header structs.
+//!
+//! ELF files are encoded in the byte-order of their target machine, which is identified by the
+//! [`EI_DATA`] byte of the file header's identification bytes. The `read()` functions of the file
+//! headers within this module perform that detection and return the detected byte-order alongside
+//! the header converted to the machine's native byte-order. The detected byte-order may then be
+//! used to read the file's program and section headers.
+//!
+//! # Examples
+//! Reading the file header of a big-endian ELF file:
+//! ```
+//! use lilbig::elf::{Elf64Ehdr, ELFCLASS64, ELFDATA2MSB, ELFMAG, EI_CLASS, EI_DATA};
+//! use lilbig::{ByteOrder, FieldsByteOrdered, WireSafe};
+//!
+//! let mut e_ident = [0; 16];
+//! e_ident[..4].copy_from_slice(&ELFMAG);
+//! e_ident[EI_CLASS] = ELFCLASS64;
+//! e_ident[EI_DATA] = ELFDATA2MSB;
+//!
+//! // Produce the bytes of a big-endian file header.
+//! let mut header = Elf64Ehdr {
+//!     e_ident,
+//!     e_type: 2,
+//!     e_machine: 21,
+//!     e_entry: 0x10000000,
+//!     e_phoff: 64,
+//!     ..Default::default()
+//! };
+//! header.order_fields_be(ByteOrder::NATIVE);
+//!
+//! let (read, order) = Elf64Ehdr::read(header.as_bytes()).unwrap();
+//! assert_eq!(order, ByteOrder::Be);
+//! assert_eq!(read.e_machine, 21);
+//! assert_eq!(read.e_entry, 0x10000000);
+//! assert_eq!(read.e_phoff, 64);
+//! ```
+
+use crate::headers::{impl_header, read_ordered};
+use crate::ByteOrder;
+
+/// Magic bytes at the start of every ELF file.
+pub const ELFMAG: [u8; 4] = [0x7f, b'E', b'L', b'F'];
+
+/// Index of the file's class within the identification bytes.
+pub const EI_CLASS: usize = 4;
+
+/// Index of the file's data encoding within the identification bytes.
+pub const EI_DATA: usize = 5;
+
+/// Class of 32-bit ELF files.
+pub const ELFCLASS32: u8 = 1;
+
+/// Class of 64-bit ELF files.
+pub const ELFCLASS64: u8 = 2;
+
+/// Data encoding of little-endian ELF files.
+pub const ELFDATA2LSB: u8 = 1;
+
+/// Data encoding of big-endian ELF files.
+pub const ELFDATA2MSB: u8 = 2;
+
+/// Detect the byte-order of an ELF file from its identification bytes.
+///
+/// Returns [`None`] if `bytes` does not begin with the ELF magic bytes or if its data encoding is
+/// not recognized.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::elf::detect_order;
+/// use lilbig::ByteOrder;
+///
+/// assert_eq!(detect_order(b"\x7fELF\x02\x01\x01"), Some(ByteOrder::Le));
+/// assert_eq!(detect_order(b"\x7fELF\x01\x02\x01"), Some(ByteOrder::Be));
+/// assert_eq!(detect_order(b"\x7fELF\x02\x00\x01"), None);
+/// assert_eq!(detect_order(b"MZ\x90\x00\x03\x00"), None);
+/// ```
+#[must_use]
+pub fn detect_order(bytes: &[u8]) -> Option<ByteOrder> {
+    if bytes.get(..ELFMAG.len())? != ELFMAG {
+        return None;
+    }
+    ByteOrder::from_elf_data(*bytes.get(EI_DATA)?)
+}
+
+/// File header of a 32-bit ELF file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Elf32Ehdr {
+    /// Identification bytes, including the magic bytes, class, and data encoding of the file.
+    pub e_ident: [u8; 16],
+    /// Type of the file.
+    pub e_type: u16,
+    /// Target machine architecture.
+    pub e_machine: u16,
+    /// Version of the file format.
+    pub e_version: u32,
+    /// Virtual address of the program's entry point.
+    pub e_entry: u32,
+    /// File offset of the program header table.
+    pub e_phoff: u32,
+    /// File offset of the section header table.
+    pub e_shoff: u32,
+    /// Machine specific flags.
+    pub e_flags: u32,
+    /// Size of this header in bytes.
+    pub e_ehsize: u16,
+    /// Size of a program header table entry in bytes.
+    pub e_phentsize: u16,
+    /// Number of program header table entries.
+    pub e_phnum: u16,
+    /// Size of a section header table entry in bytes.
+    pub e_shentsize: u16,
+    /// Number of section header table entries.
+    pub e_shnum: u16,
+    /// Section header table index of the section name string table.
+    pub e_shstrndx: u16,
+}
+
+impl_header!(Elf32Ehdr {
+    e_ident,
+    e_type,
+    e_machine,
+    e_version,
+    e_entry,
+    e_phoff,
+    e_shoff,
+    e_flags,
+    e_ehsize,
+    e_phentsize,
+    e_phnum,
+    e_shentsize,
+    e_shnum,
+    e_shstrndx,
+});
+
+impl Elf32Ehdr {
+    /// Read a file header from the start of `bytes`, detecting the file's byte-order from its
+    /// identification bytes.
+    ///
+    /// Returns the header in the machine's native byte-order along with the detected byte-order of
+    /// the file. Returns [`None`] if `bytes` is too short, is not an ELF file, or is not a 32-bit
+    /// ELF file.
+    #[must_use]
+    pub fn read(bytes: &[u8]) -> Option<(Self, ByteOrder)> {
+        let order = detect_order(bytes)?;
+        if *bytes.get(EI_CLASS)? != ELFCLASS32 {
+            return None;
+        }
+        Some((read_ordered(bytes, order)?.0, order))
+    }
+}
+
+/// File header of a 64-bit ELF file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Elf64Ehdr {
+    /// Identification bytes, including the magic bytes, class, and data encoding of the file.
+    pub e_ident: [u8; 16],
+    /// Type of the file.
+    pub e_type: u16,
+    /// Target machine architecture.
+    pub e_machine: u16,
+    /// Version of the file format.
+    pub e_version: u32,
+    /// Virtual address of the program's entry point.
+    pub e_entry: u64,
+    /// File offset of the program header table.
+    pub e_phoff: u64,
+    /// File offset of the section header table.
+    pub e_shoff: u64,
+    /// Machine specific flags.
+    pub e_flags: u32,
+    /// Size of this header in bytes.
+    pub e_ehsize: u16,
+    /// Size of a program header table entry in bytes.
+    pub e_phentsize: u16,
+    /// Number of program header table entries.
+    pub e_phnum: u16,
+    /// Size of a section header table entry in bytes.
+    pub e_shentsize: u16,
+    /// Number of section header table entries.
+    pub e_shnum: u16,
+    /// Section header table index of the section name string table.
+    pub e_shstrndx: u16,
+}
+
+impl_header!(Elf64Ehdr {
+    e_ident,
+    e_type,
+    e_machine,
+    e_version,
+    e_entry,
+    e_phoff,
+    e_shoff,
+    e_flags,
+    e_ehsize,
+    e_phentsize,
+    e_phnum,
+    e_shentsize,
+    e_shnum,
+    e_shstrndx,
+});
+
+impl Elf64Ehdr {
+    /// Read a file header from the start of `bytes`, detecting the file's byte-order from its
+    /// identification bytes.
+    ///
+    /// Returns the header in the machine's native byte-order along with the detected byte-order of
+    /// the file. Returns [`None`] if `bytes` is too short, is not an ELF file, or is not a 64-bit
+    /// ELF file.
+    #[must_use]
+    pub fn read(bytes: &[u8]) -> Option<(Self, ByteOrder)> {
+        let order = detect_order(bytes)?;
+        if *bytes.get(EI_CLASS)? != ELFCLASS64 {
+            return None;
+        }
+        Some((read_ordered(bytes, order)?.0, order))
+    }
+}
+
+/// Program header of a 32-bit ELF file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Elf32Phdr {
+    /// Type of the segment.
+    pub p_type: u32,
+    /// File offset of the segment.
+    pub p_offset: u32,
+    /// Virtual address of the segment.
+    pub p_vaddr: u32,
+    /// Physical address of the segment.
+    pub p_paddr: u32,
+    /// Size of the segment within the file in bytes.
+    pub p_filesz: u32,
+    /// Size of the segment within memory in bytes.
+    pub p_memsz: u32,
+    /// Segment flags.
+    pub p_flags: u32,
+    /// Alignment of the segment.
+    pub p_align: u32,
+}
+
+impl_header!(Elf32Phdr {
+    p_type,
+    p_offset,
+    p_vaddr,
+    p_paddr,
+    p_filesz,
+    p_memsz,
+    p_flags,
+    p_align,
+});
+
+/// Program header of a 64-bit ELF file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Elf64Phdr {
+    /// Type of the segment.
+    pub p_type: u32,
+    /// Segment flags.
+    pub p_flags: u32,
+    /// File offset of the segment.
+    pub p_offset: u64,
+    /// Virtual address of the segment.
+    pub p_vaddr: u64,
+    /// Physical address of the segment.
+    pub p_paddr: u64,
+    /// Size of the segment within the file in bytes.
+    pub p_filesz: u64,
+    /// Size of the segment within memory in bytes.
+    pub p_memsz: u64,
+    /// Alignment of the segment.
+    pub p_align: u64,
+}
+
+impl_header!(Elf64Phdr {
+    p_type,
+    p_flags,
+    p_offset,
+    p_vaddr,
+    p_paddr,
+    p_filesz,
+    p_memsz,
+    p_align,
+});
+
+/// Section header of a 32-bit ELF file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Elf32Shdr {
+    /// Offset of the section's name within the section name string table.
+    pub sh_name: u32,
+    /// Type of the section.
+    pub sh_type: u32,
+    /// Section flags.
+    pub sh_flags: u32,
+    /// Virtual address of the section.
+    pub sh_addr: u32,
+    /// File offset of the section.
+    pub sh_offset: u32,
+    /// Size of the section in bytes.
+    pub sh_size: u32,
+    /// Section header table index link, whose interpretation depends upon the section's type.
+    pub sh_link: u32,
+    /// Extra information, whose interpretation depends upon the section's type.
+    pub sh_info: u32,
+    /// Alignment of the section.
+    pub sh_addralign: u32,
+    /// Size of the section's entries in bytes, if the section holds a table.
+    pub sh_entsize: u32,
+}
+
+impl_header!(Elf32Shdr {
+    sh_name,
+    sh_type,
+    sh_flags,
+    sh_addr,
+    sh_offset,
+    sh_size,
+    sh_link,
+    sh_info,
+    sh_addralign,
+    sh_entsize,
+});
+
+/// Section header of a 64-bit ELF file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Elf64Shdr {
+    /// Offset of the section's name within the section name string table.
+    pub sh_name: u32,
+    /// Type of the section.
+    pub sh_type: u32,
+    /// Section flags.
+    pub sh_flags: u64,
+    /// Virtual address of the section.
+    pub sh_addr: u64,
+    /// File offset of the section.
+    pub sh_offset: u64,
+    /// Size of the section in bytes.
+    pub sh_size: u64,
+    /// Section header table index link, whose interpretation depends upon the section's type.
+    pub sh_link: u32,
+    /// Extra information, whose interpretation depends upon the section's type.
+    pub sh_info: u32,
+    /// Alignment of the section.
+    pub sh_addralign: u64,
+    /// Size of the section's entries in bytes, if the section holds a table.
+    pub sh_entsize: u64,
+}
+
+impl_header!(Elf64Shdr {
+    sh_name,
+    sh_type,
+    sh_flags,
+    sh_addr,
+    sh_offset,
+    sh_size,
+    sh_link,
+    sh_info,
+    sh_addralign,
+    sh_entsize,
+});
+
+/// Implement `read()` for program and section header structs.
+macro_rules! impl_table_read {
+    ($($ty: ty),+) => {$(
+        impl $ty {
+            /// Read a header encoded in `order` from the start of `bytes`.
+            ///
+            /// Returns the header in the machine's native byte-order, or [`None`] if `bytes` is too
+            /// short.
+            #[must_use]
+            pub fn read(bytes: &[u8], order: ByteOrder) -> Option<Self> {
+                Some(read_ordered(bytes, order)?.0)
+            }
+        }
+    )+};
+}
+
+impl_table_read!(Elf32Phdr, Elf64Phdr, Elf32Shdr, Elf64Shdr);
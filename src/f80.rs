@@ -0,0 +1,295 @@
+//! x87 80-bit extended precision floating point values.
+//!
+//! Extended precision values consist of a sign bit, a 15-bit exponent, and a 64-bit significand
+//! with an explicit integer bit. Rust has no native extended precision type, so [`F80`] holds the
+//! 10 bytes of a value and provides conversions to and from [`f64`]. The conversions are removed by
+//! the `no-float` feature, leaving only the conversions of the value's bytes.
+//!
+//! # Examples
+//! Reading a big-endian extended precision value:
+//! ```
+//! # #[cfg(not(feature = "no-float"))]
+//! # fn main() {
+//! use lilbig::f80::F80;
+//! use lilbig::{ByteOrder, ByteOrdered};
+//!
+//! let value = F80::from_be_bytes([0x40, 0x00, 0xc9, 0x0f, 0xda, 0xa2, 0x21, 0x68, 0xc2, 0x35]);
+//! assert_eq!(value.to_f64(), core::f64::consts::PI);
+//!
+//! // Equivalently, through the runtime byte-order API.
+//! let bytes = [0x40, 0x00, 0xc9, 0x0f, 0xda, 0xa2, 0x21, 0x68, 0xc2, 0x35];
+//! let value = F80::from_ne_bytes(bytes).ordered_ne(ByteOrder::Be);
+//! assert_eq!(value.to_f64(), core::f64::consts::PI);
+//! # }
+//! # #[cfg(feature = "no-float")]
+//! # fn main() {}
+//! ```
+
+use crate::{ByteOrder, ByteOrdered, FieldsByteOrdered, WireSafe};
+
+/// Number of bits within the fraction of an [`f64`].
+#[cfg(not(feature = "no-float"))]
+const F64_FRACTION_BITS: u32 = 52;
+
+/// Exponent bias of an [`f64`].
+#[cfg(not(feature = "no-float"))]
+const F64_BIAS: i32 = 1023;
+
+/// Exponent bias of an extended precision value.
+#[cfg(not(feature = "no-float"))]
+const F80_BIAS: i32 = 16383;
+
+/// Maximum biased exponent of an extended precision value, used by infinities and NaNs.
+#[cfg(not(feature = "no-float"))]
+const F80_MAX_EXPONENT: u16 = 0x7fff;
+
+/// Integer bit of an extended precision value's significand.
+#[cfg(not(feature = "no-float"))]
+const F80_INTEGER_BIT: u64 = 1 << 63;
+
+/// x87 80-bit extended precision floating point value.
+///
+/// The value is held as its 10 bytes in the machine's native byte-order, and its byte-order is
+/// converted by reversing those bytes. Comparisons between values are bitwise rather than
+/// floating point comparisons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(transparent)]
+pub struct F80([u8; 10]);
+
+impl F80 {
+    /// Create a value from its bytes in the machine's native byte-order.
+    #[inline]
+    #[must_use]
+    pub const fn from_ne_bytes(bytes: [u8; 10]) -> Self {
+        Self(bytes)
+    }
+
+    /// Create a value from its bytes in little-endian.
+    #[inline]
+    #[must_use]
+    pub const fn from_le_bytes(bytes: [u8; 10]) -> Self {
+        match ByteOrder::NATIVE {
+            ByteOrder::Le => Self(bytes),
+            ByteOrder::Be => Self(reverse(bytes)),
+        }
+    }
+
+    /// Create a value from its bytes in big-endian.
+    #[inline]
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; 10]) -> Self {
+        match ByteOrder::NATIVE {
+            ByteOrder::Le => Self(reverse(bytes)),
+            ByteOrder::Be => Self(bytes),
+        }
+    }
+
+    /// Retrieve the value's bytes in the machine's native byte-order.
+    #[inline]
+    #[must_use]
+    pub const fn to_ne_bytes(self) -> [u8; 10] {
+        self.0
+    }
+
+    /// Retrieve the value's bytes in little-endian.
+    #[inline]
+    #[must_use]
+    pub const fn to_le_bytes(self) -> [u8; 10] {
+        match ByteOrder::NATIVE {
+            ByteOrder::Le => self.0,
+            ByteOrder::Be => reverse(self.0),
+        }
+    }
+
+    /// Retrieve the value's bytes in big-endian.
+    #[inline]
+    #[must_use]
+    pub const fn to_be_bytes(self) -> [u8; 10] {
+        match ByteOrder::NATIVE {
+            ByteOrder::Le => reverse(self.0),
+            ByteOrder::Be => self.0,
+        }
+    }
+
+    /// Create a value from its sign bit, biased exponent, and significand.
+    #[cfg(not(feature = "no-float"))]
+    const fn from_parts(negative: bool, exponent: u16, significand: u64) -> Self {
+        let sign_exponent = (negative as u16) << 15 | (exponent & F80_MAX_EXPONENT);
+        let [s0, s1, s2, s3, s4, s5, s6, s7] = significand.to_le_bytes();
+        let [e0, e1] = sign_exponent.to_le_bytes();
+        Self::from_le_bytes([s0, s1, s2, s3, s4, s5, s6, s7, e0, e1])
+    }
+
+    /// Split the value into its sign bit, biased exponent, and significand.
+    #[cfg(not(feature = "no-float"))]
+    const fn to_parts(self) -> (bool, u16, u64) {
+        let [s0, s1, s2, s3, s4, s5, s6, s7, e0, e1] = self.to_le_bytes();
+        let sign_exponent = u16::from_le_bytes([e0, e1]);
+        let significand = u64::from_le_bytes([s0, s1, s2, s3, s4, s5, s6, s7]);
+        (
+            sign_exponent >> 15 != 0,
+            sign_exponent & F80_MAX_EXPONENT,
+            significand,
+        )
+    }
+
+    /// Convert an [`f64`] to an extended precision value.
+    ///
+    /// The conversion is exact, including for subnormals, infinities, and NaN payloads.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::f80::F80;
+    ///
+    /// let one = F80::from_f64(1.0);
+    /// assert_eq!(one.to_be_bytes(), [0x3f, 0xff, 0x80, 0, 0, 0, 0, 0, 0, 0]);
+    /// assert_eq!(F80::from_f64(-2.5).to_f64(), -2.5);
+    /// assert_eq!(F80::from_f64(f64::MIN_POSITIVE / 8.0).to_f64(), f64::MIN_POSITIVE / 8.0);
+    /// ```
+    #[cfg(not(feature = "no-float"))]
+    #[must_use]
+    pub fn from_f64(value: f64) -> Self {
+        let bits = value.to_bits();
+        let negative = bits >> 63 != 0;
+        let exponent = ((bits >> F64_FRACTION_BITS) & 0x7ff) as i32;
+        let fraction = bits & ((1 << F64_FRACTION_BITS) - 1);
+
+        if exponent == 0x7ff {
+            // Infinities and NaNs, whose payloads are carried into the upper fraction bits.
+            return Self::from_parts(negative, F80_MAX_EXPONENT, F80_INTEGER_BIT | fraction << 11);
+        }
+        if exponent == 0 {
+            if fraction == 0 {
+                return Self::from_parts(negative, 0, 0);
+            }
+            // Subnormals are normalized, as the extended exponent range can represent them.
+            let shift = fraction.leading_zeros();
+            let unbiased = 1 - F64_BIAS - (shift as i32 - 11);
+            return Self::from_parts(negative, (unbiased + F80_BIAS) as u16, fraction << shift);
+        }
+        Self::from_parts(
+            negative,
+            (exponent - F64_BIAS + F80_BIAS) as u16,
+            F80_INTEGER_BIT | fraction << 11,
+        )
+    }
+
+    /// Convert the value to the nearest [`f64`], rounding ties to even.
+    ///
+    /// Values whose magnitude exceeds that of [`f64::MAX`] become infinities, and values too small
+    /// to be represented become zeros. NaNs retain as much of their payload as fits.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::f80::F80;
+    ///
+    /// // 1 + 2^-63, which is rounded to 1.
+    /// let value = F80::from_be_bytes([0x3f, 0xff, 0x80, 0, 0, 0, 0, 0, 0, 1]);
+    /// assert_eq!(value.to_f64(), 1.0);
+    ///
+    /// // 2^16383, which overflows.
+    /// let value = F80::from_be_bytes([0x7f, 0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0]);
+    /// assert_eq!(value.to_f64(), f64::INFINITY);
+    /// ```
+    #[cfg(not(feature = "no-float"))]
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        let (negative, exponent, significand) = self.to_parts();
+        let sign = (negative as u64) << 63;
+
+        if exponent == F80_MAX_EXPONENT {
+            let payload = (significand & !F80_INTEGER_BIT) >> 11;
+            let nan = significand & !F80_INTEGER_BIT != 0;
+            // Ensure NaNs whose payload lies entirely within the discarded bits remain NaNs.
+            let payload = if nan && payload == 0 {
+                1 << 51
+            } else {
+                payload
+            };
+            return f64::from_bits(sign | 0x7ff << F64_FRACTION_BITS | payload);
+        }
+        if significand == 0 {
+            return f64::from_bits(sign);
+        }
+
+        // Normalize the significand, which is only necessary for denormals and unnormals.
+        let shift = significand.leading_zeros();
+        let significand = significand << shift;
+        let unbiased = i32::from(exponent).max(1) - F80_BIAS - shift as i32;
+
+        if unbiased > F64_BIAS {
+            return f64::from_bits(sign | 0x7ff << F64_FRACTION_BITS);
+        }
+        if unbiased >= 1 - F64_BIAS {
+            let mantissa = round_shift(significand, 11);
+            // Rounding may carry into the next power of two, incrementing the exponent.
+            let carry = mantissa >> (F64_FRACTION_BITS + 1);
+            let biased = (unbiased + F64_BIAS) as u64 + carry;
+            if biased >= 0x7ff {
+                return f64::from_bits(sign | 0x7ff << F64_FRACTION_BITS);
+            }
+            let fraction = mantissa & ((1 << F64_FRACTION_BITS) - 1);
+            return f64::from_bits(sign | biased << F64_FRACTION_BITS | fraction);
+        }
+
+        // The value is subnormal as an `f64`, so the significand is shifted further such that it
+        // is scaled by 2^-1074.
+        let extra = (1 - F64_BIAS - unbiased) as u32;
+        // A carry out of the subnormal range lands exactly upon the smallest normal's bits.
+        let mantissa = match 11u32.checked_add(extra) {
+            Some(amount) if amount < 66 => round_shift(significand, amount),
+            _ => 0,
+        };
+        f64::from_bits(sign | mantissa)
+    }
+}
+
+/// Shift `value` right by `amount` bits, rounding the result to the nearest integer with ties to
+/// even.
+#[cfg(not(feature = "no-float"))]
+fn round_shift(value: u64, amount: u32) -> u64 {
+    let wide = u128::from(value);
+    let truncated = (wide >> amount) as u64;
+    let remainder = wide & ((1u128 << amount) - 1);
+    let half = 1u128 << (amount - 1);
+    if remainder > half || (remainder == half && truncated & 1 != 0) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+/// Reverse the order of `bytes`.
+const fn reverse(bytes: [u8; 10]) -> [u8; 10] {
+    let [b0, b1, b2, b3, b4, b5, b6, b7, b8, b9] = bytes;
+    [b9, b8, b7, b6, b5, b4, b3, b2, b1, b0]
+}
+
+#[cfg(not(feature = "no-float"))]
+impl From<f64> for F80 {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self::from_f64(value)
+    }
+}
+
+impl ByteOrdered for F80 {
+    #[inline]
+    fn swapped_order(self) -> Self {
+        Self(reverse(self.0))
+    }
+}
+
+impl FieldsByteOrdered for F80 {
+    #[inline]
+    fn swap_field_orders(&mut self) {
+        #[cfg(feature = "swap-trace")]
+        crate::swap_trace::swap(self);
+        *self = self.swapped_order();
+    }
+}
+
+// SAFETY: `F80` is `repr(transparent)` over a byte array.
+unsafe impl WireSafe for F80 {}
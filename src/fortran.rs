@@ -0,0 +1,221 @@
+//! Records of Fortran sequential unformatted files.
+//!
+//! Fortran writes each record of a sequential unformatted file between two markers holding the
+//! length of the record in bytes. The markers are 4 bytes wide by default and 8 bytes wide for some
+//! older compilers and options, and are encoded in the byte-order of the machine that wrote the
+//! file, as are the values within the records. [`RecordReader`] and [`RecordWriter`] read and write
+//! such records through the crate's cursors, checking that the markers surrounding each record
+//! agree.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! use lilbig::fortran::{FortranError, RecordMarker, RecordReader, RecordWriter};
+//! use lilbig::ByteOrder;
+//!
+//! let mut file = [0u8; 32];
+//! let mut writer = RecordWriter::new(&mut file, ByteOrder::Be, RecordMarker::U32);
+//! writer.write_record(|record| {
+//!     record.write(3u32)?;
+//!     record.write(-2i64)
+//! })?;
+//! writer.write_record_bytes(b"done")?;
+//! let written = writer.into_inner().position();
+//!
+//! let mut reader = RecordReader::new(&file[..written], ByteOrder::Be, RecordMarker::U32);
+//! let mut record = reader.read_record()?;
+//! assert_eq!(record.read::<u32>()?, 3);
+//! assert_eq!(record.read::<i64>()?, -2);
+//! assert_eq!(reader.read_record()?.remaining(), b"done");
+//! assert!(reader.is_empty());
+//! # Ok::<(), FortranError>(())
+//! ```
+
+use crate::cursor::{CursorError, OrderedReader, OrderedWriter};
+use crate::ByteOrder;
+
+/// Width of the markers holding the length of each record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RecordMarker {
+    /// 4-byte markers, as written by default by modern compilers.
+    #[default]
+    U32,
+    /// 8-byte markers, as written by some older compilers and options.
+    U64,
+}
+
+impl RecordMarker {
+    /// Retrieve the width of the markers in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn width(self) -> usize {
+        match self {
+            Self::U32 => 4,
+            Self::U64 => 8,
+        }
+    }
+}
+
+/// Error returned when reading or writing a record fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FortranError {
+    /// The underlying cursor failed.
+    Cursor(CursorError),
+    /// The markers preceding and following a record held different lengths.
+    MismatchedMarkers,
+}
+
+impl FortranError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::Cursor(err) => err.message(),
+            Self::MismatchedMarkers => "record markers hold different lengths",
+        }
+    }
+}
+
+impl core::fmt::Display for FortranError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for FortranError {}
+
+impl From<CursorError> for FortranError {
+    #[inline]
+    fn from(err: CursorError) -> Self {
+        Self::Cursor(err)
+    }
+}
+
+/// Reader of the records of a Fortran sequential unformatted file.
+#[derive(Debug, Clone)]
+pub struct RecordReader<'a> {
+    /// Cursor over the file.
+    reader: OrderedReader<'a>,
+    /// Width of the record markers.
+    marker: RecordMarker,
+}
+
+impl<'a> RecordReader<'a> {
+    /// Create a reader of the records within `bytes`, whose markers are of the width `marker` and
+    /// whose markers and values are encoded in `order`.
+    #[inline]
+    #[must_use]
+    pub const fn new(bytes: &'a [u8], order: ByteOrder, marker: RecordMarker) -> Self {
+        Self {
+            reader: OrderedReader::new(bytes, order),
+            marker,
+        }
+    }
+
+    /// Check whether all records have been read.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.reader.is_empty()
+    }
+
+    /// Consume the reader, retrieving the cursor over the file.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> OrderedReader<'a> {
+        self.reader
+    }
+
+    /// Read the next record, retrieving a cursor over its contents in the file's byte-order.
+    ///
+    /// # Errors
+    /// Returns [`FortranError::Cursor`] with [`CursorError::UnexpectedEnd`] if the file ends
+    /// within the record, or [`FortranError::MismatchedMarkers`] if the markers surrounding the
+    /// record disagree. Nothing is consumed upon error.
+    pub fn read_record(&mut self) -> Result<OrderedReader<'a>, FortranError> {
+        let mut reader = self.reader.clone();
+        let width = self.marker.width();
+        let len = reader.read_uint(width)?;
+        let len = usize::try_from(len).map_err(|_| CursorError::UnexpectedEnd)?;
+        let record = reader.read_bytes(len)?;
+        if reader.read_uint(width)? != len as u64 {
+            return Err(FortranError::MismatchedMarkers);
+        }
+        self.reader = reader;
+        Ok(OrderedReader::new(record, self.reader.order()))
+    }
+}
+
+/// Writer of the records of a Fortran sequential unformatted file.
+#[derive(Debug)]
+pub struct RecordWriter<'a> {
+    /// Cursor over the file.
+    writer: OrderedWriter<'a>,
+    /// Width of the record markers.
+    marker: RecordMarker,
+}
+
+impl<'a> RecordWriter<'a> {
+    /// Create a writer of records to `bytes`, whose markers are of the width `marker` and whose
+    /// markers and values are encoded in `order`.
+    #[inline]
+    #[must_use]
+    pub fn new(bytes: &'a mut [u8], order: ByteOrder, marker: RecordMarker) -> Self {
+        Self {
+            writer: OrderedWriter::new(bytes, order),
+            marker,
+        }
+    }
+
+    /// Consume the writer, retrieving the cursor over the file.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> OrderedWriter<'a> {
+        self.writer
+    }
+
+    /// Write a record whose contents are written by `write` to the cursor it is given, surrounding
+    /// them with markers holding their length.
+    ///
+    /// # Errors
+    /// Returns [`FortranError::Cursor`] if `write` fails, if the file is too short to hold the
+    /// record, or with [`CursorError::ValueOutOfRange`] if the record is too long for its length to
+    /// be held by a marker. Nothing is written upon error.
+    pub fn write_record<F>(&mut self, write: F) -> Result<(), FortranError>
+    where
+        F: FnOnce(&mut OrderedWriter<'a>) -> Result<(), CursorError>,
+    {
+        let checkpoint = self.writer.checkpoint();
+        let result = self.write_record_unchecked(write);
+        match result {
+            Ok(()) => self.writer.commit(checkpoint),
+            Err(_) => self.writer.rollback(checkpoint),
+        }
+        result.map_err(FortranError::Cursor)
+    }
+
+    /// Write a record whose contents are written by `write`, leaving anything written upon error.
+    fn write_record_unchecked<F>(&mut self, write: F) -> Result<(), CursorError>
+    where
+        F: FnOnce(&mut OrderedWriter<'a>) -> Result<(), CursorError>,
+    {
+        let width = self.marker.width();
+        let marker_offset = self.writer.position();
+        self.writer.write_uint(0, width)?;
+        write(&mut self.writer)?;
+        let len = (self.writer.position() - marker_offset - width) as u64;
+        self.writer.write_uint_at(marker_offset, len, width)?;
+        self.writer.write_uint(len, width)
+    }
+
+    /// Write a record holding `bytes` without converting their byte-order.
+    ///
+    /// # Errors
+    /// Returns [`FortranError::Cursor`] if the file is too short to hold the record, or with
+    /// [`CursorError::ValueOutOfRange`] if the record is too long for its length to be held by a
+    /// marker. Nothing is written upon error.
+    pub fn write_record_bytes(&mut self, bytes: &[u8]) -> Result<(), FortranError> {
+        self.write_record(|record| record.write_bytes(bytes))
+    }
+}
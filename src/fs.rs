@@ -0,0 +1,268 @@
+//! Filesystem superblock and boot sector structs.
+//!
+//! The structs within this module mirror the on-disk layouts of their filesystems and implement
+//! both [`FieldsByteOrdered`] and [`WireSafe`]. The ext family of filesystems and FAT both store
+//! their metadata in little-endian, so the `read()` functions of these structs convert them from
+//! little-endian to the machine's native byte-order.
+//!
+//! # Examples
+//! Reading the superblock of an ext filesystem image:
+//! ```
+//! use lilbig::fs::{ExtSuperblock, EXT_SUPERBLOCK_OFFSET};
+//!
+//! let mut image = vec![0u8; 4096];
+//! let superblock = &mut image[EXT_SUPERBLOCK_OFFSET..];
+//! superblock[0..4].copy_from_slice(&2048u32.to_le_bytes()); // s_inodes_count
+//! superblock[24..28].copy_from_slice(&2u32.to_le_bytes()); // s_log_block_size
+//! superblock[56..58].copy_from_slice(&[0x53, 0xef]); // s_magic
+//! superblock[120..124].copy_from_slice(b"root"); // s_volume_name
+//!
+//! let superblock = ExtSuperblock::read(&image).unwrap();
+//! assert_eq!(superblock.s_inodes_count, 2048);
+//! assert_eq!(superblock.block_size(), Some(4096));
+//! assert_eq!(&superblock.s_volume_name[..4], b"root");
+//! ```
+
+use crate::{ByteOrder, FieldsByteOrdered, WireSafe};
+
+/// Byte offset of the superblock of an ext filesystem from the start of its volume.
+pub const EXT_SUPERBLOCK_OFFSET: usize = 1024;
+
+/// Magic number of an ext filesystem's superblock.
+pub const EXT_SUPER_MAGIC: u16 = 0xef53;
+
+/// Signature found at the end of a FAT boot sector.
+pub const FAT_BOOT_SIGNATURE: [u8; 2] = [0x55, 0xaa];
+
+/// Leading fields of the superblock shared by the ext2, ext3, and ext4 filesystems.
+///
+/// Fields introduced by later revisions of the filesystem follow these fields on disk and are not
+/// included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FieldsByteOrdered)]
+#[repr(C)]
+pub struct ExtSuperblock {
+    /// Total number of inodes.
+    pub s_inodes_count: u32,
+    /// Total number of blocks.
+    pub s_blocks_count: u32,
+    /// Number of blocks reserved for the superuser.
+    pub s_r_blocks_count: u32,
+    /// Number of free blocks.
+    pub s_free_blocks_count: u32,
+    /// Number of free inodes.
+    pub s_free_inodes_count: u32,
+    /// Index of the first data block.
+    pub s_first_data_block: u32,
+    /// Block size as a shift applied to 1024.
+    pub s_log_block_size: u32,
+    /// Fragment size as a shift applied to 1024.
+    pub s_log_frag_size: u32,
+    /// Number of blocks per block group.
+    pub s_blocks_per_group: u32,
+    /// Number of fragments per block group.
+    pub s_frags_per_group: u32,
+    /// Number of inodes per block group.
+    pub s_inodes_per_group: u32,
+    /// Time of the last mount.
+    pub s_mtime: u32,
+    /// Time of the last write.
+    pub s_wtime: u32,
+    /// Number of mounts since the last check.
+    pub s_mnt_count: u16,
+    /// Number of mounts permitted before a check is required.
+    pub s_max_mnt_count: i16,
+    /// Magic number, which is always [`EXT_SUPER_MAGIC`].
+    pub s_magic: u16,
+    /// Filesystem state.
+    pub s_state: u16,
+    /// Behavior upon detecting errors.
+    pub s_errors: u16,
+    /// Minor revision level.
+    pub s_minor_rev_level: u16,
+    /// Time of the last check.
+    pub s_lastcheck: u32,
+    /// Maximum time between checks.
+    pub s_checkinterval: u32,
+    /// Operating system that created the filesystem.
+    pub s_creator_os: u32,
+    /// Revision level.
+    pub s_rev_level: u32,
+    /// Default user ID of reserved blocks.
+    pub s_def_resuid: u16,
+    /// Default group ID of reserved blocks.
+    pub s_def_resgid: u16,
+    /// First non-reserved inode.
+    pub s_first_ino: u32,
+    /// Size of an inode in bytes.
+    pub s_inode_size: u16,
+    /// Block group containing this superblock.
+    pub s_block_group_nr: u16,
+    /// Compatible feature flags.
+    pub s_feature_compat: u32,
+    /// Incompatible feature flags.
+    pub s_feature_incompat: u32,
+    /// Read-only compatible feature flags.
+    pub s_feature_ro_compat: u32,
+    /// Identifier of the volume.
+    pub s_uuid: [u8; 16],
+    /// Name of the volume.
+    pub s_volume_name: [u8; 16],
+    /// Path at which the volume was last mounted.
+    pub s_last_mounted: [u8; 64],
+    /// Compression algorithms in use.
+    pub s_algorithm_usage_bitmap: u32,
+}
+
+// SAFETY: `ExtSuperblock` is `repr(C)`, all of its fields are `WireSafe`, and its fields are
+// arranged such that it contains no padding.
+unsafe impl WireSafe for ExtSuperblock {}
+
+impl ExtSuperblock {
+    /// Read the superblock of an ext filesystem from `volume`, the bytes of the filesystem's volume.
+    ///
+    /// Returns the superblock in the machine's native byte-order, or [`None`] if `volume` is too
+    /// short or does not contain an ext superblock.
+    #[must_use]
+    pub fn read(volume: &[u8]) -> Option<Self> {
+        let bytes = volume
+            .get(EXT_SUPERBLOCK_OFFSET..)?
+            .get(..core::mem::size_of::<Self>())?;
+        let mut superblock = Self::from_bytes(bytes)?;
+        superblock.order_fields_ne(ByteOrder::Le);
+        (superblock.s_magic == EXT_SUPER_MAGIC).then_some(superblock)
+    }
+
+    /// Compute the filesystem's block size in bytes.
+    ///
+    /// Returns [`None`] if the block size is too large to be represented.
+    #[must_use]
+    pub fn block_size(&self) -> Option<u64> {
+        let shift = self.s_log_block_size.checked_add(10)?;
+        1u64.checked_shl(shift)
+    }
+}
+
+/// Block group descriptor of an ext2 or ext3 filesystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FieldsByteOrdered)]
+#[repr(C)]
+pub struct ExtGroupDesc {
+    /// Block containing the group's block bitmap.
+    pub bg_block_bitmap: u32,
+    /// Block containing the group's inode bitmap.
+    pub bg_inode_bitmap: u32,
+    /// First block of the group's inode table.
+    pub bg_inode_table: u32,
+    /// Number of free blocks within the group.
+    pub bg_free_blocks_count: u16,
+    /// Number of free inodes within the group.
+    pub bg_free_inodes_count: u16,
+    /// Number of directories within the group.
+    pub bg_used_dirs_count: u16,
+    /// Padding.
+    pub bg_pad: u16,
+    /// Reserved.
+    pub bg_reserved: [u32; 3],
+}
+
+// SAFETY: `ExtGroupDesc` is `repr(C)`, all of its fields are `WireSafe`, and its fields are
+// arranged such that it contains no padding.
+unsafe impl WireSafe for ExtGroupDesc {}
+
+impl ExtGroupDesc {
+    /// Read a block group descriptor from the start of `bytes`.
+    ///
+    /// Returns the descriptor in the machine's native byte-order, or [`None`] if `bytes` is too
+    /// short.
+    #[must_use]
+    pub fn read(bytes: &[u8]) -> Option<Self> {
+        let mut desc = Self::from_bytes(bytes.get(..core::mem::size_of::<Self>())?)?;
+        desc.order_fields_ne(ByteOrder::Le);
+        Some(desc)
+    }
+}
+
+/// Boot sector of a FAT filesystem through the end of its BIOS parameter block.
+///
+/// The BIOS parameter block contains fields that are not aligned to their size, so the struct is
+/// `#[repr(C, packed)]`. Copy fields out of the struct before referencing them.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::fs::FatBootSector;
+///
+/// let mut sector = [0u8; 512];
+/// sector[11..13].copy_from_slice(&512u16.to_le_bytes()); // bytes_per_sector
+/// sector[13] = 4; // sectors_per_cluster
+/// sector[19..21].copy_from_slice(&0u16.to_le_bytes()); // total_sectors_16
+/// sector[32..36].copy_from_slice(&131072u32.to_le_bytes()); // total_sectors_32
+/// sector[510..].copy_from_slice(&[0x55, 0xaa]);
+///
+/// let boot = FatBootSector::read(&sector).unwrap();
+/// assert_eq!({ boot.bytes_per_sector }, 512);
+/// assert_eq!(boot.sectors_per_cluster, 4);
+/// assert_eq!(boot.total_sectors(), 131072);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FieldsByteOrdered)]
+#[repr(C, packed)]
+pub struct FatBootSector {
+    /// Jump instruction to the boot code.
+    pub jmp_boot: [u8; 3],
+    /// Name of the system that formatted the volume.
+    pub oem_name: [u8; 8],
+    /// Size of a sector in bytes.
+    pub bytes_per_sector: u16,
+    /// Number of sectors per cluster.
+    pub sectors_per_cluster: u8,
+    /// Number of reserved sectors preceding the first FAT.
+    pub reserved_sector_count: u16,
+    /// Number of FATs.
+    pub num_fats: u8,
+    /// Number of root directory entries on FAT12 and FAT16 volumes.
+    pub root_entry_count: u16,
+    /// Total number of sectors if it fits within 16 bits, otherwise zero.
+    pub total_sectors_16: u16,
+    /// Media descriptor.
+    pub media: u8,
+    /// Number of sectors per FAT on FAT12 and FAT16 volumes.
+    pub fat_size_16: u16,
+    /// Number of sectors per track.
+    pub sectors_per_track: u16,
+    /// Number of heads.
+    pub num_heads: u16,
+    /// Number of sectors preceding the volume.
+    pub hidden_sectors: u32,
+    /// Total number of sectors if it does not fit within 16 bits, otherwise zero.
+    pub total_sectors_32: u32,
+}
+
+// SAFETY: `FatBootSector` is `repr(C, packed)` and all of its fields are `WireSafe`, so it contains
+// no padding.
+unsafe impl WireSafe for FatBootSector {}
+
+impl FatBootSector {
+    /// Read a boot sector from `sector`, the first sector of a FAT volume.
+    ///
+    /// Returns the boot sector in the machine's native byte-order, or [`None`] if `sector` is
+    /// shorter than 512 bytes or does not end with [`FAT_BOOT_SIGNATURE`].
+    #[must_use]
+    pub fn read(sector: &[u8]) -> Option<Self> {
+        if sector.get(510..512)? != FAT_BOOT_SIGNATURE {
+            return None;
+        }
+        let mut boot = Self::from_bytes(sector.get(..core::mem::size_of::<Self>())?)?;
+        boot.order_fields_ne(ByteOrder::Le);
+        Some(boot)
+    }
+
+    /// Retrieve the total number of sectors of the volume.
+    #[inline]
+    #[must_use]
+    pub const fn total_sectors(&self) -> u32 {
+        if self.total_sectors_16 != 0 {
+            self.total_sectors_16 as u32
+        } else {
+            self.total_sectors_32
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Differential fuzzing harnesses comparing the crate's accelerated and derived conversions against
+//! simple reference implementations.
+//!
+//! Each harness accepts arbitrary bytes, as provided by a fuzzer, and panics upon finding a
+//! difference between the implementations it compares. The harnesses are intended to be called
+//! from fuzz targets, such as those of [cargo-fuzz](https://github.com/rust-fuzz/cargo-fuzz).
+//!
+//! # Examples
+//! A cargo-fuzz target:
+//! ```ignore
+//! #![no_main]
+//! use libfuzzer_sys::fuzz_target;
+//!
+//! fuzz_target!(|data: &[u8]| lilbig::fuzzing::bulk_swap(data));
+//! ```
+
+use crate::bulk::{try_swap_elements, IncrementalSwapper};
+use crate::layout::{SwapLayout, TypeLayout};
+use crate::{WidthError, WireSafe};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Compare the bulk swapping of elements against reversing the bytes of each element in turn.
+///
+/// The first byte of `data` selects the width of the elements, the second byte selects the budget
+/// of each step of an [`IncrementalSwapper`], and the remaining bytes form the buffer.
+///
+/// # Panics
+/// Panics if the bulk swapping of elements differs from the reference.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// lilbig::fuzzing::bulk_swap(&[8, 3, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+/// lilbig::fuzzing::bulk_swap(&[3, 0, 0, 1, 2, 3, 4]);
+/// ```
+pub fn bulk_swap(data: &[u8]) {
+    let [width, budget, bytes @ ..] = data else {
+        return;
+    };
+    let width = usize::from(*width % 33);
+    let budget = usize::from(*budget);
+
+    let mut expected = Vec::from(bytes);
+    let expected_result = reference_swap_elements(&mut expected, width);
+
+    let mut swapped = Vec::from(bytes);
+    let result = try_swap_elements(&mut swapped, width);
+    assert_eq!(result, expected_result, "results differ for width {width}");
+    assert_eq!(swapped, expected, "swapped bytes differ for width {width}");
+
+    let mut stepped = Vec::from(bytes);
+    if let Ok(mut swapper) = IncrementalSwapper::try_new(&mut stepped, width) {
+        // A budget smaller than the width makes no progress, so finish rather than stepping.
+        if budget < width {
+            swapper.finish();
+        }
+        while !swapper.is_finished() {
+            swapper.step(budget);
+        }
+        assert_eq!(stepped, expected, "stepped bytes differ for width {width}");
+    }
+}
+
+/// Compare swapping the byte-order of the fields of a value of `T` against a [`TypeLayout`] driven
+/// by `T`'s [`SwapLayout`] table.
+///
+/// The value is read from the start of `data`, which is padded with zeros if it is too short.
+///
+/// # Panics
+/// Panics if swapping the byte-order of the value's fields differs from the layout.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// lilbig::fuzzing::layout_swap::<[u32; 3]>(&[1, 2, 3, 4, 5, 6, 7]);
+/// lilbig::fuzzing::layout_swap::<[[u16; 2]; 2]>(&[]);
+/// ```
+pub fn layout_swap<T: SwapLayout + WireSafe + Debug>(data: &[u8]) {
+    let mut bytes = Vec::from(data);
+    bytes.resize(core::mem::size_of::<T>(), 0);
+    let Some(mut value) = T::from_bytes(&bytes) else {
+        return;
+    };
+
+    let fields = T::swap_fields().collect::<Vec<_>>();
+    TypeLayout::new(bytes.len(), &fields).swap(&mut bytes);
+
+    value.swap_field_orders();
+    assert_eq!(
+        value.as_bytes(),
+        bytes.as_slice(),
+        "swapping the fields of {} differs from its layout",
+        core::any::type_name::<T>(),
+    );
+}
+
+/// Reference implementation of [`try_swap_elements()`].
+fn reference_swap_elements(bytes: &mut [u8], width: usize) -> Result<(), WidthError> {
+    if width == 0 {
+        return Err(WidthError::Zero);
+    }
+    if !bytes.len().is_multiple_of(width) {
+        return Err(WidthError::IndivisibleLength);
+    }
+    for element in bytes.chunks_exact_mut(width) {
+        for index in 0..width / 2 {
+            element.swap(index, width - 1 - index);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,175 @@
+//! Conversion of GUIDs between their mixed-endian and big-endian binary encodings.
+//!
+//! RFC 4122 encodes a UUID as 16 bytes in big-endian. Microsoft's GUID structure instead encodes its
+//! first three fields (a 32-bit field and two 16-bit fields) in little-endian while leaving the final
+//! eight bytes as they are. The two encodings of the same identifier thus differ in the order of
+//! their first eight bytes, and treating one as the other silently produces a different identifier.
+//!
+//! [`Guid`] holds an identifier as its fields and converts between both encodings given the
+//! [`ByteOrder`] of those first three fields: [`ByteOrder::Be`] for RFC 4122 and [`ByteOrder::Le`]
+//! for Microsoft's mixed-endian encoding.
+//!
+//! # Examples
+//! Converting a GUID read from a Windows structure to its RFC 4122 encoding:
+//! ```
+//! use lilbig::guid::Guid;
+//! use lilbig::ByteOrder;
+//!
+//! let mixed = [
+//!     0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+//! ];
+//!
+//! let guid = Guid::from_bytes(mixed, ByteOrder::Le);
+//! assert_eq!(guid.data1, 0x00112233);
+//! assert_eq!(
+//!     guid.to_bytes(ByteOrder::Be),
+//!     [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff],
+//! );
+//! assert_eq!(guid.to_string(), "00112233-4455-6677-8899-aabbccddeeff");
+//! ```
+
+use crate::{ByteOrder, FieldsByteOrdered, WireSafe};
+
+/// Globally unique identifier held as its fields.
+///
+/// The struct is `#[repr(C)]` and matches the layout of Microsoft's `GUID` structure. When read
+/// directly from bytes with [`WireSafe::from_bytes()`], its fields are in the byte-order of those
+/// bytes and may be converted with the [`FieldsByteOrdered`] methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(C)]
+pub struct Guid {
+    /// First 32 bits of the identifier.
+    pub data1: u32,
+    /// Next 16 bits of the identifier.
+    pub data2: u16,
+    /// Next 16 bits of the identifier.
+    pub data3: u16,
+    /// Final 64 bits of the identifier, which are never reordered.
+    pub data4: [u8; 8],
+}
+
+impl FieldsByteOrdered for Guid {
+    #[inline]
+    fn swap_field_orders(&mut self) {
+        self.data1.swap_field_orders();
+        self.data2.swap_field_orders();
+        self.data3.swap_field_orders();
+    }
+}
+
+// SAFETY: `Guid` is `repr(C)`, all of its fields are `WireSafe`, and its fields are arranged such
+// that it contains no padding.
+unsafe impl WireSafe for Guid {}
+
+impl Guid {
+    /// The nil identifier, whose bits are all zero.
+    pub const NIL: Self = Self::new(0, 0, 0, [0; 8]);
+
+    /// Create an identifier from its fields.
+    #[inline]
+    #[must_use]
+    pub const fn new(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> Self {
+        Self {
+            data1,
+            data2,
+            data3,
+            data4,
+        }
+    }
+
+    /// Create an identifier from its 16-byte encoding whose first three fields are encoded in
+    /// `order`.
+    ///
+    /// Use [`ByteOrder::Be`] for RFC 4122 encodings and [`ByteOrder::Le`] for Microsoft's
+    /// mixed-endian encoding.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::guid::Guid;
+    /// use lilbig::ByteOrder;
+    ///
+    /// let bytes = [
+    ///     0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+    /// ];
+    /// let guid = Guid::from_bytes(bytes, ByteOrder::Be);
+    /// assert_eq!((guid.data1, guid.data2, guid.data3), (0x00112233, 0x4455, 0x6677));
+    /// assert_eq!(guid.data4, [0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff]);
+    /// assert_eq!(Guid::from_bytes(bytes, ByteOrder::Le).data1, 0x33221100);
+    /// ```
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 16], order: ByteOrder) -> Self {
+        let [a0, a1, a2, a3, b0, b1, c0, c1, d0, d1, d2, d3, d4, d5, d6, d7] = bytes;
+        let (data1, data2, data3) = match order {
+            ByteOrder::Le => (
+                u32::from_le_bytes([a0, a1, a2, a3]),
+                u16::from_le_bytes([b0, b1]),
+                u16::from_le_bytes([c0, c1]),
+            ),
+            ByteOrder::Be => (
+                u32::from_be_bytes([a0, a1, a2, a3]),
+                u16::from_be_bytes([b0, b1]),
+                u16::from_be_bytes([c0, c1]),
+            ),
+        };
+        Self::new(data1, data2, data3, [d0, d1, d2, d3, d4, d5, d6, d7])
+    }
+
+    /// Encode the identifier as 16 bytes with its first three fields encoded in `order`.
+    ///
+    /// Use [`ByteOrder::Be`] for RFC 4122 encodings and [`ByteOrder::Le`] for Microsoft's
+    /// mixed-endian encoding.
+    #[must_use]
+    pub const fn to_bytes(self, order: ByteOrder) -> [u8; 16] {
+        let ([a0, a1, a2, a3], [b0, b1], [c0, c1]) = match order {
+            ByteOrder::Le => (
+                self.data1.to_le_bytes(),
+                self.data2.to_le_bytes(),
+                self.data3.to_le_bytes(),
+            ),
+            ByteOrder::Be => (
+                self.data1.to_be_bytes(),
+                self.data2.to_be_bytes(),
+                self.data3.to_be_bytes(),
+            ),
+        };
+        let [d0, d1, d2, d3, d4, d5, d6, d7] = self.data4;
+        [
+            a0, a1, a2, a3, b0, b1, c0, c1, d0, d1, d2, d3, d4, d5, d6, d7,
+        ]
+    }
+}
+
+impl core::fmt::Display for Guid {
+    /// Format the identifier in its canonical hyphenated form.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let [d0, d1, d2, d3, d4, d5, d6, d7] = self.data4;
+        write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{d0:02x}{d1:02x}-{d2:02x}{d3:02x}{d4:02x}{d5:02x}{d6:02x}{d7:02x}",
+            self.data1, self.data2, self.data3,
+        )
+    }
+}
+
+/// Convert the 16-byte encoding of an identifier between its RFC 4122 and mixed-endian forms.
+///
+/// The conversion is its own inverse and may be applied in either direction.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::guid::swap_mixed_endian;
+///
+/// let rfc = [
+///     0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+/// ];
+/// let mixed = swap_mixed_endian(rfc);
+/// assert_eq!(mixed[..8], [0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66]);
+/// assert_eq!(mixed[8..], rfc[8..]);
+/// assert_eq!(swap_mixed_endian(mixed), rfc);
+/// ```
+#[must_use]
+pub const fn swap_mixed_endian(bytes: [u8; 16]) -> [u8; 16] {
+    Guid::from_bytes(bytes, ByteOrder::Be).to_bytes(ByteOrder::Le)
+}
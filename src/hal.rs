@@ -0,0 +1,234 @@
+//! Helpers for transferring byte-ordered types over [`embedded-hal`](::embedded_hal) buses.
+//!
+//! Sensors and peripherals commonly expose register maps that are read and written as blocks of
+//! bytes in a byte-order dictated by the device. The helpers within this module encode a
+//! [`WireSafe`] value in the device's byte-order before writing it to the bus, and decode values
+//! read from the bus into the machine's native byte-order.
+//!
+//! Values are encoded within a copy of themselves, so no buffers beyond the value itself are
+//! required.
+
+use crate::{ByteOrder, FieldsByteOrdered, WireSafe};
+use ::embedded_hal::i2c::{self, I2c};
+use ::embedded_hal::spi::{self, SpiDevice};
+
+/// Encode a copy of `value` from the machine's native byte-order to `order`.
+#[inline]
+fn encode<T: FieldsByteOrdered + WireSafe>(value: &T, order: ByteOrder) -> T {
+    let mut encoded = *value;
+    encoded.order_fields_as(ByteOrder::NATIVE, order);
+    encoded
+}
+
+/// Write `value` to an SPI device with its fields encoded in `order`.
+///
+/// # Errors
+/// Returns an error if the underlying bus transaction fails.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+/// # struct Device(Vec<u8>);
+/// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+/// # impl SpiDevice for Device {
+/// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+/// #         for op in ops {
+/// #             if let Operation::Write(bytes) = op { self.0.extend_from_slice(bytes); }
+/// #         }
+/// #         Ok(())
+/// #     }
+/// # }
+/// use lilbig::ByteOrder;
+///
+/// let mut device = Device(Vec::new());
+/// lilbig::hal::spi_write(&mut device, &[0x1234u16, 0x5678u16], ByteOrder::Be).unwrap();
+/// assert_eq!(device.0, [0x12, 0x34, 0x56, 0x78]);
+/// ```
+pub fn spi_write<S, T>(spi: &mut S, value: &T, order: ByteOrder) -> Result<(), S::Error>
+where
+    S: SpiDevice,
+    T: FieldsByteOrdered + WireSafe,
+{
+    spi.write(encode(value, order).as_bytes())
+}
+
+/// Read a value from an SPI device whose fields are encoded in `order`, returning it in the
+/// machine's native byte-order.
+///
+/// # Errors
+/// Returns an error if the underlying bus transaction fails.
+pub fn spi_read<S, T>(spi: &mut S, order: ByteOrder) -> Result<T, S::Error>
+where
+    S: SpiDevice,
+    T: FieldsByteOrdered + WireSafe,
+{
+    let mut value = T::zeroed();
+    spi.read(value.as_bytes_mut())?;
+    value.order_fields_ne(order);
+    Ok(value)
+}
+
+/// Write `command` to an SPI device and then, within the same transaction, write `value` with its
+/// fields encoded in `order`.
+///
+/// This covers the common pattern of a register address or command byte preceding a register
+/// block.
+///
+/// # Errors
+/// Returns an error if the underlying bus transaction fails.
+pub fn spi_write_command<S, T>(
+    spi: &mut S,
+    command: &[u8],
+    value: &T,
+    order: ByteOrder,
+) -> Result<(), S::Error>
+where
+    S: SpiDevice,
+    T: FieldsByteOrdered + WireSafe,
+{
+    let encoded = encode(value, order);
+    spi.transaction(&mut [
+        spi::Operation::Write(command),
+        spi::Operation::Write(encoded.as_bytes()),
+    ])
+}
+
+/// Write `command` to an SPI device and then, within the same transaction, read a value whose
+/// fields are encoded in `order`, returning it in the machine's native byte-order.
+///
+/// # Errors
+/// Returns an error if the underlying bus transaction fails.
+pub fn spi_read_command<S, T>(spi: &mut S, command: &[u8], order: ByteOrder) -> Result<T, S::Error>
+where
+    S: SpiDevice,
+    T: FieldsByteOrdered + WireSafe,
+{
+    let mut value = T::zeroed();
+    spi.transaction(&mut [
+        spi::Operation::Write(command),
+        spi::Operation::Read(value.as_bytes_mut()),
+    ])?;
+    value.order_fields_ne(order);
+    Ok(value)
+}
+
+/// Write `value` to the I2C device at `address` with its fields encoded in `order`.
+///
+/// # Errors
+/// Returns an error if the underlying bus transaction fails.
+pub fn i2c_write<I, T>(
+    i2c: &mut I,
+    address: u8,
+    value: &T,
+    order: ByteOrder,
+) -> Result<(), I::Error>
+where
+    I: I2c,
+    T: FieldsByteOrdered + WireSafe,
+{
+    i2c.write(address, encode(value, order).as_bytes())
+}
+
+/// Read a value from the I2C device at `address` whose fields are encoded in `order`, returning it
+/// in the machine's native byte-order.
+///
+/// # Errors
+/// Returns an error if the underlying bus transaction fails.
+pub fn i2c_read<I, T>(i2c: &mut I, address: u8, order: ByteOrder) -> Result<T, I::Error>
+where
+    I: I2c,
+    T: FieldsByteOrdered + WireSafe,
+{
+    let mut value = T::zeroed();
+    i2c.read(address, value.as_bytes_mut())?;
+    value.order_fields_ne(order);
+    Ok(value)
+}
+
+/// Write `value` to the registers of the I2C device at `address` starting at `register`, with its
+/// fields encoded in `order`.
+///
+/// The register address and the value are written within a single transaction without a repeated
+/// start condition between them.
+///
+/// # Errors
+/// Returns an error if the underlying bus transaction fails.
+pub fn i2c_write_register<I, T>(
+    i2c: &mut I,
+    address: u8,
+    register: u8,
+    value: &T,
+    order: ByteOrder,
+) -> Result<(), I::Error>
+where
+    I: I2c,
+    T: FieldsByteOrdered + WireSafe,
+{
+    let encoded = encode(value, order);
+    i2c.transaction(
+        address,
+        &mut [
+            i2c::Operation::Write(&[register]),
+            i2c::Operation::Write(encoded.as_bytes()),
+        ],
+    )
+}
+
+/// Read a value from the registers of the I2C device at `address` starting at `register`, whose
+/// fields are encoded in `order`, returning it in the machine's native byte-order.
+///
+/// # Errors
+/// Returns an error if the underlying bus transaction fails.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+/// # struct Sensor([u8; 256]);
+/// # impl ErrorType for Sensor { type Error = core::convert::Infallible; }
+/// # impl I2c for Sensor {
+/// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+/// #         let mut register = 0;
+/// #         for op in ops {
+/// #             match op {
+/// #                 Operation::Write(bytes) => register = bytes[0] as usize,
+/// #                 Operation::Read(bytes) => {
+/// #                     bytes.copy_from_slice(&self.0[register..register + bytes.len()]);
+/// #                 }
+/// #             }
+/// #         }
+/// #         Ok(())
+/// #     }
+/// # }
+/// use lilbig::ByteOrder;
+///
+/// /// Address of the sensor on the bus.
+/// const ADDRESS: u8 = 0x76;
+/// /// Register at which the sensor's calibration block begins.
+/// const CALIBRATION_REGISTER: u8 = 0x88;
+///
+/// let mut registers = [0u8; 256];
+/// registers[0x88..0x8c].copy_from_slice(&[0x70, 0x6b, 0x43, 0x67]);
+/// let mut sensor = Sensor(registers);
+///
+/// let calibration: [u16; 2] =
+///     lilbig::hal::i2c_read_register(&mut sensor, ADDRESS, CALIBRATION_REGISTER, ByteOrder::Le)
+///         .unwrap();
+/// assert_eq!(calibration, [0x6b70, 0x6743]);
+/// ```
+pub fn i2c_read_register<I, T>(
+    i2c: &mut I,
+    address: u8,
+    register: u8,
+    order: ByteOrder,
+) -> Result<T, I::Error>
+where
+    I: I2c,
+    T: FieldsByteOrdered + WireSafe,
+{
+    let mut value = T::zeroed();
+    i2c.write_read(address, &[register], value.as_bytes_mut())?;
+    value.order_fields_ne(order);
+    Ok(value)
+}
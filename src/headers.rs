@@ -0,0 +1,36 @@
+//! Helpers shared by the modules providing the header structs of file formats and protocols.
+
+#[cfg(any(feature = "elf", feature = "macho", feature = "pcap", feature = "riff"))]
+use crate::{ByteOrder, FieldsByteOrdered, WireSafe};
+
+/// Implement [`FieldsByteOrdered`](crate::FieldsByteOrdered) and [`WireSafe`](crate::WireSafe) for
+/// a header struct.
+macro_rules! impl_header {
+    ($ty: ty { $($field: ident),+ $(,)? }) => {
+        impl $crate::FieldsByteOrdered for $ty {
+            #[inline]
+            fn swap_field_orders(&mut self) {
+                $($crate::FieldsByteOrdered::swap_field_orders(&mut self.$field);)+
+            }
+        }
+
+        // SAFETY: The header is `repr(C)`, all of its fields are `WireSafe`, and its fields are
+        // arranged such that it contains no padding.
+        unsafe impl $crate::WireSafe for $ty {}
+    };
+}
+
+pub(crate) use impl_header;
+
+/// Read a header encoded in `order` from the start of `bytes`, returning it in the machine's
+/// native byte-order along with the bytes that follow it.
+#[cfg(any(feature = "elf", feature = "macho", feature = "pcap", feature = "riff"))]
+pub(crate) fn read_ordered<T: FieldsByteOrdered + WireSafe>(
+    bytes: &[u8],
+    order: ByteOrder,
+) -> Option<(T, &[u8])> {
+    let (header, rest) = bytes.split_at_checked(core::mem::size_of::<T>())?;
+    let mut header = T::from_bytes(header)?;
+    header.order_fields_ne(order);
+    Some((header, rest))
+}
@@ -0,0 +1,138 @@
+//! Loading arrays of values of a runtime byte-order from readers.
+//!
+//! Scientific formats, such as those adjacent to HDF and NetCDF, commonly store large arrays of
+//! big-endian numbers. The functions in this module read such arrays from any [`Read`]
+//! implementation, convert them to the machine's native byte-order, and collect them into a
+//! [`Vec`] in one call. The arrays are read in chunks, each of which is converted by the bulk
+//! swapping path while it is still in cache, and memory is only allocated as the values arrive such
+//! that a corrupted count does not cause a single giant allocation.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! # #[cfg(not(feature = "no-float"))]
+//! # fn main() -> std::io::Result<()> {
+//! use lilbig::io::read_f64_array_ordered;
+//! use lilbig::ByteOrder;
+//!
+//! let bytes = [0x3f, 0xf8, 0, 0, 0, 0, 0, 0, 0xc0, 0x04, 0, 0, 0, 0, 0, 0];
+//! let values = read_f64_array_ordered(&bytes[..], 2, ByteOrder::Be)?;
+//! assert_eq!(values, [1.5, -2.5]);
+//! # Ok(())
+//! # }
+//! # #[cfg(feature = "no-float")]
+//! # fn main() {}
+//! ```
+
+use crate::{bulk, ByteOrder, FieldsByteOrdered, WireSafe};
+use alloc::vec::Vec;
+use std::io::{self, Read};
+
+/// Number of bytes read and converted at a time.
+const CHUNK_BYTES: usize = 64 * 1024;
+
+/// Read `count` values from `reader`, converting each chunk of values in place with `convert`.
+fn read_chunked<T: WireSafe, R: Read>(
+    mut reader: R,
+    count: usize,
+    convert: impl Fn(&mut [T]),
+) -> io::Result<Vec<T>> {
+    let chunk_len = (CHUNK_BYTES / core::mem::size_of::<T>().max(1)).max(1);
+    let mut values = Vec::new();
+    let mut remaining = count;
+    while remaining > 0 {
+        let start = values.len();
+        let len = remaining.min(chunk_len);
+        values.resize(start + len, T::zeroed());
+        let chunk = values.get_mut(start..).unwrap_or_default();
+        // SAFETY: Implementors of `WireSafe` guarantee that the type contains no padding and that
+        // every bit pattern is valid, so any bytes may be written through the slice.
+        let bytes = unsafe {
+            core::slice::from_raw_parts_mut(
+                chunk.as_mut_ptr().cast::<u8>(),
+                core::mem::size_of_val(chunk),
+            )
+        };
+        reader.read_exact(bytes)?;
+        convert(chunk);
+        remaining -= len;
+    }
+    Ok(values)
+}
+
+/// Read `count` values encoded in `order` from `reader`, converting them to the machine's native
+/// byte-order.
+///
+/// Each value's fields are converted by [`FieldsByteOrdered::order_fields_ne()`]. Arrays of
+/// primitive numbers are read faster by the functions specific to their types, such as
+/// [`read_u32_array_ordered()`].
+///
+/// # Errors
+/// Returns any error returned by `reader`, including an error of the kind
+/// [`io::ErrorKind::UnexpectedEof`] if `reader` ends before `count` values have been read.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::io::read_array_ordered;
+/// use lilbig::ByteOrder;
+///
+/// let bytes = [0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04];
+/// let pairs: Vec<[u16; 2]> = read_array_ordered(&bytes[..], 2, ByteOrder::Be)?;
+/// assert_eq!(pairs, [[1, 2], [3, 4]]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn read_array_ordered<T: FieldsByteOrdered + WireSafe, R: Read>(
+    reader: R,
+    count: usize,
+    order: ByteOrder,
+) -> io::Result<Vec<T>> {
+    read_chunked(reader, count, |chunk: &mut [T]| {
+        chunk.order_fields_ne(order)
+    })
+}
+
+/// Define functions reading arrays of primitive numbers through the bulk swapping path.
+macro_rules! define_read_array {
+    ($($(#[$attr: meta])* $name: ident: $ty: ty),+ $(,)?) => {
+        $(
+        #[doc = concat!(
+            "Read `count` values of type [`", stringify!($ty), "`] encoded in `order` from `reader`,"
+        )]
+        /// converting them to the machine's native byte-order with the bulk swapping path.
+        ///
+        /// # Errors
+        /// Returns any error returned by `reader`, including an error of the kind
+        /// [`io::ErrorKind::UnexpectedEof`] if `reader` ends before `count` values have been read.
+        $(#[$attr])*
+        pub fn $name<R: Read>(reader: R, count: usize, order: ByteOrder) -> io::Result<Vec<$ty>> {
+            read_chunked(reader, count, |chunk: &mut [$ty]| {
+                if order != ByteOrder::NATIVE {
+                    // SAFETY: The primitive numbers contain no padding and every bit pattern is a
+                    // valid value, so any bytes may be written through the slice.
+                    let bytes = unsafe {
+                        core::slice::from_raw_parts_mut(
+                            chunk.as_mut_ptr().cast::<u8>(),
+                            core::mem::size_of_val(chunk),
+                        )
+                    };
+                    bulk::swap_elements_unchecked(bytes, core::mem::size_of::<$ty>());
+                }
+            })
+        }
+        )+
+    };
+}
+
+define_read_array!(
+    read_u16_array_ordered: u16,
+    read_u32_array_ordered: u32,
+    read_u64_array_ordered: u64,
+    read_u128_array_ordered: u128,
+    read_i16_array_ordered: i16,
+    read_i32_array_ordered: i32,
+    read_i64_array_ordered: i64,
+    read_i128_array_ordered: i128,
+);
+#[cfg(not(feature = "no-float"))]
+define_read_array!(read_f32_array_ordered: f32, read_f64_array_ordered: f64);
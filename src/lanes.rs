@@ -0,0 +1,299 @@
+//! Byte-lane swapping utilities for bridging buses of differing byte-orders.
+//!
+//! Bus bridges between masters of differing byte-orders (such as a big-endian PowerPC core
+//! accessing little-endian PCI devices) are built to be one of two kinds:
+//! * **Data-invariant** bridges reverse the bytes within each bus lane so that values exactly as
+//!   wide as a lane retain their numeric value. Values narrower than a lane retain their numeric
+//!   value but are moved to the mirrored position within the lane, whereas values wider than a lane
+//!   are scrambled.
+//! * **Address-invariant** bridges preserve the address of every byte. Values of every width are
+//!   left encoded in the byte-order of the master that wrote them and must be swapped by software.
+//!   Some processors running in their non-native byte-order instead modify the address of narrow
+//!   accesses within a lane, which [`try_munge_address()`] models.
+//!
+//! Plain byte swapping of each element is only correct for an address-invariant bridge. The
+//! functions within this module provide the additional operations required to correct data that
+//! has crossed a data-invariant bridge.
+//!
+//! # Examples
+//! Correcting 32-bit values that crossed a data-invariant bridge with 64-bit lanes:
+//! ```
+//! use lilbig::lanes;
+//!
+//! // Two 32-bit values written by a little-endian master.
+//! let written = [0x11223344u32.to_le_bytes(), 0x55667788u32.to_le_bytes()].concat();
+//!
+//! // The bridge reverses the bytes of each 64-bit lane.
+//! let mut received = written.clone();
+//! lanes::try_swap_lanes(&mut received, 8)?;
+//!
+//! // Each value is now encoded in big-endian, but the two values have swapped positions.
+//! assert_eq!(received[..4], 0x55667788u32.to_be_bytes());
+//! assert_eq!(received[4..], 0x11223344u32.to_be_bytes());
+//!
+//! // Restoring the positions of the values leaves them in their original order, big-endian.
+//! lanes::try_reverse_elements_in_lanes(&mut received, 8, 4)?;
+//! assert_eq!(received[..4], 0x11223344u32.to_be_bytes());
+//! assert_eq!(received[4..], 0x55667788u32.to_be_bytes());
+//! # Ok::<(), lilbig::WidthError>(())
+//! ```
+
+use crate::WidthError;
+
+/// Reverse the bytes within each `lane_width` byte wide lane of `bytes`.
+///
+/// This is the operation performed by a data-invariant bridge and is equivalent to
+/// [`bulk::swap_elements()`](crate::bulk::swap_elements) where the element width is the lane width.
+///
+/// # Panics
+/// Panics if `lane_width` is zero or if the length of `bytes` is not a multiple of `lane_width`.
+/// See [`try_swap_lanes()`] for a non-panicking alternative.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// let mut bytes = [0, 1, 2, 3, 4, 5, 6, 7];
+/// lilbig::lanes::swap_lanes(&mut bytes, 8);
+/// assert_eq!(bytes, [7, 6, 5, 4, 3, 2, 1, 0]);
+/// ```
+#[cfg(not(feature = "no-panic"))]
+#[inline]
+pub fn swap_lanes(bytes: &mut [u8], lane_width: usize) {
+    crate::bulk::swap_elements(bytes, lane_width);
+}
+
+/// Reverse the bytes within each `lane_width` byte wide lane of `bytes`.
+///
+/// This is the non-panicking alternative of `swap_lanes()`.
+///
+/// # Errors
+/// Returns an error under the same conditions as
+/// [`bulk::try_swap_elements()`](crate::bulk::try_swap_elements).
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::WidthError;
+///
+/// let mut bytes = [0, 1, 2, 3, 4, 5, 6, 7];
+/// assert_eq!(lilbig::lanes::try_swap_lanes(&mut bytes, 8), Ok(()));
+/// assert_eq!(bytes, [7, 6, 5, 4, 3, 2, 1, 0]);
+/// assert_eq!(lilbig::lanes::try_swap_lanes(&mut bytes, 16), Err(WidthError::IndivisibleLength));
+/// ```
+#[inline]
+pub fn try_swap_lanes(bytes: &mut [u8], lane_width: usize) -> Result<(), WidthError> {
+    crate::bulk::try_swap_elements(bytes, lane_width)
+}
+
+/// Reverse the order of the `element_width` byte wide elements within each `lane_width` byte wide
+/// lane of `bytes`, leaving the bytes within each element in their original order.
+///
+/// Applying this after [`swap_lanes()`](fn@swap_lanes) (or the reverse) results in each element's
+/// bytes being swapped in place, which is the correction required for elements narrower than the
+/// lanes of a data-invariant bridge.
+///
+/// # Panics
+/// Panics if `lane_width` or `element_width` is zero, if `lane_width` is not a multiple of
+/// `element_width`, or if the length of `bytes` is not a multiple of `lane_width`. See
+/// [`try_reverse_elements_in_lanes()`] for a non-panicking alternative.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// let mut bytes = [0, 1, 2, 3, 4, 5, 6, 7];
+/// lilbig::lanes::reverse_elements_in_lanes(&mut bytes, 8, 2);
+/// assert_eq!(bytes, [6, 7, 4, 5, 2, 3, 0, 1]);
+/// ```
+#[cfg(not(feature = "no-panic"))]
+pub fn reverse_elements_in_lanes(bytes: &mut [u8], lane_width: usize, element_width: usize) {
+    if let Err(err) = try_reverse_elements_in_lanes(bytes, lane_width, element_width) {
+        panic!("{err}");
+    }
+}
+
+/// Reverse the order of the `element_width` byte wide elements within each `lane_width` byte wide
+/// lane of `bytes`, leaving the bytes within each element in their original order.
+///
+/// This is the non-panicking alternative of `reverse_elements_in_lanes()`.
+///
+/// # Errors
+/// Returns [`WidthError::Zero`] if `lane_width` or `element_width` is zero,
+/// [`WidthError::IndivisibleWidth`] if `lane_width` is not a multiple of `element_width`, or
+/// [`WidthError::IndivisibleLength`] if the length of `bytes` is not a multiple of `lane_width`.
+/// The contents of `bytes` are left unmodified upon error.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::WidthError;
+/// use lilbig::lanes::try_reverse_elements_in_lanes;
+///
+/// let mut bytes = [0, 1, 2, 3, 4, 5, 6, 7];
+/// assert_eq!(try_reverse_elements_in_lanes(&mut bytes, 8, 4), Ok(()));
+/// assert_eq!(bytes, [4, 5, 6, 7, 0, 1, 2, 3]);
+/// assert_eq!(try_reverse_elements_in_lanes(&mut bytes, 8, 3), Err(WidthError::IndivisibleWidth));
+/// assert_eq!(try_reverse_elements_in_lanes(&mut [], 0, 1), Err(WidthError::Zero));
+/// ```
+pub fn try_reverse_elements_in_lanes(
+    bytes: &mut [u8],
+    lane_width: usize,
+    element_width: usize,
+) -> Result<(), WidthError> {
+    if lane_width == 0 || element_width == 0 {
+        return Err(WidthError::Zero);
+    }
+    if !lane_width.is_multiple_of(element_width) {
+        return Err(WidthError::IndivisibleWidth);
+    }
+    if !bytes.len().is_multiple_of(lane_width) {
+        return Err(WidthError::IndivisibleLength);
+    }
+    reverse_elements_in_lanes_unchecked(bytes, lane_width, element_width);
+    Ok(())
+}
+
+/// Implementation of [`try_reverse_elements_in_lanes()`] that assumes its arguments have already
+/// been validated.
+fn reverse_elements_in_lanes_unchecked(bytes: &mut [u8], lane_width: usize, element_width: usize) {
+    // Reversing all the bytes of a lane and then reversing the bytes of each element within it
+    // results in the elements being reversed with their bytes left in their original order.
+    for lane in bytes.chunks_exact_mut(lane_width) {
+        lane.reverse();
+        lane.chunks_exact_mut(element_width)
+            .for_each(<[u8]>::reverse);
+    }
+}
+
+/// Compute the address actually accessed when a processor performs an `access_width` byte wide
+/// access of `address` upon a bus with `lane_width` byte wide lanes while operating in its
+/// non-native byte-order via address munging.
+///
+/// Munging inverts the position of an access within its lane, which is accomplished by applying an
+/// exclusive-or of `lane_width - access_width` to the address. Munging is its own inverse.
+///
+/// # Panics
+/// Panics if `lane_width` or `access_width` is not a power of two, or if `access_width` is greater
+/// than `lane_width`. See [`try_munge_address()`] for a non-panicking alternative.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::lanes::munge_address;
+///
+/// // PowerPC little-endian mode upon a 64-bit bus.
+/// assert_eq!(munge_address(0x1000, 1, 8), 0x1007);
+/// assert_eq!(munge_address(0x1002, 2, 8), 0x1004);
+/// assert_eq!(munge_address(0x1004, 4, 8), 0x1000);
+/// assert_eq!(munge_address(0x1000, 8, 8), 0x1000);
+/// ```
+#[cfg(not(feature = "no-panic"))]
+#[must_use]
+pub const fn munge_address(address: usize, access_width: usize, lane_width: usize) -> usize {
+    match try_munge_address(address, access_width, lane_width) {
+        Ok(address) => address,
+        Err(err) => panic!("{}", err.message()),
+    }
+}
+
+/// Compute the address actually accessed when a processor performs an `access_width` byte wide
+/// access of `address` upon a bus with `lane_width` byte wide lanes while operating in its
+/// non-native byte-order via address munging.
+///
+/// This is the non-panicking alternative of `munge_address()`.
+///
+/// # Errors
+/// Returns [`WidthError::NotPowerOfTwo`] if `lane_width` or `access_width` is not a power of two,
+/// or [`WidthError::ExceedsWidth`] if `access_width` is greater than `lane_width`.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::WidthError;
+/// use lilbig::lanes::try_munge_address;
+///
+/// assert_eq!(try_munge_address(0x1000, 2, 4), Ok(0x1002));
+/// assert_eq!(try_munge_address(0x1000, 3, 4), Err(WidthError::NotPowerOfTwo));
+/// assert_eq!(try_munge_address(0x1000, 8, 4), Err(WidthError::ExceedsWidth));
+/// ```
+pub const fn try_munge_address(
+    address: usize,
+    access_width: usize,
+    lane_width: usize,
+) -> Result<usize, WidthError> {
+    if !lane_width.is_power_of_two() || !access_width.is_power_of_two() {
+        return Err(WidthError::NotPowerOfTwo);
+    }
+    if access_width > lane_width {
+        return Err(WidthError::ExceedsWidth);
+    }
+    Ok(address ^ (lane_width - access_width))
+}
+
+/// Swap the two 32-bit words of a 64-bit lane, leaving the bytes within each word in their original
+/// order.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// assert_eq!(
+///     lilbig::lanes::swap_words_u64(0x0011223344556677),
+///     0x4455667700112233,
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub const fn swap_words_u64(value: u64) -> u64 {
+    value.rotate_left(32)
+}
+
+/// Reverse the order of the four 16-bit half-words of a 64-bit lane, leaving the bytes within each
+/// half-word in their original order.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// assert_eq!(
+///     lilbig::lanes::swap_half_words_u64(0x0011223344556677),
+///     0x6677445522330011,
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub const fn swap_half_words_u64(value: u64) -> u64 {
+    let value = value.rotate_left(32);
+    ((value & 0xffff0000ffff0000) >> 16) | ((value & 0x0000ffff0000ffff) << 16)
+}
+
+/// Swap the bytes within each of the two 32-bit words of a 64-bit lane, leaving the words in their
+/// original positions.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// assert_eq!(
+///     lilbig::lanes::swap_bytes_in_words_u64(0x0011223344556677),
+///     0x3322110077665544,
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub const fn swap_bytes_in_words_u64(value: u64) -> u64 {
+    swap_words_u64(value.swap_bytes())
+}
+
+/// Swap the bytes within each of the four 16-bit half-words of a 64-bit lane, leaving the
+/// half-words in their original positions.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// assert_eq!(
+///     lilbig::lanes::swap_bytes_in_half_words_u64(0x0011223344556677),
+///     0x1100332255447766,
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub const fn swap_bytes_in_half_words_u64(value: u64) -> u64 {
+    ((value & 0xff00ff00ff00ff00) >> 8) | ((value & 0x00ff00ff00ff00ff) << 8)
+}
@@ -0,0 +1,801 @@
+//! Tables describing which bytes of a type are swapped when converting its byte-order.
+//!
+//! A type implementing [`SwapLayout`] describes itself as a table of fields, each of which is a
+//! region of bytes that is reversed as a unit when the byte-order of the type's fields is swapped.
+//! Nested structs and arrays are flattened such that the table lists only the fields of primitive
+//! types. Such a table allows the result of [`FieldsByteOrdered::swap_field_orders()`] to be checked
+//! against an independent reference, as is done by `assert_matches_layout()` of the `testing`
+//! feature.
+//!
+//! With the `derive` feature enabled, [`SwapLayout`] may be derived for structs.
+//!
+//! A type implementing [`SwapPermutation`] additionally describes the swapping of its fields as a
+//! single permutation of its bytes, which allows slices of the type to be converted with bulk byte
+//! shuffles by [`swap_permuted()`].
+//!
+//! Layouts that are only known at runtime, such as those described by a schema file, are instead
+//! described by a [`TypeLayout`], which swaps the byte-order of records of raw bytes. Records of raw
+//! bytes whose layouts are known at compile time may likewise be swapped by the functions created
+//! by [`layout!`](crate::layout!), without defining a struct.
+//!
+//! # Examples
+//! Listing the fields of a type:
+//! ```
+//! use lilbig::layout::{FieldLayout, SwapLayout};
+//!
+//! let fields = <[u16; 2]>::swap_fields().collect::<Vec<_>>();
+//! assert_eq!(fields, [FieldLayout::new(0, 2), FieldLayout::new(2, 2)]);
+//! ```
+
+use crate::f80::F80;
+use crate::{FieldsByteOrdered, WidthError, WireSafe};
+use core::marker::PhantomData;
+
+#[cfg(feature = "derive")]
+pub use lilbig_derive::{SwapLayout, SwapPermutation};
+
+/// Region of bytes within a type that is reversed as a unit when swapping its byte-order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldLayout {
+    /// Offset of the field in bytes from the start of the type.
+    pub offset: usize,
+    /// Width of the field in bytes.
+    pub width: usize,
+    /// Whether the field is of a width other than that of a primitive integer, such as a 24-bit
+    /// integer or an 80-bit float, which [`TypeLayout::try_new()`] would otherwise reject.
+    pub opaque: bool,
+}
+
+impl FieldLayout {
+    /// Create a field of `width` bytes at `offset`.
+    #[inline]
+    #[must_use]
+    pub const fn new(offset: usize, width: usize) -> Self {
+        Self {
+            offset,
+            width,
+            opaque: false,
+        }
+    }
+
+    /// Create an opaque field of `width` bytes at `offset`, which may be of any width.
+    #[inline]
+    #[must_use]
+    pub const fn opaque(offset: usize, width: usize) -> Self {
+        Self {
+            offset,
+            width,
+            opaque: true,
+        }
+    }
+
+    /// Retrieve the field moved further from the start of the type by `offset` bytes, as is the
+    /// case when the type containing the field is itself nested within another type.
+    #[inline]
+    #[must_use]
+    pub const fn offset_by(self, offset: usize) -> Self {
+        Self {
+            offset: self.offset + offset,
+            ..self
+        }
+    }
+}
+
+/// Trait for types describing the fields whose bytes are reversed by
+/// [`FieldsByteOrdered::swap_field_orders()`].
+///
+/// # Examples
+/// Implementing the trait for a struct:
+/// ```
+/// use core::mem::offset_of;
+/// use lilbig::layout::{FieldLayout, SwapLayout};
+/// use lilbig::FieldsByteOrdered;
+///
+/// #[repr(C)]
+/// struct Sample {
+///     id: u32,
+///     channel: u16,
+/// }
+///
+/// impl FieldsByteOrdered for Sample {
+///     fn swap_field_orders(&mut self) {
+///         self.id.swap_field_orders();
+///         self.channel.swap_field_orders();
+///     }
+/// }
+///
+/// impl SwapLayout for Sample {
+///     const SWAP_FIELD_COUNT: usize = 2;
+///
+///     fn swap_field(index: usize) -> Option<FieldLayout> {
+///         match index {
+///             0 => Some(FieldLayout::new(offset_of!(Sample, id), 4)),
+///             1 => Some(FieldLayout::new(offset_of!(Sample, channel), 2)),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Sample::swap_fields().map(|field| field.width).sum::<usize>(), 6);
+/// ```
+pub trait SwapLayout: FieldsByteOrdered {
+    /// Number of fields within the type's table.
+    const SWAP_FIELD_COUNT: usize;
+
+    /// Retrieve the field at `index` within the type's table, or [`None`] if `index` is not less
+    /// than [`SWAP_FIELD_COUNT`](SwapLayout::SWAP_FIELD_COUNT).
+    fn swap_field(index: usize) -> Option<FieldLayout>;
+
+    /// Create an iterator over the fields within the type's table.
+    #[inline]
+    #[must_use]
+    fn swap_fields() -> SwapFields<Self>
+    where
+        Self: Sized,
+    {
+        SwapFields {
+            index: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the fields within the table of a type implementing [`SwapLayout`].
+#[derive(Debug)]
+pub struct SwapFields<T> {
+    /// Index of the next field.
+    index: usize,
+    /// Marker of the type whose fields are iterated over.
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: SwapLayout> Iterator for SwapFields<T> {
+    type Item = FieldLayout;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let field = T::swap_field(self.index)?;
+        self.index += 1;
+        Some(field)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = T::SWAP_FIELD_COUNT.saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: SwapLayout> ExactSizeIterator for SwapFields<T> {}
+
+/// Implement [`SwapLayout`] for a set of primitive types as a single field spanning the type.
+macro_rules! impl_swap_layout_primitive {
+    ($($ty: ty),+) => {
+        $(impl SwapLayout for $ty {
+            const SWAP_FIELD_COUNT: usize = 1;
+
+            #[inline]
+            fn swap_field(index: usize) -> Option<FieldLayout> {
+                match index {
+                    0 => Some(FieldLayout::new(0, core::mem::size_of::<$ty>())),
+                    _ => None,
+                }
+            }
+        })+
+    };
+}
+
+impl_swap_layout_primitive!(bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+#[cfg(not(feature = "no-float"))]
+impl_swap_layout_primitive!(f32, f64);
+#[cfg(not(feature = "strict-usize"))]
+impl_swap_layout_primitive!(isize, usize);
+
+/// The 10 bytes of the value form a single opaque field, as they are wider than a primitive
+/// integer.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::f80::F80;
+/// use lilbig::layout::{FieldLayout, SwapLayout, TypeLayout};
+///
+/// let fields = F80::swap_fields().collect::<Vec<_>>();
+/// assert_eq!(fields, [FieldLayout::opaque(0, 10)]);
+/// assert!(TypeLayout::try_new(10, &fields).is_ok());
+/// ```
+impl SwapLayout for F80 {
+    const SWAP_FIELD_COUNT: usize = 1;
+
+    #[inline]
+    fn swap_field(index: usize) -> Option<FieldLayout> {
+        match index {
+            0 => Some(FieldLayout::opaque(0, core::mem::size_of::<Self>())),
+            _ => None,
+        }
+    }
+}
+
+impl<T: SwapLayout, const N: usize> SwapLayout for [T; N] {
+    const SWAP_FIELD_COUNT: usize = T::SWAP_FIELD_COUNT * N;
+
+    fn swap_field(index: usize) -> Option<FieldLayout> {
+        let element = index.checked_div(T::SWAP_FIELD_COUNT)?;
+        if element >= N {
+            return None;
+        }
+        let field = T::swap_field(index % T::SWAP_FIELD_COUNT)?;
+        Some(field.offset_by(element * core::mem::size_of::<T>()))
+    }
+}
+
+/// Trait for types providing the permutation applied to their bytes by
+/// [`FieldsByteOrdered::swap_field_orders()`].
+///
+/// The permutation of a type covers the entire type at once, which allows [`swap_permuted()`] to
+/// convert slices of the type with a single byte shuffle per 16 bytes, such as the `pshufb`
+/// instruction of x86 or the `tbl` instruction of AArch64, rather than swapping each field
+/// individually.
+///
+/// With the `derive` feature enabled, [`SwapPermutation`] may be derived for structs.
+///
+/// # Examples
+/// Implementing the trait for a struct:
+/// ```
+/// use lilbig::layout::{self, SwapPermutation};
+/// use lilbig::{FieldsByteOrdered, WireSafe};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// #[repr(C)]
+/// struct Sample {
+///     channel: u16,
+///     level: [u8; 2],
+/// }
+///
+/// impl FieldsByteOrdered for Sample {
+///     fn swap_field_orders(&mut self) {
+///         self.channel.swap_field_orders();
+///     }
+/// }
+///
+/// unsafe impl WireSafe for Sample {}
+///
+/// impl SwapPermutation for Sample {
+///     const SWAP_PERMUTATION: &'static [usize] = &[1, 0, 2, 3];
+/// }
+///
+/// let mut samples = [Sample { channel: 0x0102, level: [3, 4] }; 5];
+/// layout::swap_permuted(&mut samples);
+/// assert_eq!(samples, [Sample { channel: 0x0201, level: [3, 4] }; 5]);
+/// ```
+pub trait SwapPermutation: FieldsByteOrdered + WireSafe {
+    /// Permutation of the bytes of the type, such that byte `i` of a swapped value is byte
+    /// `SWAP_PERMUTATION[i]` of the original value. Its length is the size of the type.
+    const SWAP_PERMUTATION: &'static [usize];
+}
+
+/// Create the permutation of `N` bytes that leaves each byte in place.
+///
+/// This is the starting point of permutations built by [`permute_field()`].
+#[inline]
+#[must_use]
+pub const fn identity_permutation<const N: usize>() -> [usize; N] {
+    let mut permutation = [0; N];
+    let mut rest = permutation.as_mut_slice();
+    let mut index = 0;
+    while let Some((slot, remaining)) = rest.split_first_mut() {
+        *slot = index;
+        index += 1;
+        rest = remaining;
+    }
+    permutation
+}
+
+/// Create the permutation of `N` bytes that reverses their order.
+const fn reversed_permutation<const N: usize>() -> [usize; N] {
+    let mut permutation = [0; N];
+    let mut rest = permutation.as_mut_slice();
+    let mut index = N;
+    while let Some((slot, remaining)) = rest.split_first_mut() {
+        index -= 1;
+        *slot = index;
+        rest = remaining;
+    }
+    permutation
+}
+
+/// Apply the permutation `field` of a field at `offset` bytes to the permutation of the type
+/// containing the field.
+///
+/// Bytes of `field` lying beyond the end of `permutation` are ignored.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::layout::{identity_permutation, permute_field, SwapPermutation};
+///
+/// const PERMUTATION: [usize; 8] = {
+///     let mut permutation = identity_permutation::<8>();
+///     permute_field(&mut permutation, 0, u32::SWAP_PERMUTATION);
+///     permute_field(&mut permutation, 4, u16::SWAP_PERMUTATION);
+///     permutation
+/// };
+/// assert_eq!(PERMUTATION, [3, 2, 1, 0, 5, 4, 6, 7]);
+/// ```
+pub const fn permute_field(permutation: &mut [usize], offset: usize, field: &[usize]) {
+    let Some((_, mut rest)) = permutation.split_at_mut_checked(offset) else {
+        return;
+    };
+    let mut field = field;
+    while let (Some((slot, remaining)), Some((index, field_remaining))) =
+        (rest.split_first_mut(), field.split_first())
+    {
+        *slot = offset + *index;
+        rest = remaining;
+        field = field_remaining;
+    }
+}
+
+/// Implement [`SwapPermutation`] for a set of primitive types as a reversal of their bytes.
+macro_rules! impl_swap_permutation_primitive {
+    ($($ty: ty),+) => {
+        $(impl SwapPermutation for $ty {
+            const SWAP_PERMUTATION: &'static [usize] =
+                &reversed_permutation::<{ core::mem::size_of::<$ty>() }>();
+        })+
+    };
+}
+
+impl_swap_permutation_primitive!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+#[cfg(not(feature = "no-float"))]
+impl_swap_permutation_primitive!(f32, f64);
+#[cfg(not(feature = "strict-usize"))]
+impl_swap_permutation_primitive!(isize, usize);
+
+/// Swap the byte-order of the fields of each of `values` by applying the permutation of `T`.
+///
+/// With the `simd` feature enabled upon targets providing a byte shuffle instruction, and when the
+/// size of `T` divides 16 bytes, the permutation is repeated into a 16 byte shuffle mask that
+/// converts several values with a single instruction. Otherwise, or should the permutation of `T`
+/// not cover the entire type, each value is swapped with
+/// [`swap_field_orders()`](FieldsByteOrdered::swap_field_orders), as are the values trailing the
+/// last shuffle.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::layout;
+///
+/// let mut values = [0x0102_u16, 0x0304, 0x0506, 0x0708, 0x090a, 0x0b0c, 0x0d0e, 0x0f10, 0x1112];
+/// layout::swap_permuted(&mut values);
+/// assert_eq!(values, [0x0201, 0x0403, 0x0605, 0x0807, 0x0a09, 0x0c0b, 0x0e0d, 0x100f, 0x1211]);
+/// ```
+pub fn swap_permuted<T: SwapPermutation>(values: &mut [T]) {
+    #[cfg(all(
+        feature = "simd",
+        any(
+            target_feature = "ssse3",
+            target_feature = "neon",
+            target_feature = "simd128"
+        ),
+    ))]
+    let values = shuffle::swap_shuffled(values);
+    values.iter_mut().for_each(T::swap_field_orders);
+}
+
+/// Conversion of slices with byte shuffle instructions, for targets providing them.
+#[cfg(all(
+    feature = "simd",
+    any(
+        target_feature = "ssse3",
+        target_feature = "neon",
+        target_feature = "simd128"
+    ),
+))]
+mod shuffle {
+    use super::SwapPermutation;
+    use core::simd::Simd;
+
+    /// Width in bytes of the shuffles performed by [`swap_shuffled()`].
+    const SHUFFLE_WIDTH: usize = 16;
+
+    /// Create the shuffle mask applying the permutation of `T` to each of the values within
+    /// [`SHUFFLE_WIDTH`] bytes, if the permutation covers the entire type and its size divides
+    /// the width of the mask.
+    fn shuffle_mask<T: SwapPermutation>() -> Option<[u8; SHUFFLE_WIDTH]> {
+        let permutation = T::SWAP_PERMUTATION;
+        let size = core::mem::size_of::<T>();
+        if permutation.len() != size || size == 0 || !SHUFFLE_WIDTH.is_multiple_of(size) {
+            return None;
+        }
+        let mut mask = [0u8; SHUFFLE_WIDTH];
+        for (index, slot) in mask.iter_mut().enumerate() {
+            let offset = index % size;
+            if let Some(source) = permutation.get(offset) {
+                // The source lies within the same value as `index`, so it is less than the width
+                // of the mask.
+                *slot = (index - offset + *source) as u8;
+            }
+        }
+        Some(mask)
+    }
+
+    /// Swap the byte-order of the fields of as many of `values` as fill whole shuffles, retrieving
+    /// the values that remain to be swapped.
+    pub(super) fn swap_shuffled<T: SwapPermutation>(values: &mut [T]) -> &mut [T] {
+        let Some(mask) = shuffle_mask::<T>() else {
+            return values;
+        };
+        let mask = Simd::from_array(mask);
+        let mut chunks = values.chunks_exact_mut(SHUFFLE_WIDTH / core::mem::size_of::<T>());
+        for chunk in &mut chunks {
+            // SAFETY: `T` implements `WireSafe`, so it contains no padding and any bytes may be
+            // written to it. The chunk holds `SHUFFLE_WIDTH / size` values of `size` bytes each.
+            let bytes = unsafe {
+                core::slice::from_raw_parts_mut(chunk.as_mut_ptr().cast::<u8>(), SHUFFLE_WIDTH)
+            };
+            let shuffled = Simd::<u8, SHUFFLE_WIDTH>::from_slice(bytes).swizzle_dyn(mask);
+            shuffled.copy_to_slice(bytes);
+        }
+        chunks.into_remainder()
+    }
+}
+
+/// Difference between the fields at one index of two tables of fields.
+///
+/// Either field is [`None`] when its table ended before the index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldMismatch {
+    /// Index of the fields within their tables.
+    pub index: usize,
+    /// Field of the expected table.
+    pub expected: Option<FieldLayout>,
+    /// Field of the actual table.
+    pub actual: Option<FieldLayout>,
+}
+
+impl core::fmt::Display for FieldMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "field {}: expected ", self.index)?;
+        write_field(f, self.expected)?;
+        f.write_str(", found ")?;
+        write_field(f, self.actual)
+    }
+}
+
+/// Write the offset and width of `field`, or that there is no field.
+fn write_field(f: &mut core::fmt::Formatter<'_>, field: Option<FieldLayout>) -> core::fmt::Result {
+    match field {
+        Some(field) => write!(f, "{} bytes at offset {}", field.width, field.offset),
+        None => f.write_str("no field"),
+    }
+}
+
+/// Compare two tables of fields, such as the table of a type implementing [`SwapLayout`] and a
+/// table parsed from a C header or debugging information, yielding the fields whose offsets or
+/// widths differ.
+///
+/// Fields are compared index by index, so both tables must list their fields in the same order.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::layout::{self, FieldLayout, FieldMismatch};
+///
+/// let expected = [FieldLayout::new(0, 4), FieldLayout::new(4, 2), FieldLayout::new(6, 2)];
+/// let actual = [FieldLayout::new(0, 4), FieldLayout::new(4, 4)];
+///
+/// let mismatches = layout::diff_fields(expected, actual).collect::<Vec<_>>();
+/// assert_eq!(
+///     mismatches,
+///     [
+///         FieldMismatch {
+///             index: 1,
+///             expected: Some(FieldLayout::new(4, 2)),
+///             actual: Some(FieldLayout::new(4, 4)),
+///         },
+///         FieldMismatch { index: 2, expected: Some(FieldLayout::new(6, 2)), actual: None },
+///     ],
+/// );
+/// assert_eq!(
+///     mismatches[1].to_string(),
+///     "field 2: expected 2 bytes at offset 6, found no field",
+/// );
+/// ```
+pub fn diff_fields<E, A>(expected: E, actual: A) -> DiffFields<E::IntoIter, A::IntoIter>
+where
+    E: IntoIterator<Item = FieldLayout>,
+    A: IntoIterator<Item = FieldLayout>,
+{
+    DiffFields {
+        expected: expected.into_iter(),
+        actual: actual.into_iter(),
+        index: 0,
+    }
+}
+
+/// Iterator over the differences between two tables of fields, created by [`diff_fields()`].
+#[derive(Debug, Clone)]
+pub struct DiffFields<E, A> {
+    /// Remaining fields of the expected table.
+    expected: E,
+    /// Remaining fields of the actual table.
+    actual: A,
+    /// Index of the next fields.
+    index: usize,
+}
+
+impl<E, A> Iterator for DiffFields<E, A>
+where
+    E: Iterator<Item = FieldLayout>,
+    A: Iterator<Item = FieldLayout>,
+{
+    type Item = FieldMismatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let expected = self.expected.next();
+            let actual = self.actual.next();
+            let index = self.index;
+            self.index += 1;
+            let matches = match (expected, actual) {
+                (None, None) => return None,
+                (Some(expected), Some(actual)) => {
+                    expected.offset == actual.offset && expected.width == actual.width
+                }
+                _ => false,
+            };
+            if !matches {
+                return Some(FieldMismatch {
+                    index,
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+}
+
+/// Error returned when a [`TypeLayout`] is constructed from an invalid description.
+///
+/// Each variant holds the index of the offending field within the description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LayoutError {
+    /// A field extended beyond the end of the record.
+    OutOfBounds(usize),
+    /// A field overlapped the field at the second index.
+    Overlapping(usize, usize),
+    /// A field that was not opaque was of a width other than 1, 2, 4, 8, or 16 bytes.
+    UnsupportedWidth(usize),
+}
+
+impl LayoutError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::OutOfBounds(_) => "field extends beyond the end of the record",
+            Self::Overlapping(..) => "fields overlap",
+            Self::UnsupportedWidth(_) => "field width must be 1, 2, 4, 8, or 16 bytes",
+        }
+    }
+}
+
+impl core::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBounds(index) | Self::UnsupportedWidth(index) => {
+                write!(f, "{} (field {index})", self.message())
+            }
+            Self::Overlapping(first, second) => {
+                write!(f, "{} (fields {first} and {second})", self.message())
+            }
+        }
+    }
+}
+
+impl core::error::Error for LayoutError {}
+
+/// Runtime description of the fields of a record of raw bytes.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::layout::{FieldLayout, TypeLayout};
+///
+/// // A 4-byte field, a 2-byte field, and 2 bytes of padding.
+/// const FIELDS: [FieldLayout; 2] = [FieldLayout::new(0, 4), FieldLayout::new(4, 2)];
+/// const LAYOUT: TypeLayout<'static> = TypeLayout::new(8, &FIELDS);
+///
+/// let mut bytes = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+/// LAYOUT.try_swap_records(&mut bytes)?;
+/// assert_eq!(bytes, [4, 3, 2, 1, 6, 5, 7, 8, 12, 11, 10, 9, 14, 13, 15, 16]);
+/// # Ok::<(), lilbig::WidthError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TypeLayout<'a> {
+    /// Size of a record in bytes.
+    size: usize,
+    /// Fields within a record.
+    fields: &'a [FieldLayout],
+    /// Whether bytes outside of every field are zeroed when swapping a record.
+    zero_padding: bool,
+}
+
+impl<'a> TypeLayout<'a> {
+    /// Create a layout of records of `size` bytes containing `fields`.
+    #[inline]
+    #[must_use]
+    pub const fn new(size: usize, fields: &'a [FieldLayout]) -> Self {
+        Self {
+            size,
+            fields,
+            zero_padding: false,
+        }
+    }
+
+    /// Create a layout of records of `size` bytes containing `fields`, validating that the fields
+    /// lie within the record without overlapping one another and that each field that is not
+    /// opaque is as wide as a primitive integer.
+    ///
+    /// Descriptions read at runtime, such as from a schema file, should be constructed with this
+    /// function so that a corrupted description is reported rather than scrambling records.
+    ///
+    /// # Errors
+    /// Returns [`LayoutError::OutOfBounds`] if a field extends beyond the end of the record,
+    /// [`LayoutError::Overlapping`] if two fields share a byte, or
+    /// [`LayoutError::UnsupportedWidth`] if a field that is not opaque is of a width other than 1,
+    /// 2, 4, 8, or 16 bytes.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::layout::{FieldLayout, LayoutError, TypeLayout};
+    ///
+    /// let fields = [FieldLayout::new(0, 4), FieldLayout::opaque(4, 3)];
+    /// assert!(TypeLayout::try_new(8, &fields).is_ok());
+    /// assert_eq!(TypeLayout::try_new(6, &fields), Err(LayoutError::OutOfBounds(1)));
+    ///
+    /// let fields = [FieldLayout::new(0, 4), FieldLayout::new(2, 2)];
+    /// assert_eq!(TypeLayout::try_new(8, &fields), Err(LayoutError::Overlapping(1, 0)));
+    ///
+    /// let fields = [FieldLayout::new(0, 3)];
+    /// assert_eq!(TypeLayout::try_new(8, &fields), Err(LayoutError::UnsupportedWidth(0)));
+    /// ```
+    pub fn try_new(size: usize, fields: &'a [FieldLayout]) -> Result<Self, LayoutError> {
+        for (index, field) in fields.iter().enumerate() {
+            if !field.opaque && !matches!(field.width, 1 | 2 | 4 | 8 | 16) {
+                return Err(LayoutError::UnsupportedWidth(index));
+            }
+            let end = field.offset.checked_add(field.width);
+            if end.is_none_or(|end| end > size) {
+                return Err(LayoutError::OutOfBounds(index));
+            }
+            let overlapped = fields.iter().take(index).position(|other| {
+                field.offset < other.offset + other.width
+                    && other.offset < field.offset + field.width
+            });
+            if let Some(other) = overlapped {
+                return Err(LayoutError::Overlapping(index, other));
+            }
+        }
+        Ok(Self {
+            size,
+            fields,
+            zero_padding: false,
+        })
+    }
+
+    /// Retrieve the layout with the zeroing of padding enabled, such that swapping a record also
+    /// zeroes each of its bytes lying outside of every field.
+    ///
+    /// This prevents records written to a file or the network from leaking the stale contents of
+    /// their padding.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::layout::{FieldLayout, TypeLayout};
+    ///
+    /// // A 4-byte field, a 2-byte field, and 2 bytes of padding.
+    /// const FIELDS: [FieldLayout; 2] = [FieldLayout::new(0, 4), FieldLayout::new(4, 2)];
+    /// const LAYOUT: TypeLayout<'static> = TypeLayout::new(8, &FIELDS).with_zeroed_padding();
+    ///
+    /// let mut record = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// LAYOUT.swap(&mut record);
+    /// assert_eq!(record, [4, 3, 2, 1, 6, 5, 0, 0]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_zeroed_padding(self) -> Self {
+        Self {
+            zero_padding: true,
+            ..self
+        }
+    }
+
+    /// Check whether swapping a record zeroes its padding.
+    #[inline]
+    #[must_use]
+    pub const fn zeroes_padding(&self) -> bool {
+        self.zero_padding
+    }
+
+    /// Retrieve the size of a record in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Retrieve the fields within a record.
+    #[inline]
+    #[must_use]
+    pub const fn fields(&self) -> &'a [FieldLayout] {
+        self.fields
+    }
+
+    /// Zero each byte of `record` lying outside of every field, regardless of whether the zeroing
+    /// of padding is enabled.
+    pub fn zero_padding(&self, record: &mut [u8]) {
+        for (index, byte) in record.iter_mut().enumerate() {
+            let covered = self
+                .fields
+                .iter()
+                .any(|field| index >= field.offset && index - field.offset < field.width);
+            if !covered {
+                *byte = 0;
+            }
+        }
+    }
+
+    /// Swap the byte-order of each field within `record`, zeroing the padding of `record` if the
+    /// zeroing of padding is enabled.
+    ///
+    /// Fields lying outside of `record` are left unmodified.
+    pub fn swap(&self, record: &mut [u8]) {
+        if self.zero_padding {
+            self.zero_padding(record);
+        }
+        for field in self.fields {
+            let bytes = field
+                .offset
+                .checked_add(field.width)
+                .and_then(|end| record.get_mut(field.offset..end));
+            if let Some(bytes) = bytes {
+                bytes.reverse();
+            }
+        }
+    }
+
+    /// Swap the byte-order of each field within each record of `bytes`.
+    ///
+    /// # Panics
+    /// Panics if the layout's size is zero or if the length of `bytes` is not a multiple of the
+    /// layout's size. See [`try_swap_records()`](TypeLayout::try_swap_records) for a
+    /// non-panicking alternative.
+    #[cfg(not(feature = "no-panic"))]
+    pub fn swap_records(&self, bytes: &mut [u8]) {
+        if let Err(err) = self.try_swap_records(bytes) {
+            panic!("{err}");
+        }
+    }
+
+    /// Swap the byte-order of each field within each record of `bytes`.
+    ///
+    /// # Errors
+    /// Returns [`WidthError::Zero`] if the layout's size is zero, or
+    /// [`WidthError::IndivisibleLength`] if the length of `bytes` is not a multiple of the layout's
+    /// size. The contents of `bytes` are left unmodified upon error.
+    pub fn try_swap_records(&self, bytes: &mut [u8]) -> Result<(), WidthError> {
+        if self.size == 0 {
+            return Err(WidthError::Zero);
+        }
+        if !bytes.len().is_multiple_of(self.size) {
+            return Err(WidthError::IndivisibleLength);
+        }
+        bytes
+            .chunks_exact_mut(self.size)
+            .for_each(|record| self.swap(record));
+        Ok(())
+    }
+}
@@ -2,16 +2,137 @@
 
 #![no_std]
 #![warn(missing_docs)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+#![cfg_attr(
+    feature = "no-panic",
+    deny(
+        clippy::expect_used,
+        clippy::indexing_slicing,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented,
+        clippy::unreachable,
+        clippy::unwrap_used
+    )
+)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+// Allow the derive macros to refer to this crate as `::lilbig` from within it.
+#[cfg(feature = "derive")]
+extern crate self as lilbig;
+
+#[cfg(feature = "bindgen-helpers")]
+pub mod bindgen_helpers;
+#[cfg(feature = "bitfields")]
+pub mod bitfields;
+pub mod bulk;
+pub mod can;
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(feature = "c-header")]
+pub mod cheader;
+#[cfg(feature = "checked")]
+pub mod checked;
+pub mod convert;
 mod core_impls;
+#[cfg(feature = "counters")]
+pub mod counters;
+pub mod cursor;
+#[cfg(feature = "dicom")]
+pub mod dicom;
+#[cfg(feature = "dns")]
+pub mod dns;
+#[cfg(feature = "elf")]
+pub mod elf;
+pub mod f80;
+#[cfg(feature = "fortran")]
+pub mod fortran;
+#[cfg(feature = "fs")]
+pub mod fs;
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
+pub mod guid;
+#[cfg(feature = "embedded-hal")]
+pub mod hal;
+#[cfg(any(
+    feature = "dns",
+    feature = "elf",
+    feature = "macho",
+    feature = "net-headers",
+    feature = "pcap",
+    feature = "riff"
+))]
+mod headers;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod lanes;
+pub mod layout;
+#[cfg(feature = "macho")]
+pub mod macho;
+mod macros;
+pub mod marker;
+pub mod masked;
+pub mod modbus;
+#[cfg(feature = "net-headers")]
+pub mod net;
+pub mod odd_width;
+pub mod offset;
+pub mod ordered;
+pub mod overlay;
+#[cfg(feature = "pcap")]
+pub mod pcap;
+#[cfg(feature = "pixels")]
+pub mod pixels;
+#[cfg(feature = "plausibility")]
+pub mod plausibility;
+pub mod portable;
+#[cfg(feature = "pyo3")]
+pub mod python;
+#[cfg(any(feature = "tock-registers", feature = "volatile-register"))]
+pub mod registers;
+pub mod repr;
+#[cfg(feature = "riff")]
+pub mod riff;
+pub mod samples;
+pub mod scratch;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod subset;
+#[cfg(feature = "swap-trace")]
+pub mod swap_trace;
+pub mod tagged;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod timestamp;
+pub mod tlv;
+#[cfg(feature = "trace")]
+mod trace;
+pub mod unaligned;
+pub mod view;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "zerocopy")]
+mod zerocopy_impls;
+
+#[cfg(feature = "derive")]
+pub use lilbig_derive::{ByteOrdered, FieldsByteOrdered, FieldsByteOrderedWith, WireTwin};
 
 /// Enumeration providing byte-order variants.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// The enumeration is represented by a `u8` whose value is stable, such that byte-orders may be
+/// stored within headers through [`to_repr()`](ByteOrder::to_repr) and
+/// [`try_from_repr()`](ByteOrder::try_from_repr). Byte-orders are ordered by those values, and
+/// default to the compilation target's native byte-order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
 pub enum ByteOrder {
-    /// Little-endian.
-    Le,
-    /// Big-endian.
-    Be,
+    /// Little-endian, represented by `0`.
+    Le = 0,
+    /// Big-endian, represented by `1`.
+    Be = 1,
 }
 
 impl ByteOrder {
@@ -38,6 +159,9 @@ impl ByteOrder {
     /// The opposite of the compilation target's native byte-order.
     pub const NATIVE_OPPOSITE: Self = Self::NATIVE.opposite();
 
+    /// The byte-order of network protocols, which is big-endian.
+    pub const NETWORK: Self = Self::Be;
+
     /// Checks if `self` is the compilation target's native byte-order.
     ///
     /// # Examples
@@ -55,6 +179,60 @@ impl ByteOrder {
         matches!(self, Self::NATIVE)
     }
 
+    /// Retrieves the byte-order marked by the marker type `O`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::marker::{BigEndian, LittleEndian};
+    /// use lilbig::ByteOrder;
+    /// assert_eq!(ByteOrder::of::<LittleEndian>(), ByteOrder::Le);
+    /// assert_eq!(ByteOrder::of::<BigEndian>(), ByteOrder::Be);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn of<O: marker::OrderMarker>() -> Self {
+        O::ORDER
+    }
+
+    /// Retrieves the stable `u8` representation of `self`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    /// assert_eq!(ByteOrder::Le.to_repr(), 0);
+    /// assert_eq!(ByteOrder::Be.to_repr(), 1);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_repr(self) -> u8 {
+        self as u8
+    }
+
+    /// Retrieves the byte-order whose stable `u8` representation is `repr`.
+    ///
+    /// # Errors
+    /// Returns [`ByteOrderReprError::UnknownRepr`] if `repr` is not the representation of any
+    /// byte-order.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::{ByteOrder, ByteOrderReprError};
+    /// assert_eq!(ByteOrder::try_from_repr(1), Ok(ByteOrder::Be));
+    /// assert_eq!(ByteOrder::try_from(0), Ok(ByteOrder::Le));
+    /// assert_eq!(ByteOrder::try_from_repr(2), Err(ByteOrderReprError::UnknownRepr));
+    /// ```
+    #[inline]
+    pub const fn try_from_repr(repr: u8) -> Result<Self, ByteOrderReprError> {
+        match repr {
+            0 => Ok(Self::Le),
+            1 => Ok(Self::Be),
+            _ => Err(ByteOrderReprError::UnknownRepr),
+        }
+    }
+
     /// Retrieves the opposite byte-order of `self`.
     ///
     /// # Examples
@@ -74,6 +252,264 @@ impl ByteOrder {
             Self::Be => Self::Le,
         }
     }
+
+    /// Retrieves the byte-order of UTF-16 text beginning with the code unit `bom`, which was read
+    /// in the machine's native byte-order, or [`None`] if `bom` is not a byte-order mark.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    /// assert_eq!(ByteOrder::from_bom_u16(u16::from_ne_bytes([0xff, 0xfe])), Some(ByteOrder::Le));
+    /// assert_eq!(ByteOrder::from_bom_u16(u16::from_ne_bytes([0xfe, 0xff])), Some(ByteOrder::Be));
+    /// assert_eq!(ByteOrder::from_bom_u16(u16::from_ne_bytes([0x00, 0x41])), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_bom_u16(bom: u16) -> Option<Self> {
+        match bom {
+            0xfeff => Some(Self::NATIVE),
+            0xfffe => Some(Self::NATIVE_OPPOSITE),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the byte-order of UTF-32 text beginning with the code unit `bom`, which was read
+    /// in the machine's native byte-order, or [`None`] if `bom` is not a byte-order mark.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    /// let bom = u32::from_ne_bytes([0x00, 0x00, 0xfe, 0xff]);
+    /// assert_eq!(ByteOrder::from_bom_u32(bom), Some(ByteOrder::Be));
+    /// assert_eq!(ByteOrder::from_bom_u32(bom.swap_bytes()), Some(ByteOrder::Le));
+    /// // UTF-16 text beginning with a byte-order mark followed by `A`.
+    /// let utf16 = u32::from_ne_bytes([0xff, 0xfe, 0x41, 0x00]);
+    /// assert_eq!(ByteOrder::from_bom_u32(utf16), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_bom_u32(bom: u32) -> Option<Self> {
+        match bom {
+            0x0000_feff => Some(Self::NATIVE),
+            0xfffe_0000 => Some(Self::NATIVE_OPPOSITE),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the UTF-16 byte-order mark of `self`, as a code unit whose bytes in the machine's
+    /// native byte-order are those of the mark.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    /// assert_eq!(ByteOrder::Le.to_bom_u16().to_ne_bytes(), [0xff, 0xfe]);
+    /// assert_eq!(ByteOrder::Be.to_bom_u16().to_ne_bytes(), [0xfe, 0xff]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_bom_u16(self) -> u16 {
+        if self.is_native() {
+            0xfeff
+        } else {
+            0xfffe
+        }
+    }
+
+    /// Retrieves the UTF-32 byte-order mark of `self`, as a code unit whose bytes in the machine's
+    /// native byte-order are those of the mark.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    /// assert_eq!(ByteOrder::Le.to_bom_u32().to_ne_bytes(), [0xff, 0xfe, 0x00, 0x00]);
+    /// assert_eq!(ByteOrder::Be.to_bom_u32().to_ne_bytes(), [0x00, 0x00, 0xfe, 0xff]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_bom_u32(self) -> u32 {
+        if self.is_native() {
+            0x0000_feff
+        } else {
+            0xfffe_0000
+        }
+    }
+
+    /// Retrieves the byte-order of a TIFF file beginning with `marker`, or [`None`] if `marker` is
+    /// neither `II` nor `MM`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    /// assert_eq!(ByteOrder::from_tiff_marker(*b"II"), Some(ByteOrder::Le));
+    /// assert_eq!(ByteOrder::from_tiff_marker(*b"MM"), Some(ByteOrder::Be));
+    /// assert_eq!(ByteOrder::from_tiff_marker(*b"IM"), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_tiff_marker(marker: [u8; 2]) -> Option<Self> {
+        match &marker {
+            b"II" => Some(Self::Le),
+            b"MM" => Some(Self::Be),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the marker beginning TIFF files of byte-order `self`, which is `II` for
+    /// little-endian and `MM` for big-endian.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    /// assert_eq!(&ByteOrder::Le.to_tiff_marker(), b"II");
+    /// assert_eq!(&ByteOrder::Be.to_tiff_marker(), b"MM");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_tiff_marker(self) -> [u8; 2] {
+        match self {
+            Self::Le => *b"II",
+            Self::Be => *b"MM",
+        }
+    }
+
+    /// Retrieves the byte-order of an ELF file whose `EI_DATA` identification byte is `data`, or
+    /// [`None`] if `data` is neither `ELFDATA2LSB` (`1`) nor `ELFDATA2MSB` (`2`).
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    /// assert_eq!(ByteOrder::from_elf_data(1), Some(ByteOrder::Le));
+    /// assert_eq!(ByteOrder::from_elf_data(2), Some(ByteOrder::Be));
+    /// assert_eq!(ByteOrder::from_elf_data(0), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_elf_data(data: u8) -> Option<Self> {
+        match data {
+            1 => Some(Self::Le),
+            2 => Some(Self::Be),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the `EI_DATA` identification byte of ELF files of byte-order `self`, which is
+    /// `ELFDATA2LSB` (`1`) for little-endian and `ELFDATA2MSB` (`2`) for big-endian.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    /// assert_eq!(ByteOrder::Le.to_elf_data(), 1);
+    /// assert_eq!(ByteOrder::Be.to_elf_data(), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_elf_data(self) -> u8 {
+        match self {
+            Self::Le => 1,
+            Self::Be => 2,
+        }
+    }
+
+    /// Detects the byte-order of a file from its magic number, where `expected` is the magic number
+    /// and `found` is the magic number read from the file in the machine's native byte-order.
+    ///
+    /// Returns [`ByteOrder::NATIVE`] if `found` equals `expected`,
+    /// [`ByteOrder::NATIVE_OPPOSITE`] if `found` equals `expected` once its byte-order is swapped,
+    /// or [`None`] otherwise. Magic numbers that are unchanged by swapping their byte-order can't
+    /// distinguish between byte-orders, and are always detected as native.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    ///
+    /// const MAGIC: u32 = 0xa1b2c3d4;
+    /// let found = u32::from_ne_bytes([0xd4, 0xc3, 0xb2, 0xa1]);
+    /// assert_eq!(ByteOrder::detect_by_magic(MAGIC, found), Some(ByteOrder::Le));
+    /// let found = u32::from_ne_bytes([0xa1, 0xb2, 0xc3, 0xd4]);
+    /// assert_eq!(ByteOrder::detect_by_magic(MAGIC, found), Some(ByteOrder::Be));
+    /// assert_eq!(ByteOrder::detect_by_magic(MAGIC, 0), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn detect_by_magic<T: ByteOrdered + Eq>(expected: T, found: T) -> Option<Self> {
+        if found == expected {
+            Some(Self::NATIVE)
+        } else if found.swapped_order() == expected {
+            Some(Self::NATIVE_OPPOSITE)
+        } else {
+            None
+        }
+    }
+
+    /// Decodes `value`, which is encoded in `self`, to the machine's native byte-order.
+    ///
+    /// This is equivalent to [`value.ordered_ne(self)`](ByteOrdered::ordered_ne), and reads
+    /// naturally where the byte-order is the subject of the conversion.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    ///
+    /// let order = ByteOrder::Be;
+    /// assert_eq!(order.decode(0x1234_u16.to_be()), 0x1234);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn decode<T: ByteOrdered>(self, value: T) -> T {
+        value.ordered_ne(self)
+    }
+
+    /// Encodes `value`, which is in the machine's native byte-order, in `self`.
+    ///
+    /// This is equivalent to
+    /// [`value.ordered_as(ByteOrder::NATIVE, self)`](ByteOrdered::ordered_as), and reads naturally
+    /// where the byte-order is the subject of the conversion.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    ///
+    /// let order = ByteOrder::Le;
+    /// assert_eq!(order.encode(0x1234_u16), 0x1234_u16.to_le());
+    /// assert_eq!(order.decode(order.encode(0x1234_u16)), 0x1234);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn encode<T: ByteOrdered>(self, value: T) -> T {
+        value.ordered_as(Self::NATIVE, self)
+    }
+
+    /// Decodes the fields of `value`, which are encoded in `self`, to the machine's native
+    /// byte-order in place.
+    ///
+    /// This is equivalent to
+    /// [`value.order_fields_ne(self)`](FieldsByteOrdered::order_fields_ne), and reads naturally
+    /// where the byte-order is the subject of the conversion.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::ByteOrder;
+    ///
+    /// let mut ports = [80_u16.to_le(), 443_u16.to_le()];
+    /// ByteOrder::Le.apply_to(&mut ports);
+    /// assert_eq!(ports, [80, 443]);
+    /// ```
+    #[inline]
+    pub fn apply_to<T: FieldsByteOrdered + ?Sized>(self, value: &mut T) {
+        value.order_fields_ne(self);
+    }
 }
 
 impl core::ops::Not for ByteOrder {
@@ -81,7 +517,180 @@ impl core::ops::Not for ByteOrder {
 
     /// Invokes [`opposite()`](Self::opposite).
     #[inline(always)]
+    // Recent compilers ignore `#[must_use]` upon trait impl methods, and warn about it.
+    #[allow(unused_attributes)]
+    #[must_use]
+    fn not(self) -> Self::Output {
+        self.opposite()
+    }
+}
+
+/// Defaults to [`ByteOrder::NATIVE`].
+impl Default for ByteOrder {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::NATIVE
+    }
+}
+
+impl From<ByteOrder> for u8 {
+    /// Invokes [`to_repr()`](ByteOrder::to_repr).
+    #[inline(always)]
+    fn from(order: ByteOrder) -> Self {
+        order.to_repr()
+    }
+}
+
+impl TryFrom<u8> for ByteOrder {
+    type Error = ByteOrderReprError;
+
+    /// Invokes [`try_from_repr()`](ByteOrder::try_from_repr).
+    #[inline(always)]
+    fn try_from(repr: u8) -> Result<Self, ByteOrderReprError> {
+        Self::try_from_repr(repr)
+    }
+}
+
+/// Error returned when a byte-order fails to be retrieved from its `u8` representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ByteOrderReprError {
+    /// The integer was not the representation of any byte-order.
+    UnknownRepr,
+}
+
+impl ByteOrderReprError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::UnknownRepr => "unknown byte-order representation",
+        }
+    }
+}
+
+impl core::fmt::Display for ByteOrderReprError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for ByteOrderReprError {}
+
+/// Formats the byte-order as `le` or `be`, which [`FromStr`](core::str::FromStr) parses back.
+impl core::fmt::Display for ByteOrder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(match self {
+            Self::Le => "le",
+            Self::Be => "be",
+        })
+    }
+}
+
+/// Parses a byte-order from a name such as those given by configuration files and command line
+/// arguments.
+///
+/// The names `le`, `little`, and `little-endian` are parsed as [`ByteOrder::Le`], the names `be`,
+/// `big`, `big-endian`, and `network` as [`ByteOrder::Be`], and `native` as
+/// [`ByteOrder::NATIVE`]. Names are matched regardless of their ASCII case.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::{ByteOrder, ParseByteOrderError};
+///
+/// assert_eq!("little".parse(), Ok(ByteOrder::Le));
+/// assert_eq!("BE".parse(), Ok(ByteOrder::Be));
+/// assert_eq!("native".parse(), Ok(ByteOrder::NATIVE));
+/// assert_eq!("middle".parse::<ByteOrder>(), Err(ParseByteOrderError::UnknownName));
+/// assert_eq!(ByteOrder::Le.to_string().parse(), Ok(ByteOrder::Le));
+/// ```
+impl core::str::FromStr for ByteOrder {
+    type Err = ParseByteOrderError;
+
+    fn from_str(name: &str) -> Result<Self, ParseByteOrderError> {
+        const NAMES: [(&str, ByteOrder); 8] = [
+            ("le", ByteOrder::Le),
+            ("little", ByteOrder::Le),
+            ("little-endian", ByteOrder::Le),
+            ("be", ByteOrder::Be),
+            ("big", ByteOrder::Be),
+            ("big-endian", ByteOrder::Be),
+            ("network", ByteOrder::Be),
+            ("native", ByteOrder::NATIVE),
+        ];
+        NAMES
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(name))
+            .map(|&(_, order)| order)
+            .ok_or(ParseByteOrderError::UnknownName)
+    }
+}
+
+/// Error returned when a byte-order fails to be parsed from its name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseByteOrderError {
+    /// The name was not the name of any byte-order.
+    UnknownName,
+}
+
+impl ParseByteOrderError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::UnknownName => "unknown byte-order name",
+        }
+    }
+}
+
+impl core::fmt::Display for ParseByteOrderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for ParseByteOrderError {}
+
+/// Enumeration providing the orders in which the 16-bit words of a multi-word value may be
+/// arranged.
+///
+/// Devices that transfer data as a sequence of 16-bit words (such as Modbus devices) commonly
+/// arrange the words of wider values in an order independent of the byte-order of each word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordOrder {
+    /// Least significant word first.
+    Le,
+    /// Most significant word first.
+    Be,
+}
+
+impl WordOrder {
+    /// Retrieves the opposite word-order of `self`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::WordOrder;
+    /// assert_eq!(WordOrder::Le.opposite(), WordOrder::Be);
+    /// assert_eq!(WordOrder::Be.opposite(), WordOrder::Le);
+    /// ```
+    #[inline]
     #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Le => Self::Be,
+            Self::Be => Self::Le,
+        }
+    }
+}
+
+impl core::ops::Not for WordOrder {
+    type Output = Self;
+
+    /// Invokes [`opposite()`](Self::opposite).
+    #[inline(always)]
     fn not(self) -> Self::Output {
         self.opposite()
     }
@@ -218,6 +827,23 @@ pub trait ByteOrdered: Sized {
         }
     }
 
+    /// Provided `self`'s current byte-order as an input argument, conditionally swap the byte-order
+    /// of `self`'s bytes so that it is encoded in [network](ByteOrder::NETWORK) byte-order.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::{ByteOrder, ByteOrdered};
+    ///
+    /// let port = 443_u16;
+    /// assert_eq!(port.ordered_network(ByteOrder::NATIVE).to_ne_bytes(), [0x01, 0xbb]);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    fn ordered_network(self, current_order: ByteOrder) -> Self {
+        self.ordered_be(current_order)
+    }
+
     /// Provided `self`'s current byte-order and a new byte-order for `self`, conditionally swap
     /// `self`'s bytes so that it is encoded in that new byte-order.
     ///
@@ -253,6 +879,38 @@ pub trait ByteOrdered: Sized {
             self.swapped_order()
         }
     }
+
+    /// Provided `self`'s current byte-order as a type parameter, conditionally swap the byte-order
+    /// of `self`'s bytes so that it is encoded in the machine's native byte-order.
+    ///
+    /// As swapping is its own inverse, this equally converts a value encoded in the machine's
+    /// native byte-order to the byte-order marked by `O`. The byte-order is known at compile time,
+    /// so the condition is resolved statically and the call compiles down to either nothing or an
+    /// unconditional swap.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::marker::{BigEndian, LittleEndian};
+    /// use lilbig::ByteOrdered;
+    ///
+    /// const NE_N: u32 = 0x7cf3a4b1;
+    /// const LE_N: u32 = NE_N.to_le();
+    /// const BE_N: u32 = NE_N.to_be();
+    ///
+    /// assert_eq!(NE_N, LE_N.ordered::<LittleEndian>());
+    /// assert_eq!(NE_N, BE_N.ordered::<BigEndian>());
+    /// assert_eq!(BE_N, NE_N.ordered::<BigEndian>());
+    /// ```
+    #[inline]
+    #[must_use]
+    fn ordered<O: marker::OrderMarker>(self) -> Self {
+        if O::ORDER.is_native() {
+            self
+        } else {
+            self.swapped_order()
+        }
+    }
 }
 
 /// Trait for converting the byte-order of a type whose fields are all encoded in one byte-order.
@@ -265,6 +923,12 @@ pub trait ByteOrdered: Sized {
 /// Calling [`swap_field_orders()`](FieldsByteOrdered::swap_field_orders) on an array or slice will
 /// swap the byte-order of all that array/slice's elements.
 ///
+/// Implementations swapping each field of a struct may be generated by
+/// `#[derive(FieldsByteOrdered)]` with the `derive` feature enabled, or by
+/// [`impl_fields_byte_ordered!`] without it. Newtypes, such as `GroupId` and
+/// `AccessPrivilegeFlags` below, may instead delegate to the type they wrap through
+/// [`impl_byte_ordered_newtype!`].
+///
 /// # Examples
 /// Implementing:
 /// ```
@@ -324,6 +988,32 @@ pub trait ByteOrdered: Sized {
 /// }
 /// ```
 pub trait FieldsByteOrdered {
+    /// Whether swapping the byte-order of the type's fields never modifies a value, as is the case
+    /// for single byte types and for arrays of such types.
+    ///
+    /// This defaults to `false`. Implementations of containing types, including those generated by
+    /// `#[derive(FieldsByteOrdered)]`, may consult this to statically skip swapping such fields.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use core::marker::PhantomData;
+    /// use lilbig::FieldsByteOrdered;
+    ///
+    /// assert!(<[u8; 16]>::IS_ORDER_INVARIANT);
+    /// assert!(<PhantomData<u32>>::IS_ORDER_INVARIANT);
+    /// assert!(!<[u16; 16]>::IS_ORDER_INVARIANT);
+    /// ```
+    const IS_ORDER_INVARIANT: bool = false;
+
+    /// Whether the type holds fields pinned to a byte-order, which are converted by
+    /// [`swap_pinned_field_orders()`](FieldsByteOrdered::swap_pinned_field_orders) rather than by
+    /// [`swap_field_orders()`](FieldsByteOrdered::swap_field_orders).
+    ///
+    /// This defaults to `false`. Implementations of containing types, including those generated by
+    /// `#[derive(FieldsByteOrdered)]`, may consult this to statically skip converting such fields.
+    const HAS_PINNED_FIELDS: bool = false;
+
     /// Unconditionally swap the byte-order of `self`'s fields.
     ///
     /// # Examples
@@ -361,6 +1051,98 @@ pub trait FieldsByteOrdered {
     /// ```
     fn swap_field_orders(&mut self);
 
+    /// Zero the bytes of `self`'s padding fields, such that writing `self` to a file or the network
+    /// does not leak the stale contents of the memory they occupy.
+    ///
+    /// This defaults to doing nothing. `#[derive(FieldsByteOrdered)]` zeroes the fields annotated
+    /// with `#[lilbig(padding)]` along with the padding nested within the struct's other fields.
+    /// [`OrderedWriter::write()`](crate::cursor::OrderedWriter::write) zeroes the padding of each
+    /// value that it writes.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #[cfg(feature = "derive")]
+    /// # fn main() {
+    /// use lilbig::FieldsByteOrdered;
+    ///
+    /// #[derive(FieldsByteOrdered)]
+    /// #[repr(C)]
+    /// struct Record {
+    ///     id: u16,
+    ///     #[lilbig(padding)]
+    ///     reserved: [u8; 2],
+    ///     length: u32,
+    /// }
+    ///
+    /// let mut records = [Record { id: 1, reserved: [0xde, 0xad], length: 2 }];
+    /// records.zero_padding();
+    /// assert_eq!(records[0].reserved, [0, 0]);
+    /// assert_eq!(records[0].id, 1);
+    /// # }
+    /// # #[cfg(not(feature = "derive"))]
+    /// # fn main() {}
+    /// ```
+    #[inline]
+    fn zero_padding(&mut self) {}
+
+    /// Convert `self`'s fields that are pinned to a byte-order between their pinned byte-order and
+    /// the machine's native byte-order.
+    ///
+    /// Formats occasionally mix byte-orders within one record, such as a big-endian checksum within
+    /// an otherwise little-endian header. Fields pinned to a byte-order are left unmodified by
+    /// [`swap_field_orders()`](FieldsByteOrdered::swap_field_orders), and are instead converted by
+    /// this whenever `self` is converted to or from the machine's native byte-order by the other
+    /// methods of this trait. As swapping is its own inverse, this converts the pinned fields in
+    /// either direction.
+    ///
+    /// A byte-order matching the machine's native byte-order may describe either values in the
+    /// machine's native byte-order or encoded bytes, which differ in their pinned fields. The other
+    /// methods of this trait take such a byte-order to describe values in the machine's native
+    /// byte-order, and so convert the pinned fields only if exactly one of the current and new
+    /// byte-orders is the machine's native byte-order. Converting between equal byte-orders is
+    /// thereby always a no-op. The pinned fields of encoded bytes whose byte-order matches the
+    /// machine's native byte-order are converted by calling this directly.
+    ///
+    /// This defaults to doing nothing. `#[derive(FieldsByteOrdered)]` converts the fields annotated
+    /// with `#[lilbig(order = "...")]` along with the pinned fields nested within the struct's other
+    /// fields.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #[cfg(feature = "derive")]
+    /// # fn main() {
+    /// use lilbig::{ByteOrder, FieldsByteOrdered};
+    ///
+    /// #[derive(FieldsByteOrdered)]
+    /// struct Header {
+    ///     length: u32,
+    ///     #[lilbig(order = "be")]
+    ///     checksum: u16,
+    /// }
+    ///
+    /// let mut header = Header {
+    ///     length: 0x0102,
+    ///     checksum: 0x0304,
+    /// };
+    /// header.order_fields_as(ByteOrder::NATIVE, ByteOrder::NATIVE_OPPOSITE);
+    /// assert_eq!(header.length, 0x0102_u32.swap_bytes());
+    /// assert_eq!(header.checksum, 0x0304_u16.to_be());
+    ///
+    /// header.order_fields_ne(ByteOrder::NATIVE_OPPOSITE);
+    /// assert_eq!(header.length, 0x0102);
+    /// assert_eq!(header.checksum, 0x0304);
+    ///
+    /// header.order_fields_ne(ByteOrder::NATIVE);
+    /// assert_eq!(header.checksum, 0x0304);
+    /// # }
+    /// # #[cfg(not(feature = "derive"))]
+    /// # fn main() {}
+    /// ```
+    #[inline]
+    fn swap_pinned_field_orders(&mut self) {}
+
     /// Provided `self`'s current byte-order as an input argument, conditionally swap the byte-order
     /// of `self`'s fields so that they are in the machine's native byte-order.
     ///
@@ -392,9 +1174,7 @@ pub trait FieldsByteOrdered {
     /// ```
     #[inline]
     fn order_fields_ne(&mut self, current_order: ByteOrder) {
-        if current_order != ByteOrder::NATIVE {
-            self.swap_field_orders();
-        }
+        self.order_fields_as(current_order, ByteOrder::NATIVE);
     }
 
     /// Provided `self`'s current byte-order as an input argument, conditionally swap the byte-order
@@ -428,9 +1208,7 @@ pub trait FieldsByteOrdered {
     /// ```
     #[inline]
     fn order_fields_le(&mut self, current_order: ByteOrder) {
-        if current_order != ByteOrder::Le {
-            self.swap_field_orders();
-        }
+        self.order_fields_as(current_order, ByteOrder::Le);
     }
 
     /// Provided `self`'s current byte-order as an input argument, conditionally swap the byte-order
@@ -463,9 +1241,24 @@ pub trait FieldsByteOrdered {
     /// ```
     #[inline]
     fn order_fields_be(&mut self, current_order: ByteOrder) {
-        if current_order != ByteOrder::Be {
-            self.swap_field_orders();
-        }
+        self.order_fields_as(current_order, ByteOrder::Be);
+    }
+
+    /// Provided `self`'s current byte-order as an input argument, conditionally swap the byte-order
+    /// of `self`'s fields so that they are in [network](ByteOrder::NETWORK) byte-order.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::{ByteOrder, FieldsByteOrdered};
+    ///
+    /// let mut ports = [80_u16, 443];
+    /// ports.order_fields_network(ByteOrder::NATIVE);
+    /// assert_eq!(ports, [80_u16.to_be(), 443_u16.to_be()]);
+    /// ```
+    #[inline(always)]
+    fn order_fields_network(&mut self, current_order: ByteOrder) {
+        self.order_fields_be(current_order);
     }
 
     /// Provided `self`'s current byte-order and a new byte-order for `self`, conditionally swap
@@ -507,9 +1300,302 @@ pub trait FieldsByteOrdered {
     /// assert_eq!(le_numbers, clone_ordered_as(&be_numbers, ByteOrder::Be, ByteOrder::Le));
     /// assert_eq!(be_numbers, clone_ordered_as(&be_numbers, ByteOrder::Be, ByteOrder::Be));
     /// ```
+    #[inline]
     fn order_fields_as(&mut self, current_order: ByteOrder, new_order: ByteOrder) {
         if current_order != new_order {
+            record_conversion(self, current_order, new_order);
             self.swap_field_orders();
         }
+        if Self::HAS_PINNED_FIELDS && current_order.is_native() != new_order.is_native() {
+            self.swap_pinned_field_orders();
+        }
+    }
+
+    /// Provided `self`'s current byte-order as a type parameter, conditionally swap the byte-order
+    /// of `self`'s fields so that they are in the machine's native byte-order.
+    ///
+    /// As swapping is its own inverse, this equally converts fields in the machine's native
+    /// byte-order to the byte-order marked by `O`. The byte-order is known at compile time, so the
+    /// condition is resolved statically and the call compiles down to either nothing or an
+    /// unconditional swap.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::marker::{BigEndian, LittleEndian};
+    /// use lilbig::FieldsByteOrdered;
+    ///
+    /// const NE_NUMBERS: [u32; 4] = [0x7cf3a4b1, 0x3dd4f42, 0xff317cde, 0x87fce321];
+    /// let mut le_numbers = NE_NUMBERS.map(u32::to_le);
+    /// let mut be_numbers = NE_NUMBERS.map(u32::to_be);
+    ///
+    /// le_numbers.order_fields::<LittleEndian>();
+    /// be_numbers.order_fields::<BigEndian>();
+    /// assert_eq!(NE_NUMBERS, le_numbers);
+    /// assert_eq!(NE_NUMBERS, be_numbers);
+    /// ```
+    #[inline]
+    fn order_fields<O: marker::OrderMarker>(&mut self) {
+        self.order_fields_as(O::ORDER, ByteOrder::NATIVE);
+    }
+}
+
+/// Trait for converting the byte-order of types whose fields depend upon a context, such as the
+/// version of a format or a count read from a sibling header.
+///
+/// This is the counterpart of [`FieldsByteOrdered`] for types that cannot be swapped without
+/// knowing which of their fields exist or how wide those fields are. With the `derive` feature
+/// enabled, this may be derived for structs whose fields pass the context along.
+///
+/// # Examples
+/// Implementing:
+/// ```
+/// use lilbig::{ByteOrder, FieldsByteOrdered, FieldsByteOrderedWith};
+///
+/// /// Sample whose width depends on the format of the file containing it.
+/// struct Sample([u8; 4]);
+///
+/// impl FieldsByteOrderedWith<usize> for Sample {
+///     fn swap_field_orders_with(&mut self, width: usize) {
+///         if let Some(bytes) = self.0.get_mut(..width) {
+///             bytes.reverse();
+///         }
+///     }
+/// }
+///
+/// let mut samples = [Sample([1, 2, 0, 0]), Sample([3, 4, 0, 0])];
+/// samples.order_fields_ne_with(ByteOrder::NATIVE_OPPOSITE, 2);
+/// assert_eq!(samples[1].0, [4, 3, 0, 0]);
+/// ```
+pub trait FieldsByteOrderedWith<Ctx> {
+    /// Unconditionally swap the byte-order of `self`'s fields as determined by `ctx`.
+    fn swap_field_orders_with(&mut self, ctx: Ctx);
+
+    /// Provided `self`'s current byte-order as an input argument, conditionally swap the byte-order
+    /// of `self`'s fields as determined by `ctx` so that they are in the machine's native
+    /// byte-order.
+    #[inline]
+    fn order_fields_ne_with(&mut self, current_order: ByteOrder, ctx: Ctx) {
+        if current_order != ByteOrder::NATIVE {
+            record_conversion(self, current_order, ByteOrder::NATIVE);
+            self.swap_field_orders_with(ctx);
+        }
+    }
+
+    /// Provided `self`'s current byte-order as an input argument, conditionally swap the byte-order
+    /// of `self`'s fields as determined by `ctx` so that they are in little-endian byte-order.
+    #[inline]
+    fn order_fields_le_with(&mut self, current_order: ByteOrder, ctx: Ctx) {
+        if current_order != ByteOrder::Le {
+            record_conversion(self, current_order, ByteOrder::Le);
+            self.swap_field_orders_with(ctx);
+        }
+    }
+
+    /// Provided `self`'s current byte-order as an input argument, conditionally swap the byte-order
+    /// of `self`'s fields as determined by `ctx` so that they are in big-endian byte-order.
+    #[inline]
+    fn order_fields_be_with(&mut self, current_order: ByteOrder, ctx: Ctx) {
+        if current_order != ByteOrder::Be {
+            record_conversion(self, current_order, ByteOrder::Be);
+            self.swap_field_orders_with(ctx);
+        }
+    }
+
+    /// Provided `self`'s current byte-order and a desired byte-order as input arguments,
+    /// conditionally swap the byte-order of `self`'s fields as determined by `ctx` so that they are
+    /// in the desired byte-order.
+    #[inline]
+    fn order_fields_as_with(&mut self, current_order: ByteOrder, new_order: ByteOrder, ctx: Ctx) {
+        if current_order != new_order {
+            record_conversion(self, current_order, new_order);
+            self.swap_field_orders_with(ctx);
+        }
+    }
+}
+
+/// Record the conversion of `value` from `current_order` to `new_order` with the enabled
+/// instrumentation features, if any.
+#[inline(always)]
+#[allow(unused_variables)]
+fn record_conversion<T: ?Sized>(value: &T, current_order: ByteOrder, new_order: ByteOrder) {
+    #[cfg(feature = "trace")]
+    trace::conversion(value, current_order, new_order);
+    #[cfg(feature = "counters")]
+    counters::conversion(value);
+}
+
+/// Marker trait for types that may be safely reinterpreted to and from their raw bytes.
+///
+/// This is implemented for the primitive integer and floating point types, and for arrays of any
+/// type implementing this trait.
+///
+/// # Safety
+/// Implementors of this trait must guarantee all of the following:
+/// * The type contains no padding bytes.
+/// * Every possible bit pattern is a valid value of the type.
+/// * The type contains no pointers, references, or interior mutability.
+///
+/// # Examples
+/// Implementing:
+/// ```
+/// // Defining a register map for a sensor that can be safely transmitted as raw bytes.
+/// use lilbig::WireSafe;
+///
+/// /// Calibration registers of a sensor.
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Calibration {
+///     /// Offset applied to raw readings.
+///     pub offset: i16,
+///     /// Scale applied to raw readings.
+///     pub scale: u16,
+///     /// Polynomial correction coefficients.
+///     pub coefficients: [i32; 3],
+/// }
+///
+/// // SAFETY: `Calibration` is `repr(C)`, contains no padding, and all of its fields are
+/// // `WireSafe`.
+/// unsafe impl WireSafe for Calibration {}
+///
+/// let calibration = Calibration {
+///     offset: -3,
+///     scale: 100,
+///     coefficients: [1, 2, 3],
+/// };
+/// assert_eq!(calibration.as_bytes().len(), 16);
+/// ```
+pub unsafe trait WireSafe: Copy + 'static {
+    /// Create a value whose bytes are all zero.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::WireSafe;
+    /// assert_eq!(<[u32; 4]>::zeroed(), [0u32; 4]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn zeroed() -> Self {
+        // SAFETY: Implementors guarantee that every bit pattern is a valid value.
+        unsafe { core::mem::zeroed() }
+    }
+
+    /// View the raw bytes of `self`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::WireSafe;
+    /// assert_eq!(0x1234u16.to_be().as_bytes(), [0x12, 0x34]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn as_bytes(&self) -> &[u8] {
+        // SAFETY: Implementors guarantee that the type contains no padding, so all
+        // `size_of::<Self>()` bytes of `self` are initialized.
+        unsafe {
+            core::slice::from_raw_parts(
+                (self as *const Self).cast::<u8>(),
+                core::mem::size_of::<Self>(),
+            )
+        }
+    }
+
+    /// Mutably view the raw bytes of `self`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::WireSafe;
+    /// let mut n = 0u32;
+    /// n.as_bytes_mut().copy_from_slice(&[0x12, 0x34, 0x56, 0x78]);
+    /// assert_eq!(n, u32::from_ne_bytes([0x12, 0x34, 0x56, 0x78]));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        // SAFETY: Implementors guarantee that the type contains no padding and that every bit
+        // pattern is valid, so any bytes may be written through the slice.
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                (self as *mut Self).cast::<u8>(),
+                core::mem::size_of::<Self>(),
+            )
+        }
     }
+
+    /// Read a value from the raw bytes of `bytes`.
+    ///
+    /// Returns [`None`] if the length of `bytes` is not equal to the size of `Self`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::WireSafe;
+    /// assert_eq!(u16::from_bytes(&[0x12, 0x34]), Some(0x1234u16.to_be()));
+    /// assert_eq!(u16::from_bytes(&[0x12, 0x34, 0x56]), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != core::mem::size_of::<Self>() {
+            return None;
+        }
+        // SAFETY: The length of `bytes` was checked to be equal to the size of `Self` and
+        // implementors guarantee that every bit pattern is a valid value.
+        Some(unsafe { bytes.as_ptr().cast::<Self>().read_unaligned() })
+    }
+}
+
+/// Error returned when the widths provided to an operation are incompatible with one another or
+/// with the length of the buffer being operated upon.
+///
+/// This is returned by the `try_` alternatives of the crate's panicking operations.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::{bulk, WidthError};
+///
+/// let mut bytes = [0u8; 6];
+/// assert_eq!(bulk::try_swap_elements(&mut bytes, 0), Err(WidthError::Zero));
+/// assert_eq!(bulk::try_swap_elements(&mut bytes, 4), Err(WidthError::IndivisibleLength));
+/// assert_eq!(bulk::try_swap_elements(&mut bytes, 2), Ok(()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WidthError {
+    /// A width was zero.
+    Zero,
+    /// A width was required to be a power of two but was not.
+    NotPowerOfTwo,
+    /// The length of a buffer was not a multiple of a width.
+    IndivisibleLength,
+    /// A width was not a multiple of the narrower width that it was required to contain.
+    IndivisibleWidth,
+    /// A width exceeded the wider width that it was required to be contained by.
+    ExceedsWidth,
 }
+
+impl WidthError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::Zero => "width must be non-zero",
+            Self::NotPowerOfTwo => "width must be a power of two",
+            Self::IndivisibleLength => "buffer length must be a multiple of the width",
+            Self::IndivisibleWidth => "width must be a multiple of the narrower width",
+            Self::ExceedsWidth => "width must not exceed the wider width",
+        }
+    }
+}
+
+impl core::fmt::Display for WidthError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for WidthError {}
@@ -0,0 +1,273 @@
+//! Mach-O and universal ("fat") binary header structs.
+//!
+//! Mach-O files are encoded in the byte-order of their target machine, which readers detect
+//! through the magic number at the start of the file. The headers of universal binaries, which
+//! contain a Mach-O file for each of several architectures, are always encoded in big-endian.
+//!
+//! # Examples
+//! Locating and reading the Mach-O header of an architecture within a universal binary:
+//! ```
+//! use lilbig::macho::{FatArch, FatHeader, MachHeader64, MH_MAGIC_64};
+//! use lilbig::{ByteOrder, FieldsByteOrdered, WireSafe};
+//!
+//! let mut file = [0u8; 64];
+//! // Universal binary header and a single architecture at offset 32.
+//! file[..8].copy_from_slice(&[0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x01]);
+//! file[8..28].copy_from_slice(&[
+//!     0x01, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+//!     0x20, 0x00, 0x00, 0x00, 0x05,
+//! ]);
+//! // Little-endian Mach-O header of the architecture.
+//! let mut header = MachHeader64 {
+//!     magic: MH_MAGIC_64,
+//!     cputype: 0x0100000c,
+//!     ncmds: 17,
+//!     ..Default::default()
+//! };
+//! header.order_fields_le(ByteOrder::NATIVE);
+//! file[32..].copy_from_slice(header.as_bytes());
+//!
+//! let (fat, rest) = FatHeader::read(&file).unwrap();
+//! assert_eq!(fat.nfat_arch, 1);
+//! let arch = FatArch::read(rest).unwrap();
+//! assert_eq!(arch.cputype, 0x0100000c);
+//!
+//! let offset = arch.offset as usize;
+//! let (header, order) = MachHeader64::read(&file[offset..]).unwrap();
+//! assert_eq!(order, ByteOrder::Le);
+//! assert_eq!(header.ncmds, 17);
+//! ```
+
+use crate::headers::{impl_header, read_ordered};
+use crate::ByteOrder;
+
+/// Magic number of a 32-bit Mach-O file.
+pub const MH_MAGIC: u32 = 0xfeedface;
+
+/// Magic number of a 64-bit Mach-O file.
+pub const MH_MAGIC_64: u32 = 0xfeedfacf;
+
+/// Magic number of a universal binary with 32-bit architecture entries.
+pub const FAT_MAGIC: u32 = 0xcafebabe;
+
+/// Magic number of a universal binary with 64-bit architecture entries.
+pub const FAT_MAGIC_64: u32 = 0xcafebabf;
+
+/// Detect the byte-order of a file whose first four bytes are `magic` encoded in its byte-order.
+fn detect_magic(bytes: &[u8], magic: u32) -> Option<ByteOrder> {
+    ByteOrder::detect_by_magic(magic, u32::from_ne_bytes(*bytes.first_chunk()?))
+}
+
+/// Detect the byte-order of a 32-bit or 64-bit Mach-O file from its magic number.
+///
+/// Returns [`None`] if `bytes` does not begin with a Mach-O magic number.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::macho::detect_order;
+/// use lilbig::ByteOrder;
+///
+/// assert_eq!(detect_order(&[0xcf, 0xfa, 0xed, 0xfe]), Some(ByteOrder::Le));
+/// assert_eq!(detect_order(&[0xfe, 0xed, 0xfa, 0xce]), Some(ByteOrder::Be));
+/// assert_eq!(detect_order(&[0xca, 0xfe, 0xba, 0xbe]), None);
+/// ```
+#[must_use]
+pub fn detect_order(bytes: &[u8]) -> Option<ByteOrder> {
+    detect_magic(bytes, MH_MAGIC).or_else(|| detect_magic(bytes, MH_MAGIC_64))
+}
+
+/// Header of a 32-bit Mach-O file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct MachHeader {
+    /// Magic number identifying the file's byte-order and width.
+    pub magic: u32,
+    /// Target CPU type.
+    pub cputype: i32,
+    /// Target CPU subtype.
+    pub cpusubtype: i32,
+    /// Type of the file.
+    pub filetype: u32,
+    /// Number of load commands.
+    pub ncmds: u32,
+    /// Size of all load commands in bytes.
+    pub sizeofcmds: u32,
+    /// File flags.
+    pub flags: u32,
+}
+
+impl_header!(MachHeader {
+    magic,
+    cputype,
+    cpusubtype,
+    filetype,
+    ncmds,
+    sizeofcmds,
+    flags,
+});
+
+impl MachHeader {
+    /// Read a header from the start of `bytes`, detecting the file's byte-order from its magic
+    /// number.
+    ///
+    /// Returns the header in the machine's native byte-order along with the detected byte-order of
+    /// the file. Returns [`None`] if `bytes` is too short or is not a 32-bit Mach-O file.
+    #[must_use]
+    pub fn read(bytes: &[u8]) -> Option<(Self, ByteOrder)> {
+        let order = detect_magic(bytes, MH_MAGIC)?;
+        Some((read_ordered(bytes, order)?.0, order))
+    }
+}
+
+/// Header of a 64-bit Mach-O file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct MachHeader64 {
+    /// Magic number identifying the file's byte-order and width.
+    pub magic: u32,
+    /// Target CPU type.
+    pub cputype: i32,
+    /// Target CPU subtype.
+    pub cpusubtype: i32,
+    /// Type of the file.
+    pub filetype: u32,
+    /// Number of load commands.
+    pub ncmds: u32,
+    /// Size of all load commands in bytes.
+    pub sizeofcmds: u32,
+    /// File flags.
+    pub flags: u32,
+    /// Reserved.
+    pub reserved: u32,
+}
+
+impl_header!(MachHeader64 {
+    magic,
+    cputype,
+    cpusubtype,
+    filetype,
+    ncmds,
+    sizeofcmds,
+    flags,
+    reserved,
+});
+
+impl MachHeader64 {
+    /// Read a header from the start of `bytes`, detecting the file's byte-order from its magic
+    /// number.
+    ///
+    /// Returns the header in the machine's native byte-order along with the detected byte-order of
+    /// the file. Returns [`None`] if `bytes` is too short or is not a 64-bit Mach-O file.
+    #[must_use]
+    pub fn read(bytes: &[u8]) -> Option<(Self, ByteOrder)> {
+        let order = detect_magic(bytes, MH_MAGIC_64)?;
+        Some((read_ordered(bytes, order)?.0, order))
+    }
+}
+
+/// Header of a universal binary, which is always encoded in big-endian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct FatHeader {
+    /// Magic number identifying the width of the architecture entries.
+    pub magic: u32,
+    /// Number of architecture entries following the header.
+    pub nfat_arch: u32,
+}
+
+impl_header!(FatHeader { magic, nfat_arch });
+
+impl FatHeader {
+    /// Read a header from the start of `bytes`.
+    ///
+    /// Returns the header in the machine's native byte-order along with the bytes that follow it.
+    /// Returns [`None`] if `bytes` is too short or is not a universal binary.
+    ///
+    /// Java class files share their magic number with universal binaries. Callers that may
+    /// encounter class files should sanity check [`nfat_arch`](FatHeader::nfat_arch), which is
+    /// small for universal binaries.
+    #[must_use]
+    pub fn read(bytes: &[u8]) -> Option<(Self, &[u8])> {
+        let (header, rest) = read_ordered::<Self>(bytes, ByteOrder::Be)?;
+        matches!(header.magic, FAT_MAGIC | FAT_MAGIC_64).then_some((header, rest))
+    }
+
+    /// Check whether the header's architecture entries are [`FatArch64`] rather than [`FatArch`].
+    #[inline]
+    #[must_use]
+    pub const fn is_64(&self) -> bool {
+        self.magic == FAT_MAGIC_64
+    }
+}
+
+/// Architecture entry of a universal binary with 32-bit entries, which is always encoded in
+/// big-endian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct FatArch {
+    /// CPU type of the architecture.
+    pub cputype: i32,
+    /// CPU subtype of the architecture.
+    pub cpusubtype: i32,
+    /// File offset of the architecture's Mach-O file.
+    pub offset: u32,
+    /// Size of the architecture's Mach-O file in bytes.
+    pub size: u32,
+    /// Alignment of the architecture's Mach-O file as a power of two.
+    pub align: u32,
+}
+
+impl_header!(FatArch {
+    cputype,
+    cpusubtype,
+    offset,
+    size,
+    align,
+});
+
+/// Architecture entry of a universal binary with 64-bit entries, which is always encoded in
+/// big-endian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct FatArch64 {
+    /// CPU type of the architecture.
+    pub cputype: i32,
+    /// CPU subtype of the architecture.
+    pub cpusubtype: i32,
+    /// File offset of the architecture's Mach-O file.
+    pub offset: u64,
+    /// Size of the architecture's Mach-O file in bytes.
+    pub size: u64,
+    /// Alignment of the architecture's Mach-O file as a power of two.
+    pub align: u32,
+    /// Reserved.
+    pub reserved: u32,
+}
+
+impl_header!(FatArch64 {
+    cputype,
+    cpusubtype,
+    offset,
+    size,
+    align,
+    reserved,
+});
+
+/// Implement `read()` for architecture entry structs.
+macro_rules! impl_arch_read {
+    ($($ty: ty),+) => {$(
+        impl $ty {
+            /// Read an entry from the start of `bytes`.
+            ///
+            /// Returns the entry in the machine's native byte-order, or [`None`] if `bytes` is too
+            /// short.
+            #[must_use]
+            pub fn read(bytes: &[u8]) -> Option<Self> {
+                Some(read_ordered(bytes, ByteOrder::Be)?.0)
+            }
+        }
+    )+};
+}
+
+impl_arch_read!(FatArch, FatArch64);
@@ -0,0 +1,395 @@
+//! Declarative macros implementing the crate's traits without depending upon the `derive` feature.
+
+/// Implement [`FieldsByteOrdered`](crate::FieldsByteOrdered) for structs by swapping the byte-order
+/// of each of the listed fields.
+///
+/// Each struct is given as `Type { field, ... }`, listing the fields that are swapped, and multiple
+/// structs are separated by semicolons. The fields of tuple structs are listed by their index, such
+/// as `Type { 0 }`. Each listed field must implement `FieldsByteOrdered`, and fields that are not
+/// listed are left unmodified. The padding nested within the listed fields is zeroed by
+/// [`FieldsByteOrdered::zero_padding()`](crate::FieldsByteOrdered::zero_padding). The struct is
+/// order invariant if each of the listed fields is, and holds pinned fields if any of them does.
+///
+/// The macro expands to the same field-by-field implementation that would otherwise be written by
+/// hand, which suits users avoiding the proc-macro dependency of the `derive` feature. Generic
+/// structs are not supported.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::{ByteOrder, FieldsByteOrdered};
+///
+/// #[repr(transparent)]
+/// struct GroupId(u32);
+///
+/// #[repr(C)]
+/// struct FileInfo {
+///     accessed: u64,
+///     modified: u64,
+///     offset: u64,
+///     length: u32,
+///     groups: [GroupId; 2],
+/// }
+///
+/// lilbig::impl_fields_byte_ordered! {
+///     GroupId { 0 };
+///     FileInfo { accessed, modified, offset, length, groups };
+/// }
+///
+/// let mut info = FileInfo {
+///     accessed: 1,
+///     modified: 2,
+///     offset: 3,
+///     length: 0x01020304,
+///     groups: [GroupId(5), GroupId(6)],
+/// };
+/// info.order_fields_be(ByteOrder::Le);
+/// assert_eq!(info.offset, 3_u64.to_be());
+/// assert_eq!(info.length, 0x01020304_u32.to_be());
+/// assert_eq!(info.groups[1].0, 6_u32.to_be());
+/// assert!(!FileInfo::IS_ORDER_INVARIANT);
+///
+/// struct Name {
+///     bytes: [u8; 16],
+///     terminated: bool,
+/// }
+///
+/// lilbig::impl_fields_byte_ordered! { Name { bytes, terminated } }
+/// assert!(Name::IS_ORDER_INVARIANT);
+/// ```
+#[macro_export]
+macro_rules! impl_fields_byte_ordered {
+    ($($ty: ty { $($field: tt),* $(,)? });+ $(;)?) => {
+        $(
+            impl $crate::FieldsByteOrdered for $ty {
+                const IS_ORDER_INVARIANT: bool = {
+                    // The types of the fields are inferred from projections of the struct, as they
+                    // are not given to the macro.
+                    const fn is_order_invariant<T: $crate::FieldsByteOrdered>(
+                        _: fn(&$ty) -> &T,
+                    ) -> bool {
+                        T::IS_ORDER_INVARIANT
+                    }
+                    true $(&& is_order_invariant(|value: &$ty| &value.$field))*
+                };
+                const HAS_PINNED_FIELDS: bool = {
+                    const fn has_pinned_fields<T: $crate::FieldsByteOrdered>(
+                        _: fn(&$ty) -> &T,
+                    ) -> bool {
+                        T::HAS_PINNED_FIELDS
+                    }
+                    false $(|| has_pinned_fields(|value: &$ty| &value.$field))*
+                };
+
+                #[inline]
+                fn swap_field_orders(&mut self) {
+                    $($crate::FieldsByteOrdered::swap_field_orders(&mut self.$field);)*
+                }
+
+                #[inline]
+                fn zero_padding(&mut self) {
+                    $($crate::FieldsByteOrdered::zero_padding(&mut self.$field);)*
+                }
+
+                #[inline]
+                fn swap_pinned_field_orders(&mut self) {
+                    $($crate::FieldsByteOrdered::swap_pinned_field_orders(&mut self.$field);)*
+                }
+            }
+        )+
+    };
+}
+
+/// Implement [`ByteOrdered`](crate::ByteOrdered) and [`FieldsByteOrdered`](crate::FieldsByteOrdered)
+/// for newtypes by delegating to the type they wrap.
+///
+/// Each newtype is given as `Type(Inner)`, and multiple newtypes are separated by commas. The
+/// newtype must be a tuple struct of the single field of type `Inner`, such as a
+/// `#[repr(transparent)]` identifier or set of flags, and `Inner` must implement both traits. The
+/// newtype is order invariant if `Inner` is, and holds pinned fields if `Inner` does. Generic
+/// newtypes are not supported.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::{ByteOrder, ByteOrdered, FieldsByteOrdered};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// #[repr(transparent)]
+/// struct GroupId(u32);
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// #[repr(transparent)]
+/// struct AccessPrivilegeFlags(u32);
+///
+/// #[repr(transparent)]
+/// struct Level(u8);
+///
+/// lilbig::impl_byte_ordered_newtype!(GroupId(u32), AccessPrivilegeFlags(u32), Level(u8));
+///
+/// assert_eq!(GroupId(1).ordered_be(ByteOrder::Le), GroupId(1_u32.to_be()));
+/// let mut flags = [AccessPrivilegeFlags(0x0102); 2];
+/// flags.swap_field_orders();
+/// assert_eq!(flags, [AccessPrivilegeFlags(0x0102_u32.swap_bytes()); 2]);
+/// assert!(Level::IS_ORDER_INVARIANT);
+/// ```
+#[macro_export]
+macro_rules! impl_byte_ordered_newtype {
+    ($($ty: ident($inner: ty)),+ $(,)?) => {
+        $(
+            impl $crate::ByteOrdered for $ty {
+                #[inline]
+                fn swapped_order(self) -> Self {
+                    Self(<$inner as $crate::ByteOrdered>::swapped_order(self.0))
+                }
+            }
+
+            impl $crate::FieldsByteOrdered for $ty {
+                const IS_ORDER_INVARIANT: bool =
+                    <$inner as $crate::FieldsByteOrdered>::IS_ORDER_INVARIANT;
+                const HAS_PINNED_FIELDS: bool =
+                    <$inner as $crate::FieldsByteOrdered>::HAS_PINNED_FIELDS;
+
+                #[inline]
+                fn swap_field_orders(&mut self) {
+                    <$inner as $crate::FieldsByteOrdered>::swap_field_orders(&mut self.0);
+                }
+
+                #[inline]
+                fn zero_padding(&mut self) {
+                    <$inner as $crate::FieldsByteOrdered>::zero_padding(&mut self.0);
+                }
+
+                #[inline]
+                fn swap_pinned_field_orders(&mut self) {
+                    <$inner as $crate::FieldsByteOrdered>::swap_pinned_field_orders(&mut self.0);
+                }
+            }
+        )+
+    };
+}
+
+/// Create a function swapping the byte-order of the fields of a record of raw bytes described by a
+/// list of types.
+///
+/// The record is described as a comma-separated list of items laid out one after another without
+/// any implicit alignment padding. Each item is either a type, which must implement both
+/// [`FieldsByteOrdered`](crate::FieldsByteOrdered) and [`WireSafe`](crate::WireSafe), such as
+/// `u32` or `[u64; 4]`, or `pad N`, which skips `N` bytes of padding.
+///
+/// The macro expands to a closure taking the `&mut [u8]` holding the record, which may be coerced
+/// to a `fn(&mut [u8]) -> bool`. The closure swaps each item in place, leaving any bytes following
+/// the record unmodified, and returns `false` without modifying the bytes if they are too short to
+/// hold the record. This suits data for which defining a Rust struct is impractical, whereas
+/// layouts only known at runtime are described by [`layout::TypeLayout`](crate::layout::TypeLayout).
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// const SWAP_HEADER: fn(&mut [u8]) -> bool = lilbig::layout! { u32, u16, pad 2, [u16; 2] };
+///
+/// let mut header = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+/// assert!(SWAP_HEADER(&mut header));
+/// assert_eq!(header, [4, 3, 2, 1, 6, 5, 7, 8, 10, 9, 12, 11, 13]);
+/// assert!(!SWAP_HEADER(&mut header[..11]));
+/// ```
+#[macro_export]
+macro_rules! layout {
+    (@parse [$($items: tt)*]) => {
+        |bytes: &mut [::core::primitive::u8]| -> ::core::primitive::bool {
+            let size: ::core::primitive::usize = 0 $(+ $crate::layout!(@size $items))*;
+            let ::core::option::Option::Some(bytes) = bytes.get_mut(..size) else {
+                return false;
+            };
+            $crate::layout!(@swap bytes [0] $($items)*);
+            true
+        }
+    };
+    (@parse [$($items: tt)*] pad $len: expr $(, $($rest: tt)*)?) => {
+        $crate::layout!(@parse [$($items)* (pad $len)] $($($rest)*)?)
+    };
+    (@parse [$($items: tt)*] $ty: ty $(, $($rest: tt)*)?) => {
+        $crate::layout!(@parse [$($items)* (field $ty)] $($($rest)*)?)
+    };
+    (@size (pad $len: expr)) => {
+        $len
+    };
+    (@size (field $ty: ty)) => {
+        ::core::mem::size_of::<$ty>()
+    };
+    (@swap $bytes: ident [$($offset: tt)*]) => {};
+    (@swap $bytes: ident [$($offset: tt)*] (pad $len: expr) $($rest: tt)*) => {
+        $crate::layout!(@swap $bytes [$($offset)* + $len] $($rest)*);
+    };
+    (@swap $bytes: ident [$($offset: tt)*] (field $ty: ty) $($rest: tt)*) => {
+        if let ::core::option::Option::Some(field) = $bytes.get_mut($($offset)*..) {
+            let _ = $crate::overlay::swap_overlay::<$ty>(field);
+        }
+        $crate::layout!(@swap $bytes [$($offset)* + ::core::mem::size_of::<$ty>()] $($rest)*);
+    };
+    ($($items: tt)*) => {
+        $crate::layout!(@parse [] $($items)*)
+    };
+}
+
+/// Evaluate an expression generic over a byte-order marked at compile time with the marker of a
+/// byte-order known only at runtime.
+///
+/// Invoked as `with_order!(order, |O| expr)`, the macro branches on `order` once and evaluates
+/// `expr` within each branch with `O` aliasing the [`OrderMarker`](crate::marker::OrderMarker) of
+/// the branch's byte-order, such as [`LittleEndian`](crate::marker::LittleEndian). Hot loops
+/// converting many values of a runtime byte-order thereby hoist the branch on the byte-order out of
+/// the loop, as `expr` is compiled once for each byte-order. Operations that are not written inline
+/// may instead implement [`OrderVisitor`](crate::marker::OrderVisitor).
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::{ByteOrder, FieldsByteOrdered};
+///
+/// fn decode_all(values: &mut [u32], order: ByteOrder) -> u32 {
+///     lilbig::with_order!(order, |O| {
+///         let mut sum = 0;
+///         for value in values.iter_mut() {
+///             value.order_fields::<O>();
+///             sum += *value;
+///         }
+///         sum
+///     })
+/// }
+///
+/// let mut values = [1_u32.to_le(), 2_u32.to_le(), 3_u32.to_le()];
+/// assert_eq!(decode_all(&mut values, ByteOrder::Le), 6);
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! with_order {
+    ($order: expr, |$marker: ident| $body: expr) => {
+        match $order {
+            $crate::ByteOrder::Le => {
+                type $marker = $crate::marker::LittleEndian;
+                $body
+            }
+            $crate::ByteOrder::Be => {
+                type $marker = $crate::marker::BigEndian;
+                $body
+            }
+        }
+    };
+}
+
+/// Define a struct along with a wire twin, a sibling struct whose fields are stored in a fixed
+/// byte-order, and conversions between the two.
+///
+/// The struct is defined as it would be otherwise, and is followed by the declaration of its twin
+/// as `struct Name: Le;` or `struct Name: Be;`, each preceded by their own attributes and
+/// visibility. Each field of the twin wraps the corresponding field of the struct in
+/// [`ordered::Le`](crate::ordered::Le) or [`ordered::Be`](crate::ordered::Be), so the on-disk
+/// representation is a distinct type that can't be mistaken for native data. The twin shares the
+/// visibility and documentation of each of the fields, and implements `Debug`, `Clone`, `Copy`,
+/// and [`FieldsByteOrdered`](crate::FieldsByteOrdered), the latter of which leaves it unmodified,
+/// as well as `From` of the struct, which is implemented in turn for the struct from the twin.
+///
+/// The macro serves users avoiding the proc-macro dependency of the `derive` feature, whereas
+/// `#[derive(WireTwin)]` additionally supports tuple structs, arrays, and padding and pinned
+/// fields. Every field must implement [`ByteOrdered`](crate::ByteOrdered) and `Copy`. Generic
+/// structs are not supported.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::WireSafe;
+///
+/// lilbig::define_ordered_struct! {
+///     /// Header of a record.
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     #[repr(C)]
+///     pub struct Header {
+///         /// Length of the record.
+///         pub length: u32,
+///         /// Port of the record's sender.
+///         pub port: u16,
+///     }
+///
+///     /// Header of a record as it is stored.
+///     #[repr(C)]
+///     pub struct HeaderBe: Be;
+/// }
+///
+/// let header = Header { length: 0x01020304, port: 443 };
+/// let wire = HeaderBe::from(header);
+/// assert_eq!(wire.length.as_bytes(), [0x01, 0x02, 0x03, 0x04]);
+/// assert_eq!(wire.port.as_bytes(), [0x01, 0xbb]);
+/// assert_eq!(Header::from(wire), header);
+/// ```
+#[macro_export]
+macro_rules! define_ordered_struct {
+    (@twin $order: ident [$($head: tt)*] [$($fields: tt)*] []) => {
+        $($head)* {
+            $($fields)*
+        }
+    };
+    (@twin $order: ident $head: tt $fields: tt [$($docs: tt)*] #[doc $($doc: tt)*] $($rest: tt)*) => {
+        $crate::define_ordered_struct!(@twin $order $head $fields [$($docs)* #[doc $($doc)*]] $($rest)*);
+    };
+    (@twin $order: ident $head: tt $fields: tt $docs: tt #[$($attr: tt)*] $($rest: tt)*) => {
+        $crate::define_ordered_struct!(@twin $order $head $fields $docs $($rest)*);
+    };
+    (
+        @twin $order: ident $head: tt [$($fields: tt)*] [$($docs: tt)*]
+        $field_vis: vis $field: ident: $ty: ty, $($rest: tt)*
+    ) => {
+        $crate::define_ordered_struct!(
+            @twin $order $head
+            [$($fields)* $($docs)* $field_vis $field: $crate::ordered::$order<$ty>,] []
+            $($rest)*
+        );
+    };
+    (
+        $(#[$attr: meta])*
+        $vis: vis struct $name: ident {
+            $($(#[$($field_attr: tt)*])* $field_vis: vis $field: ident: $ty: ty),* $(,)?
+        }
+
+        $(#[$wire_attr: meta])*
+        $wire_vis: vis struct $wire: ident: $order: ident;
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($(#[$($field_attr)*])* $field_vis $field: $ty,)*
+        }
+
+        // Only the documentation of the fields is shared with the twin.
+        $crate::define_ordered_struct!(
+            @twin $order
+            [$(#[$wire_attr])* #[derive(Debug, Clone, Copy)] $wire_vis struct $wire] [] []
+            $($(#[$($field_attr)*])* $field_vis $field: $ty,)*
+        );
+
+        impl ::core::convert::From<$name> for $wire {
+            #[inline]
+            fn from(value: $name) -> Self {
+                Self {
+                    $($field: $crate::ordered::$order::new(value.$field),)*
+                }
+            }
+        }
+
+        impl ::core::convert::From<$wire> for $name {
+            #[inline]
+            fn from(value: $wire) -> Self {
+                Self {
+                    $($field: value.$field.get(),)*
+                }
+            }
+        }
+
+        // The byte-order of the twin's fields is fixed thus swapping leaves it unmodified.
+        impl $crate::FieldsByteOrdered for $wire {
+            const IS_ORDER_INVARIANT: bool = true;
+
+            #[inline(always)]
+            fn swap_field_orders(&mut self) {}
+        }
+    };
+}
@@ -0,0 +1,140 @@
+//! Types marking byte-orders at compile time.
+//!
+//! Each marker type implements [`OrderMarker`], which provides the [`ByteOrder`] that the type
+//! marks. Marker types are never instantiated, and are instead used as type parameters such that
+//! generic code may select a byte-order without branching at runtime.
+//!
+//! With the `byteorder` feature enabled, [`LittleEndian`] and [`BigEndian`] are the marker types of
+//! the [byteorder](https://crates.io/crates/byteorder) crate, which thereby implement both
+//! `byteorder::ByteOrder` and [`OrderMarker`]. This allows code using byteorder to migrate to this
+//! crate incrementally without maintaining two sets of markers. The marker types of this crate
+//! otherwise mirror those of byteorder, such that enabling the feature is source compatible.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! use lilbig::marker::{BigEndian, LittleEndian, NativeEndian, OrderMarker};
+//! use lilbig::{ByteOrder, ByteOrdered};
+//!
+//! fn encode<O: OrderMarker>(value: u32) -> u32 {
+//!     value.ordered_as(ByteOrder::NATIVE, O::ORDER)
+//! }
+//!
+//! assert_eq!(encode::<BigEndian>(0x1234), 0x1234_u32.to_be());
+//! assert_eq!(encode::<LittleEndian>(0x1234), 0x1234_u32.to_le());
+//! assert_eq!(ByteOrder::of::<BigEndian>(), ByteOrder::Be);
+//! assert_eq!(ByteOrder::of::<NativeEndian>(), ByteOrder::NATIVE);
+//! ```
+//!
+//! Using the markers with byteorder:
+//! ```
+//! # #[cfg(feature = "byteorder")]
+//! # fn main() {
+//! use byteorder::ByteOrder as _;
+//! use lilbig::marker::{BigEndian, OrderMarker};
+//! use lilbig::ByteOrder;
+//!
+//! assert_eq!(BigEndian::read_u16(&[0x12, 0x34]), 0x1234);
+//! assert_eq!(BigEndian::ORDER, ByteOrder::Be);
+//! # }
+//! # #[cfg(not(feature = "byteorder"))]
+//! # fn main() {}
+//! ```
+
+use crate::ByteOrder;
+use core::fmt::Debug;
+use core::hash::Hash;
+
+/// Trait for types marking a byte-order at compile time.
+pub trait OrderMarker: Copy + Debug + Eq + Ord + Hash + 'static {
+    /// The byte-order marked by the type.
+    const ORDER: ByteOrder;
+}
+
+/// Marker of little-endian byte-order.
+///
+/// This type is uninhabited, and is only used as a type parameter.
+#[cfg(not(feature = "byteorder"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LittleEndian {}
+
+/// Marker of big-endian byte-order.
+///
+/// This type is uninhabited, and is only used as a type parameter.
+#[cfg(not(feature = "byteorder"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BigEndian {}
+
+/// Marker of the machine's native byte-order, which is an alias of either [`LittleEndian`] or
+/// [`BigEndian`].
+#[cfg(all(not(feature = "byteorder"), target_endian = "little"))]
+pub type NativeEndian = LittleEndian;
+
+/// Marker of the machine's native byte-order, which is an alias of either [`LittleEndian`] or
+/// [`BigEndian`].
+#[cfg(all(not(feature = "byteorder"), target_endian = "big"))]
+pub type NativeEndian = BigEndian;
+
+#[cfg(feature = "byteorder")]
+pub use byteorder::{BigEndian, LittleEndian, NativeEndian};
+
+impl OrderMarker for LittleEndian {
+    const ORDER: ByteOrder = ByteOrder::Le;
+}
+
+impl OrderMarker for BigEndian {
+    const ORDER: ByteOrder = ByteOrder::Be;
+}
+
+/// Trait for operations generic over a byte-order marked at compile time, which are invoked with
+/// the marker of a byte-order known only at runtime by [`with_order()`].
+///
+/// Closures may not be generic over a type parameter, so operations requiring a marker are instead
+/// expressed as implementations of this trait. The [`with_order!`](crate::with_order!) macro
+/// offers a more concise alternative for operations written inline.
+pub trait OrderVisitor {
+    /// The result of the operation.
+    type Output;
+
+    /// Perform the operation in the byte-order marked by `O`.
+    fn visit<O: OrderMarker>(self) -> Self::Output;
+}
+
+/// Invoke `visitor` with the marker of `order`, branching on `order` once such that the visitor is
+/// monomorphized over each byte-order.
+///
+/// Hot loops converting many values of a runtime byte-order thereby hoist the branch on the
+/// byte-order out of the loop, leaving each monomorphized loop free of it.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::marker::{self, OrderMarker, OrderVisitor};
+/// use lilbig::{ByteOrder, FieldsByteOrdered};
+///
+/// struct Decode<'a>(&'a mut [u32]);
+///
+/// impl OrderVisitor for Decode<'_> {
+///     type Output = u32;
+///
+///     fn visit<O: OrderMarker>(self) -> u32 {
+///         let mut sum = 0;
+///         for value in self.0 {
+///             value.order_fields::<O>();
+///             sum += *value;
+///         }
+///         sum
+///     }
+/// }
+///
+/// let mut values = [1_u32.to_be(), 2_u32.to_be(), 3_u32.to_be()];
+/// assert_eq!(marker::with_order(ByteOrder::Be, Decode(&mut values)), 6);
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+#[inline]
+pub fn with_order<V: OrderVisitor>(order: ByteOrder, visitor: V) -> V::Output {
+    match order {
+        ByteOrder::Le => visitor.visit::<LittleEndian>(),
+        ByteOrder::Be => visitor.visit::<BigEndian>(),
+    }
+}
@@ -0,0 +1,58 @@
+//! Swapping of fields while preserving some of their bits.
+//!
+//! DMA descriptors and similar structures shared with hardware commonly hold an ownership or valid
+//! bit that must not be disturbed when the rest of the descriptor is converted.
+//! [`swap_preserving_bits()`] swaps the byte-order of a value while leaving the bits selected by a
+//! mask as they were. With the `derive` feature enabled, the `preserve_bits` attribute of
+//! `#[derive(FieldsByteOrdered)]` generates such swaps for a field of a struct.
+//!
+//! Masks select bits of the in-memory representation of a value, rather than bits of the number it
+//! holds, as the same bit of the number resides in different bytes before and after it is swapped.
+//! Masks are thus most clearly written from their bytes, such as with [`u32::from_ne_bytes()`].
+//!
+//! # Examples
+//! Preserving an ownership bit:
+//! ```
+//! use lilbig::masked::swap_preserving_bits;
+//!
+//! // The ownership bit is the most significant bit of the descriptor's first byte.
+//! const OWNED: u32 = u32::from_ne_bytes([0x80, 0, 0, 0]);
+//!
+//! let mut control = u32::from_ne_bytes([0x80, 0x00, 0x12, 0x34]);
+//! swap_preserving_bits(&mut control, OWNED);
+//! assert_eq!(control.to_ne_bytes(), [0xb4, 0x12, 0x00, 0x80]);
+//! ```
+
+use crate::{FieldsByteOrdered, WireSafe};
+
+/// Swap the byte-order of the fields of `value`, leaving the bits of its in-memory representation
+/// that are set in the in-memory representation of `mask` as they were.
+///
+/// The preserved bits keep the values they held before the swap, although they are written back
+/// along with the rest of their bytes. Volatile or atomic access is not provided, so bits flipped
+/// concurrently by hardware must be guarded by other means.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::masked::swap_preserving_bits;
+///
+/// let mut value = 0x1234_u16;
+/// swap_preserving_bits(&mut value, 0x00ff);
+/// assert_eq!(value, 0x1234_u16.swap_bytes() & 0xff00 | 0x0034);
+/// ```
+#[inline]
+pub fn swap_preserving_bits<T: FieldsByteOrdered + WireSafe>(value: &mut T, mask: T) {
+    let Some(mut swapped) = T::from_bytes(value.as_bytes()) else {
+        return;
+    };
+    swapped.swap_field_orders();
+    let bytes = value
+        .as_bytes_mut()
+        .iter_mut()
+        .zip(swapped.as_bytes())
+        .zip(mask.as_bytes());
+    for ((byte, &swapped), &mask) in bytes {
+        *byte = *byte & mask | swapped & !mask;
+    }
+}
@@ -0,0 +1,293 @@
+//! Encoding and decoding of multi-register values within Modbus register blocks.
+//!
+//! Modbus registers are 16 bits wide, so values such as [`u32`] and [`f64`] span multiple
+//! registers. The protocol does not specify the order of those registers, nor whether the bytes of
+//! each register have been swapped, so devices differ in the arrangement that they use. The four
+//! possible arrangements are commonly named after the order in which the bytes `A`, `B`, `C`, and
+//! `D` of a big-endian 32-bit value appear within the registers.
+//!
+//! Registers are expected to have already been decoded from the Modbus frame into native [`u16`]
+//! values, as is done by virtually all Modbus client libraries.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! # #[cfg(not(feature = "no-float"))]
+//! # fn main() -> Result<(), lilbig::modbus::RegisterError> {
+//! use lilbig::modbus::{self, RegisterOrder};
+//!
+//! // Holding registers read from a device that arranges values word-swapped.
+//! let registers = [0x0000, 0x4049, 0xd70a, 0x4048, 0x0002, 0x0001];
+//!
+//! let mut values = [0f32; 2];
+//! modbus::decode_block(&registers[..4], RegisterOrder::CDAB, &mut values)?;
+//! assert_eq!(values, [3.140625, 3.138125]);
+//! assert_eq!(modbus::decode::<u32>(&registers[4..], RegisterOrder::CDAB)?, 0x0001_0002);
+//! # Ok(())
+//! # }
+//! # #[cfg(feature = "no-float")]
+//! # fn main() {}
+//! ```
+
+use crate::{ByteOrder, WordOrder};
+
+/// Error returned when a register block is not sized to hold the values being converted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RegisterError {
+    /// The number of registers was not equal to the number spanned by the values.
+    LengthMismatch,
+}
+
+impl RegisterError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::LengthMismatch => "register count must equal the number spanned by the values",
+        }
+    }
+}
+
+impl core::fmt::Display for RegisterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for RegisterError {}
+
+/// Arrangement of the registers of a multi-register value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegisterOrder {
+    /// The order of the registers.
+    pub words: WordOrder,
+    /// The byte-order of each register.
+    pub bytes: ByteOrder,
+}
+
+impl RegisterOrder {
+    /// Most significant register first with unswapped bytes. This is the arrangement specified by
+    /// the Modbus protocol for individual registers.
+    pub const ABCD: Self = Self::new(WordOrder::Be, ByteOrder::Be);
+
+    /// Most significant register first with swapped bytes.
+    pub const BADC: Self = Self::new(WordOrder::Be, ByteOrder::Le);
+
+    /// Least significant register first with unswapped bytes.
+    pub const CDAB: Self = Self::new(WordOrder::Le, ByteOrder::Be);
+
+    /// Least significant register first with swapped bytes.
+    pub const DCBA: Self = Self::new(WordOrder::Le, ByteOrder::Le);
+
+    /// Create an arrangement from the order of the registers and the byte-order of each register.
+    #[inline]
+    #[must_use]
+    pub const fn new(words: WordOrder, bytes: ByteOrder) -> Self {
+        Self { words, bytes }
+    }
+}
+
+/// Trait for values that may be encoded within a whole number of Modbus registers.
+pub trait RegisterValue: Copy {
+    /// The number of registers spanned by the value.
+    const REGISTERS: usize;
+
+    /// Create a value from the bits of its encoding, which occupy the least significant bits.
+    fn from_bits(bits: u64) -> Self;
+
+    /// Retrieve the bits of the value's encoding in the least significant bits.
+    fn to_bits(self) -> u64;
+}
+
+/// Implement [`RegisterValue`] for a set of integer types.
+macro_rules! impl_register_value_int {
+    ($($ty: ty => $unsigned: ty),+) => {
+        $(impl RegisterValue for $ty {
+            const REGISTERS: usize = core::mem::size_of::<$ty>() / 2;
+
+            #[inline]
+            fn from_bits(bits: u64) -> Self {
+                bits as $unsigned as Self
+            }
+
+            #[inline]
+            fn to_bits(self) -> u64 {
+                self as $unsigned as u64
+            }
+        })+
+    };
+}
+
+/// Implement [`RegisterValue`] for a set of floating point types.
+#[cfg(not(feature = "no-float"))]
+macro_rules! impl_register_value_float {
+    ($($ty: ty => $unsigned: ty),+) => {
+        $(impl RegisterValue for $ty {
+            const REGISTERS: usize = core::mem::size_of::<$ty>() / 2;
+
+            #[inline]
+            fn from_bits(bits: u64) -> Self {
+                Self::from_bits(bits as $unsigned)
+            }
+
+            #[inline]
+            fn to_bits(self) -> u64 {
+                self.to_bits() as u64
+            }
+        })+
+    };
+}
+
+impl_register_value_int!(u16 => u16, i16 => u16, u32 => u32, i32 => u32, u64 => u64, i64 => u64);
+#[cfg(not(feature = "no-float"))]
+impl_register_value_float!(f32 => u32, f64 => u64);
+
+/// Decode a value from exactly as many registers as it spans.
+///
+/// # Errors
+/// Returns [`RegisterError::LengthMismatch`] if the length of `registers` is not equal to
+/// [`T::REGISTERS`](RegisterValue::REGISTERS).
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::modbus::{self, RegisterError, RegisterOrder};
+///
+/// let value = 0x1122_3344_5566_7788u64;
+/// assert_eq!(modbus::decode::<u64>(&[0x1122, 0x3344, 0x5566, 0x7788], RegisterOrder::ABCD), Ok(value));
+/// assert_eq!(modbus::decode::<u64>(&[0x2211, 0x4433, 0x6655, 0x8877], RegisterOrder::BADC), Ok(value));
+/// assert_eq!(modbus::decode::<u64>(&[0x7788, 0x5566, 0x3344, 0x1122], RegisterOrder::CDAB), Ok(value));
+/// assert_eq!(modbus::decode::<u64>(&[0x8877, 0x6655, 0x4433, 0x2211], RegisterOrder::DCBA), Ok(value));
+///
+/// let result = modbus::decode::<u64>(&[0x1122, 0x3344], RegisterOrder::ABCD);
+/// assert_eq!(result, Err(RegisterError::LengthMismatch));
+/// ```
+pub fn decode<T: RegisterValue>(
+    registers: &[u16],
+    order: RegisterOrder,
+) -> Result<T, RegisterError> {
+    if registers.len() != T::REGISTERS {
+        return Err(RegisterError::LengthMismatch);
+    }
+    Ok(decode_unchecked(registers, order))
+}
+
+/// Encode a value into exactly as many registers as it spans.
+///
+/// # Errors
+/// Returns [`RegisterError::LengthMismatch`] if the length of `registers` is not equal to
+/// [`T::REGISTERS`](RegisterValue::REGISTERS). The contents of `registers` are left unmodified upon
+/// error.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::modbus::{self, RegisterOrder};
+///
+/// let mut registers = [0u16; 2];
+/// modbus::encode(-2i32, RegisterOrder::DCBA, &mut registers)?;
+/// assert_eq!(registers, [0xfeff, 0xffff]);
+/// # Ok::<(), lilbig::modbus::RegisterError>(())
+/// ```
+pub fn encode<T: RegisterValue>(
+    value: T,
+    order: RegisterOrder,
+    registers: &mut [u16],
+) -> Result<(), RegisterError> {
+    if registers.len() != T::REGISTERS {
+        return Err(RegisterError::LengthMismatch);
+    }
+    encode_unchecked(value, order, registers);
+    Ok(())
+}
+
+/// Decode a block of consecutive values from a block of registers.
+///
+/// # Errors
+/// Returns [`RegisterError::LengthMismatch`] if the length of `registers` is not equal to the
+/// number of registers spanned by all of `values`. The contents of `values` are left unmodified
+/// upon error.
+pub fn decode_block<T: RegisterValue>(
+    registers: &[u16],
+    order: RegisterOrder,
+    values: &mut [T],
+) -> Result<(), RegisterError> {
+    if Some(registers.len()) != values.len().checked_mul(T::REGISTERS) {
+        return Err(RegisterError::LengthMismatch);
+    }
+    for (value, registers) in values.iter_mut().zip(registers.chunks_exact(T::REGISTERS)) {
+        *value = decode_unchecked(registers, order);
+    }
+    Ok(())
+}
+
+/// Encode a block of consecutive values into a block of registers.
+///
+/// # Errors
+/// Returns [`RegisterError::LengthMismatch`] if the length of `registers` is not equal to the
+/// number of registers spanned by all of `values`. The contents of `registers` are left unmodified
+/// upon error.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::modbus::{self, RegisterOrder};
+///
+/// let mut registers = [0u16; 4];
+/// modbus::encode_block(&[0x3f80_0000u32, 0xc020_0000], RegisterOrder::ABCD, &mut registers)?;
+/// assert_eq!(registers, [0x3f80, 0x0000, 0xc020, 0x0000]);
+/// # Ok::<(), lilbig::modbus::RegisterError>(())
+/// ```
+pub fn encode_block<T: RegisterValue>(
+    values: &[T],
+    order: RegisterOrder,
+    registers: &mut [u16],
+) -> Result<(), RegisterError> {
+    if Some(registers.len()) != values.len().checked_mul(T::REGISTERS) {
+        return Err(RegisterError::LengthMismatch);
+    }
+    for (value, registers) in values.iter().zip(registers.chunks_exact_mut(T::REGISTERS)) {
+        encode_unchecked(*value, order, registers);
+    }
+    Ok(())
+}
+
+/// Implementation of [`decode()`] that assumes the length of `registers` has been validated.
+fn decode_unchecked<T: RegisterValue>(registers: &[u16], order: RegisterOrder) -> T {
+    let word = |register: &u16| u64::from(order_register(*register, order.bytes));
+    let bits = match order.words {
+        WordOrder::Be => registers.iter().map(word).fold(0, |acc, w| acc << 16 | w),
+        WordOrder::Le => registers
+            .iter()
+            .rev()
+            .map(word)
+            .fold(0, |acc, w| acc << 16 | w),
+    };
+    T::from_bits(bits)
+}
+
+/// Implementation of [`encode()`] that assumes the length of `registers` has been validated.
+fn encode_unchecked<T: RegisterValue>(value: T, order: RegisterOrder, registers: &mut [u16]) {
+    let mut bits = value.to_bits();
+    let mut store = |register: &mut u16| {
+        *register = order_register(bits as u16, order.bytes);
+        bits >>= 16;
+    };
+    match order.words {
+        WordOrder::Be => registers.iter_mut().rev().for_each(&mut store),
+        WordOrder::Le => registers.iter_mut().for_each(&mut store),
+    }
+}
+
+/// Apply the byte-order of a register arrangement to a register's value.
+///
+/// Registers are transferred big-endian, so a little-endian arrangement is one whose bytes have been
+/// swapped.
+#[inline]
+fn order_register(register: u16, bytes: ByteOrder) -> u16 {
+    match bytes {
+        ByteOrder::Be => register,
+        ByteOrder::Le => register.swap_bytes(),
+    }
+}
@@ -0,0 +1,515 @@
+//! Network protocol header structs.
+//!
+//! Each header is `#[repr(C)]`, free of padding, and implements both [`FieldsByteOrdered`] and
+//! [`WireSafe`]. Headers may thus be read directly from a packet buffer with
+//! [`WireSafe::from_bytes()`] and converted from network byte-order (big-endian) with
+//! [`FieldsByteOrdered::order_fields_ne()`].
+//!
+//! Fields that pack multiple sub-byte values (such as the version and header length of an IPv4
+//! header) are provided as a whole. Each header describes its sub-byte values with [`BitField`]
+//! constants, which read and write the values within the header's bytes in network byte-order.
+//! Addresses are provided as byte arrays and are thus unaffected by byte-order conversion.
+//!
+//! # Examples
+//! Parsing the headers of a UDP datagram:
+//! ```
+//! use lilbig::net::{Ipv4Header, UdpHeader};
+//! use lilbig::{ByteOrder, FieldsByteOrdered, WireSafe};
+//!
+//! let packet = [
+//!     0x45, 0x00, 0x00, 0x1d, 0x12, 0x34, 0x40, 0x00, 0x40, 0x11, 0x00, 0x00, 0xc0, 0xa8, 0x00,
+//!     0x01, 0xc0, 0xa8, 0x00, 0x02, 0x30, 0x39, 0x00, 0x35, 0x00, 0x09, 0x00, 0x00, 0x2a,
+//! ];
+//!
+//! let (ip, rest) = packet.split_at(core::mem::size_of::<Ipv4Header>());
+//! let mut ip = Ipv4Header::from_bytes(ip).unwrap();
+//! ip.order_fields_ne(ByteOrder::Be);
+//! assert_eq!(ip.total_length, 29);
+//! assert_eq!(ip.protocol, 17);
+//! assert_eq!(ip.source, [192, 168, 0, 1]);
+//!
+//! let (udp, payload) = rest.split_at(core::mem::size_of::<UdpHeader>());
+//! let mut udp = UdpHeader::from_bytes(udp).unwrap();
+//! udp.order_fields_ne(ByteOrder::Be);
+//! assert_eq!(udp.source_port, 12345);
+//! assert_eq!(udp.destination_port, 53);
+//! assert_eq!(payload, [0x2a]);
+//!
+//! // Fill in the header's checksum and convert it back to network byte-order.
+//! ip.checksum = ip.compute_checksum();
+//! ip.order_fields_be(ByteOrder::NATIVE);
+//! assert_eq!(lilbig::net::internet_checksum(ip.as_bytes()), 0);
+//! assert_eq!(Ipv4Header::VERSION.try_get(ip.as_bytes()), Ok(4));
+//! assert_eq!(Ipv4Header::IHL.try_get(ip.as_bytes()), Ok(5));
+//! ```
+
+use crate::headers::impl_header;
+use crate::{FieldsByteOrdered, WireSafe};
+
+/// Ethernet II frame header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct EthernetHeader {
+    /// Destination MAC address.
+    pub destination: [u8; 6],
+    /// Source MAC address.
+    pub source: [u8; 6],
+    /// Protocol of the frame's payload.
+    pub ether_type: u16,
+}
+
+impl_header!(EthernetHeader {
+    destination,
+    source,
+    ether_type,
+});
+
+/// IPv4 packet header, excluding options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Ipv4Header {
+    /// Version (upper 4 bits) and header length in 32-bit words (lower 4 bits).
+    pub version_ihl: u8,
+    /// Differentiated services code point (upper 6 bits) and explicit congestion notification
+    /// (lower 2 bits).
+    pub dscp_ecn: u8,
+    /// Length of the entire packet in bytes.
+    pub total_length: u16,
+    /// Identification of the packet's fragments.
+    pub identification: u16,
+    /// Flags (upper 3 bits) and fragment offset in 8-byte units (lower 13 bits).
+    pub flags_fragment_offset: u16,
+    /// Time to live.
+    pub ttl: u8,
+    /// Protocol of the packet's payload.
+    pub protocol: u8,
+    /// Checksum of the header.
+    pub checksum: u16,
+    /// Source address.
+    pub source: [u8; 4],
+    /// Destination address.
+    pub destination: [u8; 4],
+}
+
+impl_header!(Ipv4Header {
+    version_ihl,
+    dscp_ecn,
+    total_length,
+    identification,
+    flags_fragment_offset,
+    ttl,
+    protocol,
+    checksum,
+    source,
+    destination,
+});
+
+impl Ipv4Header {
+    /// Version of the protocol, which is always 4.
+    pub const VERSION: BitField = BitField::new(0, 4);
+    /// Length of the header in 32-bit words.
+    pub const IHL: BitField = BitField::new(4, 4);
+    /// Differentiated services code point.
+    pub const DSCP: BitField = BitField::new(8, 6);
+    /// Explicit congestion notification.
+    pub const ECN: BitField = BitField::new(14, 2);
+    /// Flags, of which the lower two bits are "don't fragment" and "more fragments".
+    pub const FLAGS: BitField = BitField::new(48, 3);
+    /// Offset of the fragment in 8-byte units.
+    pub const FRAGMENT_OFFSET: BitField = BitField::new(51, 13);
+
+    /// Compute the checksum of the header, whose fields are expected to be in the machine's native
+    /// byte-order.
+    ///
+    /// The current value of the [`checksum`](Ipv4Header::checksum) field is ignored. The returned
+    /// checksum is in the machine's native byte-order and is suitable for assignment to the
+    /// [`checksum`](Ipv4Header::checksum) field prior to converting the header to network
+    /// byte-order.
+    #[must_use]
+    pub fn compute_checksum(&self) -> u16 {
+        let mut header = *self;
+        header.checksum = 0;
+        header.order_fields_be(crate::ByteOrder::NATIVE);
+        internet_checksum(header.as_bytes())
+    }
+}
+
+/// IPv6 packet header, excluding extension headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Ipv6Header {
+    /// Version (upper 4 bits), traffic class (middle 8 bits), and flow label (lower 20 bits).
+    pub version_class_flow: u32,
+    /// Length of the packet's payload in bytes, including extension headers.
+    pub payload_length: u16,
+    /// Type of the header following this header.
+    pub next_header: u8,
+    /// Hop limit.
+    pub hop_limit: u8,
+    /// Source address.
+    pub source: [u8; 16],
+    /// Destination address.
+    pub destination: [u8; 16],
+}
+
+impl_header!(Ipv6Header {
+    version_class_flow,
+    payload_length,
+    next_header,
+    hop_limit,
+    source,
+    destination,
+});
+
+impl Ipv6Header {
+    /// Version of the protocol, which is always 6.
+    pub const VERSION: BitField = BitField::new(0, 4);
+    /// Traffic class.
+    pub const TRAFFIC_CLASS: BitField = BitField::new(4, 8);
+    /// Label of the flow to which the packet belongs.
+    pub const FLOW_LABEL: BitField = BitField::new(12, 20);
+}
+
+/// TCP segment header, excluding options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct TcpHeader {
+    /// Source port.
+    pub source_port: u16,
+    /// Destination port.
+    pub destination_port: u16,
+    /// Sequence number.
+    pub sequence: u32,
+    /// Acknowledgment number.
+    pub acknowledgment: u32,
+    /// Header length in 32-bit words (upper 4 bits), reserved bits, and flags (lower 9 bits).
+    pub data_offset_flags: u16,
+    /// Receive window size.
+    pub window: u16,
+    /// Checksum of the pseudo-header, header, and payload.
+    pub checksum: u16,
+    /// Urgent pointer.
+    pub urgent_pointer: u16,
+}
+
+impl_header!(TcpHeader {
+    source_port,
+    destination_port,
+    sequence,
+    acknowledgment,
+    data_offset_flags,
+    window,
+    checksum,
+    urgent_pointer,
+});
+
+impl TcpHeader {
+    /// Length of the header in 32-bit words.
+    pub const DATA_OFFSET: BitField = BitField::new(96, 4);
+    /// Flags, of which the lowest six bits are URG, ACK, PSH, RST, SYN, and FIN.
+    pub const FLAGS: BitField = BitField::new(103, 9);
+}
+
+/// UDP datagram header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct UdpHeader {
+    /// Source port.
+    pub source_port: u16,
+    /// Destination port.
+    pub destination_port: u16,
+    /// Length of the header and payload in bytes.
+    pub length: u16,
+    /// Checksum of the pseudo-header, header, and payload.
+    pub checksum: u16,
+}
+
+impl_header!(UdpHeader {
+    source_port,
+    destination_port,
+    length,
+    checksum,
+});
+
+/// ICMP message header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct IcmpHeader {
+    /// Type of the message.
+    pub icmp_type: u8,
+    /// Subtype of the message.
+    pub code: u8,
+    /// Checksum of the header and payload.
+    pub checksum: u16,
+    /// Type dependent remainder of the header.
+    pub rest_of_header: [u8; 4],
+}
+
+impl_header!(IcmpHeader {
+    icmp_type,
+    code,
+    checksum,
+    rest_of_header,
+});
+
+/// Error returned when reading or writing a [`BitField`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BitFieldError {
+    /// The field extended beyond the end of the header.
+    OutOfBounds,
+    /// The field was wider than 32 bits.
+    UnsupportedWidth,
+    /// The value written did not fit within the field.
+    Overflow,
+}
+
+impl BitFieldError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::OutOfBounds => "bit field extends beyond the end of the header",
+            Self::UnsupportedWidth => "bit field is wider than 32 bits",
+            Self::Overflow => "value does not fit within the bit field",
+        }
+    }
+}
+
+impl core::fmt::Display for BitFieldError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for BitFieldError {}
+
+/// Location of a value of up to 32 bits within a header in network byte-order.
+///
+/// Bits are numbered as in the diagrams of RFCs, where bit 0 is the most significant bit of the
+/// first byte of the header. Values are read from and written to the bytes of a header, such as
+/// those of a packet buffer or of a header struct that is yet to be converted from network
+/// byte-order, and are never converted to or from the machine's native byte-order by hand.
+///
+/// # Examples
+/// Reading and writing the flags of a TCP header:
+/// ```
+/// use lilbig::net::{BitField, TcpHeader};
+/// use lilbig::WireSafe;
+///
+/// let mut header = [0u8; 20];
+/// header[12] = 0x50;
+/// header[13] = 0x12;
+///
+/// assert_eq!(TcpHeader::DATA_OFFSET.try_get(&header), Ok(5));
+/// assert_eq!(TcpHeader::FLAGS.try_get(&header), Ok(0b0_0001_0010));
+///
+/// const SYN: BitField = BitField::new(110, 1);
+/// SYN.try_set(&mut header, 0).unwrap();
+/// assert_eq!(header[13], 0x10);
+///
+/// let header = TcpHeader::from_bytes(&header).unwrap();
+/// assert_eq!(TcpHeader::FLAGS.try_get(header.as_bytes()), Ok(0b0_0001_0000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitField {
+    /// Offset of the field's most significant bit from the start of the header.
+    offset: usize,
+    /// Width of the field in bits.
+    width: u32,
+}
+
+impl BitField {
+    /// Create a field of `width` bits whose most significant bit is `offset` bits from the start of
+    /// the header.
+    #[inline]
+    #[must_use]
+    pub const fn new(offset: usize, width: u32) -> Self {
+        Self { offset, width }
+    }
+
+    /// Retrieve the offset of the field's most significant bit from the start of the header.
+    #[inline]
+    #[must_use]
+    pub const fn offset(self) -> usize {
+        self.offset
+    }
+
+    /// Retrieve the width of the field in bits.
+    #[inline]
+    #[must_use]
+    pub const fn width(self) -> u32 {
+        self.width
+    }
+
+    /// Retrieve the bytes spanned by the field and the shift of the field within them.
+    fn span(self, header_len: usize) -> Result<(core::ops::Range<usize>, u32), BitFieldError> {
+        if self.width > 32 {
+            return Err(BitFieldError::UnsupportedWidth);
+        }
+        let end = self
+            .offset
+            .checked_add(self.width as usize)
+            .ok_or(BitFieldError::OutOfBounds)?;
+        let bytes = self.offset / 8..end.div_ceil(8);
+        if bytes.end > header_len {
+            return Err(BitFieldError::OutOfBounds);
+        }
+        Ok((bytes.clone(), (bytes.end * 8 - end) as u32))
+    }
+
+    /// Retrieve the mask of the field's value.
+    const fn mask(self) -> u64 {
+        (1 << self.width) - 1
+    }
+
+    /// Read the field's value from `header`.
+    ///
+    /// # Panics
+    /// Panics if the field extends beyond the end of `header` or is wider than 32 bits. See
+    /// [`try_get()`](BitField::try_get) for a non-panicking alternative.
+    #[cfg(not(feature = "no-panic"))]
+    #[must_use]
+    pub fn get(self, header: &[u8]) -> u32 {
+        match self.try_get(header) {
+            Ok(value) => value,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Read the field's value from `header`.
+    ///
+    /// This is the non-panicking alternative of `get()`.
+    ///
+    /// # Errors
+    /// Returns [`BitFieldError::OutOfBounds`] if the field extends beyond the end of `header`, or
+    /// [`BitFieldError::UnsupportedWidth`] if the field is wider than 32 bits.
+    pub fn try_get(self, header: &[u8]) -> Result<u32, BitFieldError> {
+        let (bytes, shift) = self.span(header.len())?;
+        let window = header
+            .get(bytes)
+            .ok_or(BitFieldError::OutOfBounds)?
+            .iter()
+            .fold(0u64, |window, &byte| window << 8 | u64::from(byte));
+        Ok((window >> shift & self.mask()) as u32)
+    }
+
+    /// Write `value` to the field within `header`, leaving the surrounding bits unmodified.
+    ///
+    /// # Panics
+    /// Panics if the field extends beyond the end of `header`, is wider than 32 bits, or is too
+    /// narrow to hold `value`. See [`try_set()`](BitField::try_set) for a non-panicking
+    /// alternative.
+    #[cfg(not(feature = "no-panic"))]
+    pub fn set(self, header: &mut [u8], value: u32) {
+        if let Err(err) = self.try_set(header, value) {
+            panic!("{err}");
+        }
+    }
+
+    /// Write `value` to the field within `header`, leaving the surrounding bits unmodified.
+    ///
+    /// This is the non-panicking alternative of `set()`.
+    ///
+    /// # Errors
+    /// Returns [`BitFieldError::OutOfBounds`] if the field extends beyond the end of `header`,
+    /// [`BitFieldError::UnsupportedWidth`] if the field is wider than 32 bits, or
+    /// [`BitFieldError::Overflow`] if `value` does not fit within the field. The contents of
+    /// `header` are left unmodified upon error.
+    pub fn try_set(self, header: &mut [u8], value: u32) -> Result<(), BitFieldError> {
+        let (bytes, shift) = self.span(header.len())?;
+        if u64::from(value) > self.mask() {
+            return Err(BitFieldError::Overflow);
+        }
+        let bytes = header.get_mut(bytes).ok_or(BitFieldError::OutOfBounds)?;
+        let mut window = bytes
+            .iter()
+            .fold(0u64, |window, &byte| window << 8 | u64::from(byte));
+        window &= !(self.mask() << shift);
+        window |= u64::from(value) << shift;
+        for byte in bytes.iter_mut().rev() {
+            *byte = window as u8;
+            window >>= 8;
+        }
+        Ok(())
+    }
+}
+
+/// Accumulator for computing the Internet checksum (RFC 1071) over multiple byte slices.
+///
+/// # Examples
+/// Computing the checksum of a UDP datagram including its IPv4 pseudo-header:
+/// ```
+/// use lilbig::net::Checksum;
+///
+/// let source = [192, 168, 0, 1];
+/// let destination = [192, 168, 0, 2];
+/// let datagram = [0x30, 0x39, 0x00, 0x35, 0x00, 0x09, 0x00, 0x00, 0x2a];
+///
+/// let mut checksum = Checksum::new();
+/// checksum.add_bytes(&source);
+/// checksum.add_bytes(&destination);
+/// checksum.add_bytes(&[0, 17]);
+/// checksum.add_bytes(&(datagram.len() as u16).to_be_bytes());
+/// checksum.add_bytes(&datagram);
+/// assert_eq!(checksum.finish(), 0x241a);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Checksum {
+    /// Running ones' complement sum with deferred carries.
+    sum: u64,
+    /// Whether an odd number of bytes has been added.
+    odd: bool,
+}
+
+impl Checksum {
+    /// Create an accumulator to which no bytes have been added.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { sum: 0, odd: false }
+    }
+
+    /// Add `bytes` to the checksum.
+    ///
+    /// Bytes are paired into big-endian 16-bit words across calls, so a slice of odd length may be
+    /// followed by further slices.
+    pub fn add_bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.sum += if self.odd {
+                u64::from(byte)
+            } else {
+                u64::from(byte) << 8
+            };
+            self.odd = !self.odd;
+        }
+    }
+
+    /// Finish computing the checksum, returning it in the machine's native byte-order.
+    #[must_use]
+    pub fn finish(self) -> u16 {
+        let mut sum = self.sum;
+        while sum > 0xffff {
+            sum = (sum & 0xffff) + (sum >> 16);
+        }
+        !(sum as u16)
+    }
+}
+
+/// Compute the Internet checksum (RFC 1071) of `bytes`, returning it in the machine's native
+/// byte-order.
+///
+/// Computing the checksum of data that includes a correct checksum results in zero.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::net::internet_checksum;
+/// assert_eq!(internet_checksum(&[0x00, 0x01, 0xf2, 0x03, 0xf4, 0xf5, 0xf6, 0xf7]), 0x220d);
+/// ```
+#[must_use]
+pub fn internet_checksum(bytes: &[u8]) -> u16 {
+    let mut checksum = Checksum::new();
+    checksum.add_bytes(bytes);
+    checksum.finish()
+}
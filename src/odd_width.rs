@@ -0,0 +1,253 @@
+//! Integers of widths lacking a primitive type.
+//!
+//! Audio samples are commonly stored as 24-bit integers, and network counters and identifiers as
+//! 40-bit or 48-bit integers. Rust has no primitive types of these widths, so [`U24`], [`I24`],
+//! [`U40`], and [`U48`] hold the bytes of such integers and provide conversions to and from the
+//! next wider primitive type. As the types are backed by byte arrays they have an alignment of one,
+//! and their byte-order is converted by reversing their bytes.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! use lilbig::odd_width::{I24, U48};
+//! use lilbig::{ByteOrder, ByteOrdered};
+//!
+//! let sample = I24::from_le_bytes([0xfe, 0xff, 0xff]);
+//! assert_eq!(i32::from(sample), -2);
+//!
+//! let address = U48::from_ne_bytes([0x00, 0x00, 0x5e, 0x00, 0x53, 0x01]).ordered_ne(ByteOrder::Be);
+//! assert_eq!(u64::from(address), 0x00005e005301);
+//! assert!(U48::try_from(1_u64 << 48).is_err());
+//! ```
+
+use crate::layout::{FieldLayout, SwapLayout};
+use crate::{ByteOrder, ByteOrdered, FieldsByteOrdered, WireSafe};
+
+/// Error returned when an integer is converted to an integer of a narrower width that can't
+/// represent it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OddWidthError {
+    /// The integer was outside of the range of the narrower integer.
+    OutOfRange,
+}
+
+impl OddWidthError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::OutOfRange => "integer is out of the range of the narrower integer",
+        }
+    }
+}
+
+impl core::fmt::Display for OddWidthError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for OddWidthError {}
+
+/// Copy the bytes of `from` into the start of `to`, leaving any bytes of `to` beyond those of `from`
+/// unmodified.
+#[inline]
+fn copy_prefix<const N: usize, const M: usize>(from: [u8; N], mut to: [u8; M]) -> [u8; M] {
+    for (to, from) in to.iter_mut().zip(from) {
+        *to = from;
+    }
+    to
+}
+
+/// Reverse the order of `bytes`.
+#[inline]
+fn reverse<const N: usize>(mut bytes: [u8; N]) -> [u8; N] {
+    bytes.reverse();
+    bytes
+}
+
+/// Define an integer of a width lacking a primitive type.
+macro_rules! define_odd_width {
+    (@min unsigned, $width: literal) => {
+        0
+    };
+    (@max unsigned, $width: literal) => {
+        (1 << ($width * 8)) - 1
+    };
+    (@min signed, $width: literal) => {
+        -(1 << ($width * 8 - 1))
+    };
+    (@max signed, $width: literal) => {
+        (1 << ($width * 8 - 1)) - 1
+    };
+    (@negative unsigned, $bytes: ident) => {
+        false
+    };
+    (@negative signed, $bytes: ident) => {
+        $bytes.last().is_some_and(|&byte| byte & 0x80 != 0)
+    };
+    ($(#[$attr: meta])* $name: ident, $width: literal, $int: ty, $signed: ident) => {
+        $(#[$attr])*
+        ///
+        /// The integer is held as its bytes in the machine's native byte-order, and its byte-order is
+        /// converted by reversing those bytes.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #[repr(transparent)]
+        pub struct $name([u8; $width]);
+
+        impl $name {
+            /// Smallest value representable by the integer.
+            pub const MIN: $int = define_odd_width!(@min $signed, $width);
+            /// Largest value representable by the integer.
+            pub const MAX: $int = define_odd_width!(@max $signed, $width);
+
+            /// Create an integer from its bytes in the machine's native byte-order.
+            #[inline]
+            #[must_use]
+            pub const fn from_ne_bytes(bytes: [u8; $width]) -> Self {
+                Self(bytes)
+            }
+
+            /// Create an integer from its bytes in little-endian.
+            #[inline]
+            #[must_use]
+            pub fn from_le_bytes(bytes: [u8; $width]) -> Self {
+                Self(bytes).ordered_ne(ByteOrder::Le)
+            }
+
+            /// Create an integer from its bytes in big-endian.
+            #[inline]
+            #[must_use]
+            pub fn from_be_bytes(bytes: [u8; $width]) -> Self {
+                Self(bytes).ordered_ne(ByteOrder::Be)
+            }
+
+            /// Retrieve the integer's bytes in the machine's native byte-order.
+            #[inline]
+            #[must_use]
+            pub const fn to_ne_bytes(self) -> [u8; $width] {
+                self.0
+            }
+
+            /// Retrieve the integer's bytes in little-endian.
+            #[inline]
+            #[must_use]
+            pub fn to_le_bytes(self) -> [u8; $width] {
+                self.ordered_le(ByteOrder::NATIVE).0
+            }
+
+            /// Retrieve the integer's bytes in big-endian.
+            #[inline]
+            #[must_use]
+            pub fn to_be_bytes(self) -> [u8; $width] {
+                self.ordered_be(ByteOrder::NATIVE).0
+            }
+
+            #[doc = concat!("Create an integer from the least significant ", $width, " bytes of ")]
+            #[doc = "`value`, discarding its other bytes."]
+            #[inline]
+            #[must_use]
+            pub fn from_truncated(value: $int) -> Self {
+                Self::from_le_bytes(copy_prefix(value.to_le_bytes(), [0; $width]))
+            }
+
+            #[doc = concat!("Convert the integer to ", stringify!($int), ".")]
+            #[inline]
+            #[must_use]
+            pub fn get(self) -> $int {
+                let bytes = self.to_le_bytes();
+                let fill = if define_odd_width!(@negative $signed, bytes) {
+                    0xff
+                } else {
+                    0
+                };
+                <$int>::from_le_bytes(copy_prefix(bytes, [fill; core::mem::size_of::<$int>()]))
+            }
+        }
+
+        impl From<$name> for $int {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        impl TryFrom<$int> for $name {
+            type Error = OddWidthError;
+
+            #[inline]
+            fn try_from(value: $int) -> Result<Self, OddWidthError> {
+                if (Self::MIN..=Self::MAX).contains(&value) {
+                    Ok(Self::from_truncated(value))
+                } else {
+                    Err(OddWidthError::OutOfRange)
+                }
+            }
+        }
+
+        impl ByteOrdered for $name {
+            #[inline]
+            fn swapped_order(self) -> Self {
+                Self(reverse(self.0))
+            }
+        }
+
+        impl FieldsByteOrdered for $name {
+            #[inline]
+            fn swap_field_orders(&mut self) {
+                #[cfg(feature = "swap-trace")]
+                crate::swap_trace::swap(self);
+                *self = self.swapped_order();
+            }
+        }
+
+        // SAFETY: The integer is `repr(transparent)` over a byte array.
+        unsafe impl WireSafe for $name {}
+
+        /// The integer is a single field of a width lacking a primitive type.
+        impl SwapLayout for $name {
+            const SWAP_FIELD_COUNT: usize = 1;
+
+            #[inline]
+            fn swap_field(index: usize) -> Option<FieldLayout> {
+                match index {
+                    0 => Some(FieldLayout::opaque(0, $width)),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+define_odd_width!(
+    /// Unsigned 24-bit integer.
+    U24,
+    3,
+    u32,
+    unsigned
+);
+
+define_odd_width!(
+    /// Signed 24-bit integer.
+    I24,
+    3,
+    i32,
+    signed
+);
+
+define_odd_width!(
+    /// Unsigned 40-bit integer.
+    U40,
+    5,
+    u64,
+    unsigned
+);
+
+define_odd_width!(
+    /// Unsigned 48-bit integer.
+    U48,
+    6,
+    u64,
+    unsigned
+);
@@ -0,0 +1,216 @@
+//! Byte offsets referencing values within a buffer.
+//!
+//! File formats commonly reference their tables and records by their offsets from the start of the
+//! file or of an enclosing section. [`Offset32`] and [`Offset64`] hold such offsets along with the
+//! type of the value they reference, and are swapped along with the other fields of the structs
+//! holding them. Once the struct holding an offset is in the machine's native byte-order, the
+//! offset resolves to the value it references within the buffer it is relative to, which is either
+//! decoded or viewed through [`OrderedView`] in the byte-order of the buffer.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # fn main() {
+//! use lilbig::offset::Offset32;
+//! use lilbig::view::OrderedView;
+//! use lilbig::{ByteOrder, FieldsByteOrdered};
+//!
+//! #[derive(OrderedView)]
+//! #[repr(C)]
+//! struct Entry {
+//!     id: u16,
+//!     length: u16,
+//! }
+//!
+//! #[derive(FieldsByteOrdered)]
+//! #[repr(C)]
+//! struct Header {
+//!     magic: u32,
+//!     entry: Offset32<Entry>,
+//! }
+//!
+//! let file = [0x4c, 0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0xff, 0xff, 0x00, 0x07, 0x01, 0x00];
+//! let mut header = Header {
+//!     magic: 0x4c420000_u32.to_be(),
+//!     entry: Offset32::new(10_u32.to_be()),
+//! };
+//! header.order_fields_ne(ByteOrder::Be);
+//! let entry = header.entry.resolve(&file, ByteOrder::Be).unwrap();
+//! assert_eq!(entry.get_id(), 7);
+//! assert_eq!(entry.get_length(), 0x100);
+//! assert!(Offset32::<Entry>::new(12).resolve(&file, ByteOrder::Be).is_none());
+//! # }
+//! # #[cfg(not(feature = "derive"))]
+//! # fn main() {}
+//! ```
+
+use crate::layout::{FieldLayout, SwapLayout};
+use crate::view::{read_field, OrderedView};
+use crate::{ByteOrder, FieldsByteOrdered, WireSafe};
+use core::marker::PhantomData;
+
+/// Define an offset referencing a value within a buffer.
+macro_rules! define_offset {
+    ($(#[$attr: meta])* $name: ident, $int: ty) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        pub struct $name<T> {
+            /// Offset of the referenced value in bytes from the start of the buffer.
+            offset: $int,
+            /// Marker of the type of the referenced value.
+            marker: PhantomData<fn() -> T>,
+        }
+
+        impl<T> $name<T> {
+            /// Create an offset of `offset` bytes, which is in the byte-order of the struct holding
+            /// it.
+            #[inline(always)]
+            #[must_use]
+            pub const fn new(offset: $int) -> Self {
+                Self {
+                    offset,
+                    marker: PhantomData,
+                }
+            }
+
+            /// Retrieve the offset in bytes, which is in the byte-order of the struct holding it.
+            #[inline(always)]
+            #[must_use]
+            pub const fn get(self) -> $int {
+                self.offset
+            }
+
+            /// Retrieve the range of bytes of the referenced value within a buffer, or [`None`] if
+            /// the range can't be represented.
+            #[inline]
+            #[must_use]
+            pub fn range(self) -> Option<core::ops::Range<usize>> {
+                let start = usize::try_from(self.offset).ok()?;
+                Some(start..start.checked_add(core::mem::size_of::<T>())?)
+            }
+
+            /// Create a view of the referenced value encoded in `order` within `base`, or [`None`]
+            /// if `base` ends before the value does.
+            #[inline]
+            #[must_use]
+            pub fn resolve<'a>(self, base: &'a [u8], order: ByteOrder) -> Option<T::View<'a>>
+            where
+                T: OrderedView,
+            {
+                T::view(base.get(usize::try_from(self.offset).ok()?..)?, order)
+            }
+
+            /// Create a mutable view of the referenced value encoded in `order` within `base`, or
+            /// [`None`] if `base` ends before the value does.
+            #[inline]
+            #[must_use]
+            pub fn resolve_mut<'a>(
+                self,
+                base: &'a mut [u8],
+                order: ByteOrder,
+            ) -> Option<T::ViewMut<'a>>
+            where
+                T: OrderedView,
+            {
+                T::view_mut(base.get_mut(usize::try_from(self.offset).ok()?..)?, order)
+            }
+
+            /// Decode the referenced value encoded in `order` within `base`, converting it to the
+            /// machine's native byte-order.
+            ///
+            /// Returns [`None`] if `base` ends before the value does.
+            #[inline]
+            #[must_use]
+            pub fn read(self, base: &[u8], order: ByteOrder) -> Option<T>
+            where
+                T: FieldsByteOrdered + WireSafe,
+            {
+                read_field(base, usize::try_from(self.offset).ok()?, order)
+            }
+        }
+
+        impl<T> Clone for $name<T> {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T> Copy for $name<T> {}
+
+        impl<T> Default for $name<T> {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::new(0)
+            }
+        }
+
+        impl<T> PartialEq for $name<T> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.offset == other.offset
+            }
+        }
+
+        impl<T> Eq for $name<T> {}
+
+        impl<T> core::hash::Hash for $name<T> {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.offset.hash(state);
+            }
+        }
+
+        impl<T> core::fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.offset).finish()
+            }
+        }
+
+        impl<T> FieldsByteOrdered for $name<T> {
+            #[inline]
+            fn swap_field_orders(&mut self) {
+                self.offset.swap_field_orders();
+            }
+        }
+
+        // SAFETY: The offset is transparent, so it shares the layout and valid bit patterns of
+        // its integer.
+        unsafe impl<T: 'static> WireSafe for $name<T> {}
+
+        impl<T> SwapLayout for $name<T> {
+            const SWAP_FIELD_COUNT: usize = <$int as SwapLayout>::SWAP_FIELD_COUNT;
+
+            #[inline]
+            fn swap_field(index: usize) -> Option<FieldLayout> {
+                <$int as SwapLayout>::swap_field(index)
+            }
+        }
+    };
+}
+
+define_offset!(
+    /// 32-bit offset of a value of type `T` in bytes from the start of a buffer.
+    Offset32,
+    u32
+);
+
+define_offset!(
+    /// 64-bit offset of a value of type `T` in bytes from the start of a buffer.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::offset::Offset64;
+    /// use lilbig::ByteOrder;
+    ///
+    /// let file = [0xff, 0xff, 0x34, 0x12];
+    /// let length = Offset64::<u16>::new(2);
+    /// assert_eq!(length.range(), Some(2..4));
+    /// assert_eq!(length.read(&file, ByteOrder::Le), Some(0x1234));
+    /// assert_eq!(Offset64::<u16>::new(3).read(&file, ByteOrder::Le), None);
+    /// ```
+    Offset64,
+    u64
+);
@@ -0,0 +1,666 @@
+//! Wrappers of values stored in a fixed byte-order.
+//!
+//! [`Le`] and [`Be`] store their values in little-endian and big-endian byte-order respectively,
+//! regardless of the machine's native byte-order, and [`Ne`] stores its value in the machine's
+//! native byte-order. Fields of these types state the byte-order of their values within the
+//! definitions of structs, and are converted to and from the machine's native byte-order whenever
+//! their values are accessed, so a value in a foreign byte-order can't be mistaken for a native
+//! one. Each wrapper converts from the others through `From`, which decodes and re-encodes the
+//! wrapped value.
+//!
+//! [`Ordered`] generalizes the wrappers over a [marker](crate::marker) of its byte-order, such that
+//! generic code may select the byte-order of its values at compile time.
+//!
+//! Aliases of the wrappers of each integer and floating-point type, such as [`U32Be`] and
+//! `F64Le`, state the byte-order of fields concisely. Each wrapper converts from the value it
+//! wraps through `From`, and each alias converts in turn to the primitive it wraps.
+//!
+//! The wrappers implement the arithmetic and bitwise operators of the values they wrap, both
+//! between wrappers of the same byte-order and between a wrapper and a native value. Each operation
+//! decodes its operands, applies the operator, and encodes the result, so counters and lengths
+//! stored in a foreign byte-order are updated without converting them by hand.
+//!
+//! Likewise, the wrappers are compared, ordered, and hashed by their decoded values rather than by
+//! the bytes that store them, and compare equal to the native values they wrap. Sorted indexes and
+//! hash maps keyed by wrappers thereby order and find their keys as they would native values.
+//!
+//! The wrappers are formatted by `Debug` and `Display` as their decoded values. The alternate form
+//! of `Debug`, such as `{:#?}`, additionally suffixes each value with the byte-order of its
+//! wrapper, such as `258_be`.
+//!
+//! As the byte-order of such fields is fixed, swapping the byte-order of the fields of a struct
+//! leaves them unmodified.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! use lilbig::ordered::{Be, Le};
+//! use lilbig::WireSafe;
+//!
+//! let length = Be::new(0x1234_u16);
+//! assert_eq!(length.get(), 0x1234);
+//! assert_eq!(length.as_bytes(), [0x12, 0x34]);
+//!
+//! let mut offset = Le::new(0_u32);
+//! offset.set(0x12345678);
+//! assert_eq!(offset.as_bytes(), [0x78, 0x56, 0x34, 0x12]);
+//! ```
+//!
+//! Converting between byte-orders:
+//! ```
+//! use lilbig::ordered::{Be, Le, Ne};
+//! use lilbig::WireSafe;
+//!
+//! let length = Le::from_inner(0x1234_u16.to_le());
+//! let length = Be::from(length);
+//! assert_eq!(length.as_bytes(), [0x12, 0x34]);
+//! assert_eq!(Ne::from(length).into_inner(), 0x1234);
+//! ```
+//!
+//! Stating the byte-order of fields through aliases:
+//! ```
+//! use lilbig::ordered::{I32Le, U16Be, U32Be};
+//!
+//! #[repr(C)]
+//! struct Sample {
+//!     id: U32Be,
+//!     channel: U16Be,
+//!     offset: I32Le,
+//! }
+//!
+//! let sample = Sample {
+//!     id: 7.into(),
+//!     channel: U16Be::new(2),
+//!     offset: (-3).into(),
+//! };
+//! assert_eq!(u32::from(sample.id), 7);
+//! assert_eq!(sample.channel.into_inner(), 2_u16.to_be());
+//! assert_eq!(i32::from(sample.offset), -3);
+//! ```
+//!
+//! Updating a value through its wrapper:
+//! ```
+//! use lilbig::ordered::U32Be;
+//! use lilbig::WireSafe;
+//!
+//! let mut length = U32Be::new(0x100);
+//! length += 4;
+//! length = length - U32Be::new(2);
+//! assert_eq!(length.get(), 0x102);
+//! assert_eq!((length | 0x1000).as_bytes(), [0x00, 0x00, 0x11, 0x02]);
+//! ```
+//!
+//! Comparing wrappers by their decoded values:
+//! ```
+//! use lilbig::ordered::U16Le;
+//!
+//! let mut ports = [U16Le::new(443), U16Le::new(80), U16Le::new(8080)];
+//! ports.sort();
+//! assert_eq!(ports, [80, 443, 8080].map(U16Le::new));
+//! assert_eq!(ports[1], 443);
+//! assert!(U16Le::new(0x100) > U16Le::new(0x1));
+//! ```
+//!
+//! Formatting wrappers:
+//! ```
+//! use lilbig::ordered::{U16Be, U32Le};
+//!
+//! #[derive(Debug)]
+//! struct Header {
+//!     length: U32Le,
+//!     port: U16Be,
+//! }
+//!
+//! let header = Header {
+//!     length: U32Le::new(0x102),
+//!     port: U16Be::new(443),
+//! };
+//! assert_eq!(format!("{header:?}"), "Header { length: 258, port: 443 }");
+//! assert_eq!(format!("{:#?}", header.port), "443_be");
+//! assert_eq!(format!("{:x?}", header.length), "102");
+//! assert_eq!(format!("{:>5}", header.port), "  443");
+//! ```
+//!
+//! With the `zerocopy` feature enabled, the byte-order aware types of
+//! [zerocopy](https://crates.io/crates/zerocopy) convert to and from the wrappers of the same
+//! byte-order, and may be placed within structs implementing [`FieldsByteOrdered`]:
+//! ```
+//! # #[cfg(all(feature = "derive", feature = "zerocopy"))]
+//! # fn main() {
+//! use lilbig::ordered::Be;
+//! use lilbig::{ByteOrder, FieldsByteOrdered};
+//! use zerocopy::byteorder::{BigEndian, U16};
+//!
+//! #[derive(FieldsByteOrdered)]
+//! struct Header {
+//!     magic: u32,
+//!     length: U16<BigEndian>,
+//! }
+//!
+//! let mut header = Header {
+//!     magic: 0x12345678_u32.to_le(),
+//!     length: U16::new(0x0102),
+//! };
+//! header.order_fields_ne(ByteOrder::Le);
+//! assert_eq!(header.magic, 0x12345678);
+//! assert_eq!(header.length.get(), 0x0102);
+//!
+//! let length: Be<u16> = header.length.into();
+//! assert_eq!(length.get(), 0x0102);
+//! assert_eq!(U16::<BigEndian>::from(length), header.length);
+//! # }
+//! # #[cfg(not(all(feature = "derive", feature = "zerocopy")))]
+//! # fn main() {}
+//! ```
+//!
+//! With the `serde` feature enabled, the wrappers are serialized and deserialized as their decoded
+//! values:
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
+//! use lilbig::ordered::U32Be;
+//! use lilbig::WireSafe;
+//! use serde::de::value::{Error, U32Deserializer};
+//! use serde::Deserialize;
+//!
+//! let length = U32Be::deserialize(U32Deserializer::<Error>::new(0x102)).unwrap();
+//! assert_eq!(length.get(), 0x102);
+//! assert_eq!(length.as_bytes(), [0x00, 0x00, 0x01, 0x02]);
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+
+use crate::layout::{FieldLayout, SwapLayout};
+use crate::marker::OrderMarker;
+use crate::{ByteOrder, ByteOrdered, FieldsByteOrdered, WireSafe};
+use core::marker::PhantomData;
+
+/// Define a wrapper of values stored in a fixed byte-order.
+macro_rules! define_ordered {
+    ($(#[$attr: meta])* $name: ident, $order: expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy)]
+        #[repr(transparent)]
+        pub struct $name<T>(T);
+
+        impl<T: ByteOrdered> $name<T> {
+            /// Create a wrapper of `value`, which is in the machine's native byte-order.
+            #[inline]
+            #[must_use]
+            pub fn new(value: T) -> Self {
+                Self(value.ordered_as(ByteOrder::NATIVE, $order))
+            }
+
+            /// Retrieve the wrapped value in the machine's native byte-order.
+            #[inline]
+            #[must_use]
+            pub fn get(self) -> T {
+                self.0.ordered_ne($order)
+            }
+
+            /// Replace the wrapped value with `value`, which is in the machine's native byte-order.
+            #[inline]
+            pub fn set(&mut self, value: T)
+            where
+                T: Copy,
+            {
+                *self = Self::new(value);
+            }
+        }
+
+        impl<T> $name<T> {
+            /// Create a wrapper of `inner`, which is already in the wrapper's byte-order.
+            #[inline(always)]
+            #[must_use]
+            pub const fn from_inner(inner: T) -> Self {
+                Self(inner)
+            }
+
+            /// Retrieve the wrapped value without converting it from the wrapper's byte-order.
+            #[inline(always)]
+            #[must_use]
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        /// Wraps the default value of `T`, which is in the machine's native byte-order.
+        impl<T: ByteOrdered + Default> Default for $name<T> {
+            #[inline]
+            fn default() -> Self {
+                Self::new(T::default())
+            }
+        }
+
+        /// Provided for completeness. The byte-order of the wrapped value is fixed thus this
+        /// function applies no modifications to `self`.
+        impl<T> FieldsByteOrdered for $name<T> {
+            const IS_ORDER_INVARIANT: bool = true;
+
+            #[inline(always)]
+            fn swap_field_orders(&mut self) {}
+        }
+
+        // SAFETY: The wrapper is transparent, so it shares the layout and valid bit patterns of
+        // the wrapped type.
+        unsafe impl<T: WireSafe> WireSafe for $name<T> {}
+
+        /// The byte-order of the wrapped value is fixed thus the wrapper has no fields.
+        impl<T> SwapLayout for $name<T> {
+            const SWAP_FIELD_COUNT: usize = 0;
+
+            #[inline]
+            fn swap_field(_index: usize) -> Option<FieldLayout> {
+                None
+            }
+        }
+    };
+}
+
+define_ordered!(
+    /// Wrapper of a value stored in little-endian byte-order.
+    Le,
+    ByteOrder::Le
+);
+
+define_ordered!(
+    /// Wrapper of a value stored in big-endian byte-order.
+    Be,
+    ByteOrder::Be
+);
+
+define_ordered!(
+    /// Wrapper of a value stored in the machine's native byte-order.
+    Ne,
+    ByteOrder::NATIVE
+);
+
+/// Implement conversions between wrappers of different byte-orders.
+macro_rules! convert_ordered {
+    ($($from: ident => $to: ident),+ $(,)?) => {
+        $(
+            impl<T: ByteOrdered> From<$from<T>> for $to<T> {
+                #[inline]
+                fn from(value: $from<T>) -> Self {
+                    Self::new(value.get())
+                }
+            }
+        )+
+    };
+}
+
+convert_ordered!(Le => Be, Le => Ne, Be => Le, Be => Ne, Ne => Le, Ne => Be);
+
+/// Implement conversions between each wrapper and the value it wraps.
+macro_rules! convert_wrapped {
+    ($($name: ident),+) => {
+        $(
+            impl<T: ByteOrdered> From<T> for $name<T> {
+                #[inline]
+                fn from(value: T) -> Self {
+                    Self::new(value)
+                }
+            }
+        )+
+    };
+}
+
+convert_wrapped!(Le, Be, Ne);
+
+/// Define aliases of the wrappers of a primitive type, along with conversions to the primitive.
+macro_rules! define_aliases {
+    ($($ty: ident => $le: ident, $be: ident;)+) => {
+        $(
+            #[doc = concat!("`", stringify!($ty), "` stored in little-endian byte-order.")]
+            pub type $le = Le<$ty>;
+
+            #[doc = concat!("`", stringify!($ty), "` stored in big-endian byte-order.")]
+            pub type $be = Be<$ty>;
+
+            impl From<$le> for $ty {
+                #[inline]
+                fn from(value: $le) -> Self {
+                    value.get()
+                }
+            }
+
+            impl From<$be> for $ty {
+                #[inline]
+                fn from(value: $be) -> Self {
+                    value.get()
+                }
+            }
+
+            impl PartialEq<$le> for $ty {
+                #[inline]
+                fn eq(&self, other: &$le) -> bool {
+                    *self == other.get()
+                }
+            }
+
+            impl PartialEq<$be> for $ty {
+                #[inline]
+                fn eq(&self, other: &$be) -> bool {
+                    *self == other.get()
+                }
+            }
+        )+
+    };
+}
+
+define_aliases! {
+    u16 => U16Le, U16Be;
+    u32 => U32Le, U32Be;
+    u64 => U64Le, U64Be;
+    u128 => U128Le, U128Be;
+    i16 => I16Le, I16Be;
+    i32 => I32Le, I32Be;
+    i64 => I64Le, I64Be;
+    i128 => I128Le, I128Be;
+}
+
+#[cfg(not(feature = "no-float"))]
+define_aliases! {
+    f32 => F32Le, F32Be;
+    f64 => F64Le, F64Be;
+}
+
+/// Wrapper of a value stored in the byte-order marked by `O`.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::marker::{BigEndian, LittleEndian, OrderMarker};
+/// use lilbig::ordered::Ordered;
+/// use lilbig::WireSafe;
+///
+/// fn encode<O: OrderMarker>(value: u16) -> Ordered<u16, O> {
+///     Ordered::new(value)
+/// }
+///
+/// assert_eq!(encode::<BigEndian>(0x1234).as_bytes(), [0x12, 0x34]);
+/// let length = encode::<LittleEndian>(0x1234);
+/// assert_eq!(length.as_bytes(), [0x34, 0x12]);
+/// assert_eq!(length.reordered::<BigEndian>().as_bytes(), [0x12, 0x34]);
+/// assert_eq!(length.get(), 0x1234);
+/// ```
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Ordered<T, O: OrderMarker> {
+    /// Wrapped value in the byte-order marked by `O`.
+    value: T,
+    /// Marker of the byte-order of the wrapped value.
+    marker: PhantomData<O>,
+}
+
+impl<T: ByteOrdered, O: OrderMarker> Ordered<T, O> {
+    /// Create a wrapper of `value`, which is in the machine's native byte-order.
+    #[inline]
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self::from_inner(value.ordered_as(ByteOrder::NATIVE, O::ORDER))
+    }
+
+    /// Retrieve the wrapped value in the machine's native byte-order.
+    #[inline]
+    #[must_use]
+    pub fn get(self) -> T {
+        self.value.ordered_ne(O::ORDER)
+    }
+
+    /// Replace the wrapped value with `value`, which is in the machine's native byte-order.
+    #[inline]
+    pub fn set(&mut self, value: T)
+    where
+        T: Copy,
+    {
+        *self = Self::new(value);
+    }
+
+    /// Convert the wrapper to a wrapper of the byte-order marked by `P`.
+    #[inline]
+    #[must_use]
+    pub fn reordered<P: OrderMarker>(self) -> Ordered<T, P> {
+        Ordered::from_inner(self.value.ordered_as(O::ORDER, P::ORDER))
+    }
+}
+
+impl<T, O: OrderMarker> Ordered<T, O> {
+    /// Create a wrapper of `inner`, which is already in the wrapper's byte-order.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_inner(inner: T) -> Self {
+        Self {
+            value: inner,
+            marker: PhantomData,
+        }
+    }
+
+    /// Retrieve the wrapped value without converting it from the wrapper's byte-order.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+/// Wraps the default value of `T`, which is in the machine's native byte-order.
+impl<T: ByteOrdered + Default, O: OrderMarker> Default for Ordered<T, O> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/// Provided for completeness. The byte-order of the wrapped value is fixed thus this function
+/// applies no modifications to `self`.
+impl<T, O: OrderMarker> FieldsByteOrdered for Ordered<T, O> {
+    const IS_ORDER_INVARIANT: bool = true;
+
+    #[inline(always)]
+    fn swap_field_orders(&mut self) {}
+}
+
+// SAFETY: The wrapper is transparent, so it shares the layout and valid bit patterns of the
+// wrapped type.
+unsafe impl<T: WireSafe, O: OrderMarker> WireSafe for Ordered<T, O> {}
+
+/// The byte-order of the wrapped value is fixed thus the wrapper has no fields.
+impl<T, O: OrderMarker> SwapLayout for Ordered<T, O> {
+    const SWAP_FIELD_COUNT: usize = 0;
+
+    #[inline]
+    fn swap_field(_index: usize) -> Option<FieldLayout> {
+        None
+    }
+}
+
+/// Implement conversions between [`Ordered`] and the wrappers of each byte-order.
+macro_rules! convert_marked {
+    ($($name: ident),+) => {
+        $(
+            impl<T: ByteOrdered, O: OrderMarker> From<$name<T>> for Ordered<T, O> {
+                #[inline]
+                fn from(value: $name<T>) -> Self {
+                    Self::new(value.get())
+                }
+            }
+
+            impl<T: ByteOrdered, O: OrderMarker> From<Ordered<T, O>> for $name<T> {
+                #[inline]
+                fn from(value: Ordered<T, O>) -> Self {
+                    Self::new(value.get())
+                }
+            }
+        )+
+    };
+}
+
+convert_marked!(Le, Be, Ne);
+
+/// Implement a binary operator for wrappers by decoding the operands and encoding the result.
+macro_rules! impl_binary_op {
+    ($op: ident, $method: ident, $op_assign: ident, $method_assign: ident; $([$($params: tt)*] $name: ty),+) => {
+        $(
+            impl<T: ByteOrdered + core::ops::$op<Output = T>, $($params)*> core::ops::$op for $name {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    Self::new(core::ops::$op::$method(self.get(), rhs.get()))
+                }
+            }
+
+            impl<T: ByteOrdered + core::ops::$op<Output = T>, $($params)*> core::ops::$op<T> for $name {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: T) -> Self {
+                    Self::new(core::ops::$op::$method(self.get(), rhs))
+                }
+            }
+
+            impl<T: ByteOrdered + Copy + core::ops::$op<Output = T>, $($params)*> core::ops::$op_assign
+                for $name
+            {
+                #[inline]
+                fn $method_assign(&mut self, rhs: Self) {
+                    *self = core::ops::$op::$method(*self, rhs);
+                }
+            }
+
+            impl<T: ByteOrdered + Copy + core::ops::$op<Output = T>, $($params)*> core::ops::$op_assign<T>
+                for $name
+            {
+                #[inline]
+                fn $method_assign(&mut self, rhs: T) {
+                    *self = core::ops::$op::$method(*self, rhs);
+                }
+            }
+        )+
+    };
+}
+
+/// Implement a unary operator for wrappers by decoding the operand and encoding the result.
+macro_rules! impl_unary_op {
+    ($op: ident, $method: ident; $([$($params: tt)*] $name: ty),+) => {
+        $(
+            impl<T: ByteOrdered + core::ops::$op<Output = T>, $($params)*> core::ops::$op for $name {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self) -> Self {
+                    Self::new(core::ops::$op::$method(self.get()))
+                }
+            }
+        )+
+    };
+}
+
+/// Implement the arithmetic and bitwise operators for wrappers.
+macro_rules! impl_ops {
+    ($($wrappers: tt)+) => {
+        impl_binary_op!(Add, add, AddAssign, add_assign; $($wrappers)+);
+        impl_binary_op!(Sub, sub, SubAssign, sub_assign; $($wrappers)+);
+        impl_binary_op!(Mul, mul, MulAssign, mul_assign; $($wrappers)+);
+        impl_binary_op!(Div, div, DivAssign, div_assign; $($wrappers)+);
+        impl_binary_op!(Rem, rem, RemAssign, rem_assign; $($wrappers)+);
+        impl_binary_op!(BitAnd, bitand, BitAndAssign, bitand_assign; $($wrappers)+);
+        impl_binary_op!(BitOr, bitor, BitOrAssign, bitor_assign; $($wrappers)+);
+        impl_binary_op!(BitXor, bitxor, BitXorAssign, bitxor_assign; $($wrappers)+);
+        impl_unary_op!(Neg, neg; $($wrappers)+);
+        impl_unary_op!(Not, not; $($wrappers)+);
+    };
+}
+
+impl_ops!([] Le<T>, [] Be<T>, [] Ne<T>, [O: OrderMarker] Ordered<T, O>);
+
+/// Implement comparisons and hashing of wrappers by their decoded values.
+macro_rules! impl_comparisons {
+    ($([$($params: tt)*] $name: ty),+) => {
+        $(
+            impl<T: ByteOrdered + Copy + PartialEq, $($params)*> PartialEq for $name {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.get() == other.get()
+                }
+            }
+
+            impl<T: ByteOrdered + Copy + PartialEq, $($params)*> PartialEq<T> for $name {
+                #[inline]
+                fn eq(&self, other: &T) -> bool {
+                    self.get() == *other
+                }
+            }
+
+            impl<T: ByteOrdered + Copy + Eq, $($params)*> Eq for $name {}
+
+            impl<T: ByteOrdered + Copy + PartialOrd, $($params)*> PartialOrd for $name {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    self.get().partial_cmp(&other.get())
+                }
+            }
+
+            impl<T: ByteOrdered + Copy + PartialOrd, $($params)*> PartialOrd<T> for $name {
+                #[inline]
+                fn partial_cmp(&self, other: &T) -> Option<core::cmp::Ordering> {
+                    self.get().partial_cmp(other)
+                }
+            }
+
+            impl<T: ByteOrdered + Copy + Ord, $($params)*> Ord for $name {
+                #[inline]
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    self.get().cmp(&other.get())
+                }
+            }
+
+            impl<T: ByteOrdered + Copy + core::hash::Hash, $($params)*> core::hash::Hash for $name {
+                #[inline]
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    self.get().hash(state);
+                }
+            }
+        )+
+    };
+}
+
+impl_comparisons!([] Le<T>, [] Be<T>, [] Ne<T>, [O: OrderMarker] Ordered<T, O>);
+
+/// Implement the formatting of wrappers as their decoded values.
+macro_rules! impl_fmt {
+    ($([$($params: tt)*] $name: ty => $suffix: expr),+) => {
+        $(
+            impl<T: ByteOrdered + Copy + core::fmt::Debug, $($params)*> core::fmt::Debug for $name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::Debug::fmt(&self.get(), f)?;
+                    if f.alternate() {
+                        f.write_str($suffix)?;
+                    }
+                    Ok(())
+                }
+            }
+
+            impl<T: ByteOrdered + Copy + core::fmt::Display, $($params)*> core::fmt::Display
+                for $name
+            {
+                #[inline]
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::Display::fmt(&self.get(), f)
+                }
+            }
+        )+
+    };
+}
+
+impl_fmt!(
+    [] Le<T> => "_le",
+    [] Be<T> => "_be",
+    [] Ne<T> => "_ne",
+    [O: OrderMarker] Ordered<T, O> => match O::ORDER {
+        ByteOrder::Le => "_le",
+        ByteOrder::Be => "_be",
+    }
+);
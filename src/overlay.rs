@@ -0,0 +1,41 @@
+//! Swapping of overlays, which are types laid over the leading bytes of a buffer.
+//!
+//! C-style tagged unions store one of several layouts within the same bytes, with a discriminant
+//! selecting the layout that is present. Such bytes are swapped by [`swap_overlay()`] as the layout
+//! selected by the discriminant. With the `derive` feature enabled, the `tag` and `match` attributes
+//! of `#[derive(FieldsByteOrdered)]` generate such swaps for a field of a struct.
+//!
+//! # Examples
+//! Swapping a tagged union:
+//! ```
+//! use lilbig::overlay::swap_overlay;
+//!
+//! let kind = 1;
+//! let mut body = [0x12, 0x34, 0x56, 0x78, 0xff, 0xff];
+//! let swapped = match kind {
+//!     0 => swap_overlay::<u16>(&mut body),
+//!     1 => swap_overlay::<u32>(&mut body),
+//!     _ => false,
+//! };
+//! assert!(swapped);
+//! assert_eq!(body, [0x78, 0x56, 0x34, 0x12, 0xff, 0xff]);
+//! ```
+
+use crate::{FieldsByteOrdered, WireSafe};
+
+/// Swap the byte-order of the fields of `T` laid over the leading bytes of `bytes`, leaving any
+/// trailing bytes unmodified.
+///
+/// The value is copied out of `bytes` and back, so `bytes` need not be aligned for `T`. Returns
+/// `false` without modifying `bytes` if `bytes` is too short to hold a value of `T`.
+pub fn swap_overlay<T: FieldsByteOrdered + WireSafe>(bytes: &mut [u8]) -> bool {
+    let Some(bytes) = bytes.get_mut(..core::mem::size_of::<T>()) else {
+        return false;
+    };
+    let Some(mut value) = T::from_bytes(bytes) else {
+        return false;
+    };
+    value.swap_field_orders();
+    bytes.copy_from_slice(value.as_bytes());
+    true
+}
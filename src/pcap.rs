@@ -0,0 +1,333 @@
+//! PCAP and pcapng capture file header structs.
+//!
+//! Capture files are written in the byte-order of the machine that captured them, which readers
+//! detect through a magic number at the start of the file (or of each section, for pcapng). The
+//! `read()` functions of the headers within this module perform that detection and return the
+//! detected byte-order alongside the header converted to the machine's native byte-order.
+//!
+//! # Examples
+//! Reading the packets of a classic PCAP file written by a big-endian machine:
+//! ```
+//! use lilbig::pcap::{PcapGlobalHeader, Records};
+//! use lilbig::ByteOrder;
+//!
+//! let file = [
+//!     // Global header.
+//!     0xa1, 0xb2, 0xc3, 0xd4, 0x00, 0x02, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
+//!     // Record header and packet data.
+//!     0x64, 0xcd, 0x5c, 0x80, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+//!     0x3c, 0xbe, 0xef,
+//! ];
+//!
+//! let (header, order, rest) = PcapGlobalHeader::read(&file).unwrap();
+//! assert_eq!(order, ByteOrder::Be);
+//! assert_eq!((header.version_major, header.version_minor), (2, 4));
+//! assert_eq!(header.snaplen, 0xffff);
+//! assert!(!header.is_nanosecond_resolution());
+//!
+//! let mut records = Records::new(rest, order);
+//! let (record, data) = records.next().unwrap();
+//! assert_eq!(record.ts_sec, 0x64cd5c80);
+//! assert_eq!(record.ts_usec, 42);
+//! assert_eq!(record.orig_len, 60);
+//! assert_eq!(data, [0xbe, 0xef]);
+//! assert!(records.next().is_none());
+//! ```
+
+use crate::headers::{impl_header, read_ordered};
+use crate::ByteOrder;
+
+/// Magic number of a classic PCAP file with microsecond resolution timestamps.
+pub const PCAP_MAGIC_MICROS: u32 = 0xa1b2c3d4;
+
+/// Magic number of a classic PCAP file with nanosecond resolution timestamps.
+pub const PCAP_MAGIC_NANOS: u32 = 0xa1b23c4d;
+
+/// Block type of a pcapng section header block.
+pub const PCAPNG_SECTION_HEADER_TYPE: u32 = 0x0a0d0d0a;
+
+/// Block type of a pcapng interface description block.
+pub const PCAPNG_INTERFACE_DESCRIPTION_TYPE: u32 = 0x00000001;
+
+/// Block type of a pcapng enhanced packet block.
+pub const PCAPNG_ENHANCED_PACKET_TYPE: u32 = 0x00000006;
+
+/// Byte-order magic number of a pcapng section header block.
+pub const PCAPNG_BYTE_ORDER_MAGIC: u32 = 0x1a2b3c4d;
+
+/// Global header of a classic PCAP file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct PcapGlobalHeader {
+    /// Magic number identifying the file's byte-order and timestamp resolution.
+    pub magic_number: u32,
+    /// Major version of the file format.
+    pub version_major: u16,
+    /// Minor version of the file format.
+    pub version_minor: u16,
+    /// Correction in seconds between GMT and the local timezone of the timestamps.
+    pub thiszone: i32,
+    /// Accuracy of the timestamps.
+    pub sigfigs: u32,
+    /// Maximum length of captured packets in bytes.
+    pub snaplen: u32,
+    /// Link-layer header type of the captured packets.
+    pub network: u32,
+}
+
+impl_header!(PcapGlobalHeader {
+    magic_number,
+    version_major,
+    version_minor,
+    thiszone,
+    sigfigs,
+    snaplen,
+    network,
+});
+
+impl PcapGlobalHeader {
+    /// Read a global header from the start of `bytes`, detecting the file's byte-order from its
+    /// magic number.
+    ///
+    /// Returns the header in the machine's native byte-order, the detected byte-order of the file,
+    /// and the bytes that follow the header. Returns [`None`] if `bytes` is too short or does not
+    /// begin with a recognized magic number.
+    #[must_use]
+    pub fn read(bytes: &[u8]) -> Option<(Self, ByteOrder, &[u8])> {
+        let magic = u32::from_ne_bytes(*bytes.first_chunk()?);
+        let order = ByteOrder::detect_by_magic(PCAP_MAGIC_MICROS, magic)
+            .or_else(|| ByteOrder::detect_by_magic(PCAP_MAGIC_NANOS, magic))?;
+        let (header, rest) = read_ordered(bytes, order)?;
+        Some((header, order, rest))
+    }
+
+    /// Check whether the file's record timestamps have nanosecond resolution rather than
+    /// microsecond resolution.
+    #[inline]
+    #[must_use]
+    pub fn is_nanosecond_resolution(&self) -> bool {
+        self.magic_number == PCAP_MAGIC_NANOS
+    }
+}
+
+/// Header of a packet record within a classic PCAP file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct PcapRecordHeader {
+    /// Timestamp seconds.
+    pub ts_sec: u32,
+    /// Timestamp microseconds or nanoseconds, depending upon the file's magic number.
+    pub ts_usec: u32,
+    /// Length of the packet data captured within the file.
+    pub incl_len: u32,
+    /// Length of the packet when it was captured.
+    pub orig_len: u32,
+}
+
+impl_header!(PcapRecordHeader {
+    ts_sec,
+    ts_usec,
+    incl_len,
+    orig_len,
+});
+
+impl PcapRecordHeader {
+    /// Read a record header encoded in `order` from the start of `bytes`.
+    ///
+    /// Returns the header in the machine's native byte-order along with the bytes that follow it,
+    /// or [`None`] if `bytes` is too short.
+    #[must_use]
+    pub fn read(bytes: &[u8], order: ByteOrder) -> Option<(Self, &[u8])> {
+        read_ordered(bytes, order)
+    }
+}
+
+/// Iterator over the packet records of a classic PCAP file.
+///
+/// Yields the header of each record in the machine's native byte-order along with its captured
+/// packet data. Iteration ends upon reaching the end of the file or a truncated record.
+#[derive(Debug, Clone)]
+pub struct Records<'a> {
+    /// The bytes of the file following the last yielded record.
+    bytes: &'a [u8],
+    /// The byte-order of the file.
+    order: ByteOrder,
+}
+
+impl<'a> Records<'a> {
+    /// Create an iterator over the records within `bytes`, the bytes following a file's global
+    /// header, which are encoded in `order`.
+    #[inline]
+    #[must_use]
+    pub const fn new(bytes: &'a [u8], order: ByteOrder) -> Self {
+        Self { bytes, order }
+    }
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = (PcapRecordHeader, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (header, rest) = PcapRecordHeader::read(self.bytes, self.order)?;
+        let (data, rest) = rest.split_at_checked(usize::try_from(header.incl_len).ok()?)?;
+        self.bytes = rest;
+        Some((header, data))
+    }
+}
+
+/// Header common to all pcapng blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct PcapngBlockHeader {
+    /// Type of the block.
+    pub block_type: u32,
+    /// Length of the entire block in bytes.
+    pub block_total_length: u32,
+}
+
+impl_header!(PcapngBlockHeader {
+    block_type,
+    block_total_length,
+});
+
+impl PcapngBlockHeader {
+    /// Read a block header encoded in `order` from the start of `bytes`.
+    ///
+    /// Returns the header in the machine's native byte-order along with the bytes that follow it,
+    /// or [`None`] if `bytes` is too short.
+    #[must_use]
+    pub fn read(bytes: &[u8], order: ByteOrder) -> Option<(Self, &[u8])> {
+        read_ordered(bytes, order)
+    }
+}
+
+/// Fixed portion of a pcapng section header block.
+///
+/// # Examples
+/// Detecting the byte-order of a section:
+/// ```
+/// use lilbig::pcap::PcapngSectionHeader;
+/// use lilbig::ByteOrder;
+///
+/// let section = [
+///     0x0a, 0x0d, 0x0d, 0x0a, 0x1c, 0x00, 0x00, 0x00, 0x4d, 0x3c, 0x2b, 0x1a, 0x01, 0x00, 0x00,
+///     0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x1c, 0x00, 0x00, 0x00,
+/// ];
+///
+/// let (header, order, _) = PcapngSectionHeader::read(&section).unwrap();
+/// assert_eq!(order, ByteOrder::Le);
+/// assert_eq!(header.block_total_length, 28);
+/// assert_eq!((header.major_version, header.minor_version), (1, 0));
+/// assert_eq!(header.section_length, -1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct PcapngSectionHeader {
+    /// Type of the block, which is always [`PCAPNG_SECTION_HEADER_TYPE`].
+    pub block_type: u32,
+    /// Length of the entire block in bytes.
+    pub block_total_length: u32,
+    /// Magic number identifying the section's byte-order.
+    pub byte_order_magic: u32,
+    /// Major version of the file format.
+    pub major_version: u16,
+    /// Minor version of the file format.
+    pub minor_version: u16,
+    /// Length of the section in bytes excluding this block, or `-1` if unspecified.
+    pub section_length: i64,
+}
+
+impl_header!(PcapngSectionHeader {
+    block_type,
+    block_total_length,
+    byte_order_magic,
+    major_version,
+    minor_version,
+    section_length,
+});
+
+impl PcapngSectionHeader {
+    /// Read a section header block from the start of `bytes`, detecting the section's byte-order
+    /// from its byte-order magic number.
+    ///
+    /// Returns the header in the machine's native byte-order, the detected byte-order of the
+    /// section, and the bytes that follow the fixed portion of the header. Returns [`None`] if
+    /// `bytes` is too short, is not a section header block, or contains an unrecognized byte-order
+    /// magic number.
+    #[must_use]
+    pub fn read(bytes: &[u8]) -> Option<(Self, ByteOrder, &[u8])> {
+        let block_type = u32::from_ne_bytes(*bytes.first_chunk()?);
+        if block_type != PCAPNG_SECTION_HEADER_TYPE {
+            return None;
+        }
+        let magic = u32::from_ne_bytes(*bytes.get(8..)?.first_chunk()?);
+        let order = ByteOrder::detect_by_magic(PCAPNG_BYTE_ORDER_MAGIC, magic)?;
+        let (header, rest) = read_ordered(bytes, order)?;
+        Some((header, order, rest))
+    }
+}
+
+/// Fixed portion of a pcapng interface description block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct PcapngInterfaceDescription {
+    /// Type of the block, which is always [`PCAPNG_INTERFACE_DESCRIPTION_TYPE`].
+    pub block_type: u32,
+    /// Length of the entire block in bytes.
+    pub block_total_length: u32,
+    /// Link-layer header type of the interface.
+    pub link_type: u16,
+    /// Reserved.
+    pub reserved: u16,
+    /// Maximum length of captured packets in bytes.
+    pub snap_len: u32,
+}
+
+impl_header!(PcapngInterfaceDescription {
+    block_type,
+    block_total_length,
+    link_type,
+    reserved,
+    snap_len,
+});
+
+/// Fixed portion of a pcapng enhanced packet block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct PcapngEnhancedPacket {
+    /// Type of the block, which is always [`PCAPNG_ENHANCED_PACKET_TYPE`].
+    pub block_type: u32,
+    /// Length of the entire block in bytes.
+    pub block_total_length: u32,
+    /// Index of the interface the packet was captured upon.
+    pub interface_id: u32,
+    /// Upper 32 bits of the timestamp.
+    pub timestamp_high: u32,
+    /// Lower 32 bits of the timestamp.
+    pub timestamp_low: u32,
+    /// Length of the packet data captured within the block.
+    pub captured_length: u32,
+    /// Length of the packet when it was captured.
+    pub original_length: u32,
+}
+
+impl_header!(PcapngEnhancedPacket {
+    block_type,
+    block_total_length,
+    interface_id,
+    timestamp_high,
+    timestamp_low,
+    captured_length,
+    original_length,
+});
+
+impl PcapngEnhancedPacket {
+    /// Retrieve the block's 64-bit timestamp, whose units are specified by the interface
+    /// description.
+    #[inline]
+    #[must_use]
+    pub const fn timestamp(&self) -> u64 {
+        (self.timestamp_high as u64) << 32 | self.timestamp_low as u64
+    }
+}
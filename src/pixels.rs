@@ -0,0 +1,174 @@
+//! Conversion of pixel buffers between byte-orders and channel orders.
+//!
+//! Texture and asset pipelines frequently convert pixel buffers between byte-orders, such as from
+//! the big-endian 16-bit samples of a PNG to the little-endian samples expected by a GPU, while also
+//! reordering their channels, such as from RGBA to BGRA. The functions in this module perform both
+//! transforms in a single pass over the buffer.
+//!
+//! Channels are reordered by a swizzle, an array holding for each channel of the converted pixels
+//! the index of the channel of the original pixels that it is taken from. Swizzles between common
+//! channel orders are provided as constants.
+//!
+//! # Examples
+//! Converting RGBA16 pixels in big-endian byte-order to BGRA16 pixels in little-endian byte-order:
+//! ```
+//! use lilbig::pixels::{self, PixelError, RGBA_TO_BGRA};
+//! use lilbig::ByteOrder;
+//!
+//! let mut pixels = [
+//!     0x11, 0x12, 0x21, 0x22, 0x31, 0x32, 0x41, 0x42,
+//!     0x51, 0x52, 0x61, 0x62, 0x71, 0x72, 0x81, 0x82,
+//! ];
+//! pixels::try_convert_pixels(&mut pixels, 2, RGBA_TO_BGRA, ByteOrder::Be, ByteOrder::Le)?;
+//! assert_eq!(pixels, [
+//!     0x32, 0x31, 0x22, 0x21, 0x12, 0x11, 0x42, 0x41,
+//!     0x72, 0x71, 0x62, 0x61, 0x52, 0x51, 0x82, 0x81,
+//! ]);
+//! # Ok::<(), PixelError>(())
+//! ```
+
+use crate::{ByteOrder, WidthError};
+
+/// Swizzle leaving the order of channels unmodified.
+pub const RGBA_TO_RGBA: [usize; 4] = [0, 1, 2, 3];
+/// Swizzle converting RGBA pixels to BGRA pixels.
+pub const RGBA_TO_BGRA: [usize; 4] = [2, 1, 0, 3];
+/// Swizzle converting BGRA pixels to RGBA pixels.
+pub const BGRA_TO_RGBA: [usize; 4] = [2, 1, 0, 3];
+/// Swizzle converting RGBA pixels to ARGB pixels.
+pub const RGBA_TO_ARGB: [usize; 4] = [3, 0, 1, 2];
+/// Swizzle converting ARGB pixels to RGBA pixels.
+pub const ARGB_TO_RGBA: [usize; 4] = [1, 2, 3, 0];
+/// Swizzle converting RGB pixels to BGR pixels.
+pub const RGB_TO_BGR: [usize; 3] = [2, 1, 0];
+
+/// Maximum width of a channel in bytes.
+pub const MAX_CHANNEL_WIDTH: usize = 16;
+
+/// Error returned when converting a pixel buffer fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PixelError {
+    /// The width of the channels or pixels was invalid.
+    Width(WidthError),
+    /// The width of the channels exceeded [`MAX_CHANNEL_WIDTH`].
+    UnsupportedWidth,
+    /// The swizzle referred to a channel that pixels do not have.
+    InvalidChannel,
+}
+
+impl PixelError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::Width(err) => err.message(),
+            Self::UnsupportedWidth => "channel width must not exceed 16 bytes",
+            Self::InvalidChannel => "swizzle refers to a channel that pixels do not have",
+        }
+    }
+}
+
+impl core::fmt::Display for PixelError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for PixelError {}
+
+impl From<WidthError> for PixelError {
+    #[inline]
+    fn from(err: WidthError) -> Self {
+        Self::Width(err)
+    }
+}
+
+/// Convert each pixel of `bytes` from `current_order` to `new_order` while reordering its `N`
+/// channels, each of which is `channel_width` bytes wide, according to `swizzle`.
+///
+/// # Panics
+/// Panics if `channel_width` or `N` is zero, if `channel_width` exceeds [`MAX_CHANNEL_WIDTH`], if
+/// the length of `bytes` is not a multiple of the width of a pixel, or if `swizzle` holds an index
+/// that is not less than `N`. See [`try_convert_pixels()`] for a non-panicking alternative.
+#[cfg(not(feature = "no-panic"))]
+pub fn convert_pixels<const N: usize>(
+    bytes: &mut [u8],
+    channel_width: usize,
+    swizzle: [usize; N],
+    current_order: ByteOrder,
+    new_order: ByteOrder,
+) {
+    if let Err(err) = try_convert_pixels(bytes, channel_width, swizzle, current_order, new_order) {
+        panic!("{err}");
+    }
+}
+
+/// Convert each pixel of `bytes` from `current_order` to `new_order` while reordering its `N`
+/// channels, each of which is `channel_width` bytes wide, according to `swizzle`.
+///
+/// This is the non-panicking alternative of `convert_pixels()`.
+///
+/// # Errors
+/// Returns [`PixelError::Width`] if `channel_width` or `N` is zero or if the length of `bytes` is
+/// not a multiple of the width of a pixel, [`PixelError::UnsupportedWidth`] if `channel_width`
+/// exceeds [`MAX_CHANNEL_WIDTH`], or [`PixelError::InvalidChannel`] if `swizzle` holds an index
+/// that is not less than `N`. The contents of `bytes` are left unmodified upon error.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::pixels::{try_convert_pixels, PixelError, RGB_TO_BGR};
+/// use lilbig::ByteOrder;
+///
+/// let mut pixels = [0x10, 0x20, 0x30, 0x40, 0x50, 0x60];
+/// let result = try_convert_pixels(&mut pixels, 1, RGB_TO_BGR, ByteOrder::Le, ByteOrder::Be);
+/// assert_eq!(result, Ok(()));
+/// assert_eq!(pixels, [0x30, 0x20, 0x10, 0x60, 0x50, 0x40]);
+///
+/// let result = try_convert_pixels(&mut pixels, 1, [0, 3, 1], ByteOrder::Le, ByteOrder::Le);
+/// assert_eq!(result, Err(PixelError::InvalidChannel));
+/// ```
+pub fn try_convert_pixels<const N: usize>(
+    bytes: &mut [u8],
+    channel_width: usize,
+    swizzle: [usize; N],
+    current_order: ByteOrder,
+    new_order: ByteOrder,
+) -> Result<(), PixelError> {
+    if channel_width == 0 || N == 0 {
+        return Err(WidthError::Zero.into());
+    }
+    if channel_width > MAX_CHANNEL_WIDTH {
+        return Err(PixelError::UnsupportedWidth);
+    }
+    let pixel_width = channel_width * N;
+    if !bytes.len().is_multiple_of(pixel_width) {
+        return Err(WidthError::IndivisibleLength.into());
+    }
+    if swizzle.iter().any(|&source| source >= N) {
+        return Err(PixelError::InvalidChannel);
+    }
+
+    let swap = current_order != new_order;
+    let mut channels = [[0u8; MAX_CHANNEL_WIDTH]; N];
+    for pixel in bytes.chunks_exact_mut(pixel_width) {
+        for (channel, source) in channels.iter_mut().zip(pixel.chunks_exact(channel_width)) {
+            if let Some(channel) = channel.get_mut(..channel_width) {
+                channel.copy_from_slice(source);
+            }
+        }
+        for (destination, &source) in pixel.chunks_exact_mut(channel_width).zip(&swizzle) {
+            let channel = channels
+                .get(source)
+                .and_then(|channel| channel.get(..channel_width));
+            if let Some(channel) = channel {
+                destination.copy_from_slice(channel);
+                if swap {
+                    destination.reverse();
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,178 @@
+//! Heuristic detection of values whose byte-order was converted twice.
+//!
+//! Converting data that is already in the machine's native byte-order as though it were not
+//! produces values that are usually implausible, such as lengths in the gigabytes or timestamps
+//! centuries away. Plausibility predicates registered against a type are run after each
+//! conversion performed by [`CheckedFieldsByteOrdered::order_fields_ne_checked()`], flagging
+//! values that fail a predicate. Values that only satisfy the predicate before their conversion
+//! are flagged as likely double conversions.
+//!
+//! The predicates are shared by all threads, and are intended for use during development rather
+//! than as a means of validating untrusted data.
+//!
+//! # Examples
+//! Catching a double conversion:
+//! ```
+//! use lilbig::plausibility::{self, CheckedFieldsByteOrdered};
+//! use lilbig::{ByteOrder, FieldsByteOrdered};
+//!
+//! #[derive(Debug, Clone)]
+//! struct Chunk {
+//!     length: u32,
+//! }
+//!
+//! impl FieldsByteOrdered for Chunk {
+//!     fn swap_field_orders(&mut self) {
+//!         self.length.swap_field_orders();
+//!     }
+//! }
+//!
+//! plausibility::register::<Chunk>("length is less than 1 MiB", |chunk| chunk.length < 1 << 20);
+//!
+//! let mut chunk = Chunk { length: 0x1000_u32.to_be() };
+//! assert_eq!(chunk.order_fields_ne_checked(ByteOrder::Be), Ok(()));
+//!
+//! // Converting the chunk again as though it were still big-endian.
+//! let err = chunk.order_fields_ne_checked(ByteOrder::NATIVE_OPPOSITE).unwrap_err();
+//! assert_eq!(err.predicate(), "length is less than 1 MiB");
+//! assert!(err.is_likely_double_conversion());
+//! ```
+
+use crate::{ByteOrder, FieldsByteOrdered};
+use core::any::{Any, TypeId};
+use std::boxed::Box;
+use std::collections::BTreeMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::vec::Vec;
+
+/// Plausibility predicate whose type is erased such that predicates of all types may be stored
+/// together. Values of types other than that of the predicate always satisfy it.
+type ErasedCheck = dyn Fn(&dyn Any) -> bool + Send + Sync;
+
+/// Registered plausibility predicate.
+struct Predicate {
+    /// Description of the predicate.
+    name: &'static str,
+    /// The predicate.
+    check: Box<ErasedCheck>,
+}
+
+/// Predicates of each type that has registered any.
+static PREDICATES: Mutex<BTreeMap<TypeId, Vec<Predicate>>> = Mutex::new(BTreeMap::new());
+
+/// Lock the predicates, disregarding any poisoning as the predicates are always left consistent.
+fn lock() -> MutexGuard<'static, BTreeMap<TypeId, Vec<Predicate>>> {
+    PREDICATES.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Register a plausibility predicate described by `name` that values of `T` are expected to
+/// satisfy after being converted to the machine's native byte-order.
+pub fn register<T: 'static>(name: &'static str, predicate: fn(&T) -> bool) {
+    let check = move |value: &dyn Any| value.downcast_ref::<T>().is_none_or(predicate);
+    lock()
+        .entry(TypeId::of::<T>())
+        .or_default()
+        .push(Predicate {
+            name,
+            check: Box::new(check),
+        });
+}
+
+/// Remove every registered predicate of every type.
+pub fn clear() {
+    lock().clear();
+}
+
+/// Check `value` against each predicate registered for `T`.
+///
+/// # Errors
+/// Returns an [`Implausible`] describing the first predicate that `value` fails. The error never
+/// reports a likely double conversion, as no value prior to a conversion is known.
+pub fn check<T: 'static>(value: &T) -> Result<(), Implausible> {
+    check_conversion(value, None)
+}
+
+/// Check `converted` against each predicate registered for `T`, reporting a likely double
+/// conversion if `original` satisfies a predicate that `converted` fails.
+fn check_conversion<T: 'static>(converted: &T, original: Option<&T>) -> Result<(), Implausible> {
+    let predicates = lock();
+    let Some(predicates) = predicates.get(&TypeId::of::<T>()) else {
+        return Ok(());
+    };
+    for predicate in predicates {
+        if !(predicate.check)(converted) {
+            return Err(Implausible {
+                type_name: core::any::type_name::<T>(),
+                predicate: predicate.name,
+                double_conversion: original.is_some_and(|original| (predicate.check)(original)),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Error returned when a value fails a plausibility predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Implausible {
+    /// Name of the type of the value.
+    type_name: &'static str,
+    /// Description of the failed predicate.
+    predicate: &'static str,
+    /// Whether the value satisfied the predicate prior to its conversion.
+    double_conversion: bool,
+}
+
+impl Implausible {
+    /// Retrieve the name of the type of the implausible value.
+    #[inline]
+    #[must_use]
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Retrieve the description of the predicate that the value failed.
+    #[inline]
+    #[must_use]
+    pub const fn predicate(&self) -> &'static str {
+        self.predicate
+    }
+
+    /// Check whether the value satisfied the predicate prior to its conversion, which suggests that
+    /// the value was already in the machine's native byte-order.
+    #[inline]
+    #[must_use]
+    pub const fn is_likely_double_conversion(&self) -> bool {
+        self.double_conversion
+    }
+}
+
+impl core::fmt::Display for Implausible {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} failed \"{}\"", self.type_name, self.predicate)?;
+        if self.double_conversion {
+            f.write_str(", likely due to a double conversion")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for Implausible {}
+
+/// Extension trait converting values to the machine's native byte-order and checking the results
+/// against the registered plausibility predicates.
+pub trait CheckedFieldsByteOrdered: FieldsByteOrdered + Clone + 'static {
+    /// Perform [`FieldsByteOrdered::order_fields_ne()`], then check `self` against each predicate
+    /// registered for its type.
+    ///
+    /// # Errors
+    /// Returns an [`Implausible`] describing the first predicate that the converted value fails.
+    /// The conversion is performed regardless.
+    fn order_fields_ne_checked(&mut self, current_order: ByteOrder) -> Result<(), Implausible> {
+        let original = self.clone();
+        self.order_fields_ne(current_order);
+        let original = (current_order != ByteOrder::NATIVE).then_some(&original);
+        check_conversion(self, original)
+    }
+}
+
+impl<T: FieldsByteOrdered + Clone + 'static> CheckedFieldsByteOrdered for T {}
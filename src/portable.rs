@@ -0,0 +1,97 @@
+//! Explicit-width conversions for pointer-sized integers.
+//!
+//! The width of [`usize`] and [`isize`] varies between targets, so a pointer-sized value written by
+//! one target cannot be safely read by a target of a different width by merely swapping its
+//! byte-order. Wire formats instead declare a fixed width for such values, and the functions within
+//! this module convert between that declared width and the machine's pointer-sized integers while
+//! applying the wire format's byte-order.
+//!
+//! The `strict-usize` feature removes the crate's implementations for [`usize`] and [`isize`]
+//! entirely so that all handling of pointer-sized values must be routed through this module.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! use lilbig::portable::{usize_from_wire, usize_to_wire};
+//! use lilbig::ByteOrder;
+//!
+//! // A length declared by the wire format as a big-endian 32-bit integer.
+//! let wire: u32 = usize_to_wire(1024, ByteOrder::Be).unwrap();
+//! assert_eq!(wire, 1024u32.to_be());
+//! assert_eq!(usize_from_wire(wire, ByteOrder::Be), Ok(1024));
+//!
+//! // Lengths too large for the declared width are rejected rather than truncated.
+//! # #[cfg(target_pointer_width = "64")]
+//! assert!(usize_to_wire::<u32>(usize::MAX, ByteOrder::Be).is_err());
+//! ```
+
+use crate::{ByteOrder, ByteOrdered};
+
+/// Convert `value`, a wire integer encoded in `order`, to a [`usize`].
+///
+/// # Errors
+/// Returns an error if the decoded value cannot be represented by a [`usize`] on the compilation
+/// target.
+#[inline]
+pub fn usize_from_wire<W>(value: W, order: ByteOrder) -> Result<usize, W::Error>
+where
+    W: ByteOrdered + TryInto<usize>,
+{
+    value.ordered_ne(order).try_into()
+}
+
+/// Convert `value` to a wire integer of type `W` encoded in `order`.
+///
+/// # Errors
+/// Returns an error if `value` cannot be represented by `W`.
+#[inline]
+pub fn usize_to_wire<W>(value: usize, order: ByteOrder) -> Result<W, W::Error>
+where
+    W: ByteOrdered + TryFrom<usize>,
+{
+    W::try_from(value).map(|wire| wire.ordered_as(ByteOrder::NATIVE, order))
+}
+
+/// Convert `value`, a wire integer encoded in `order`, to an [`isize`].
+///
+/// # Errors
+/// Returns an error if the decoded value cannot be represented by an [`isize`] on the compilation
+/// target.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::portable::isize_from_wire;
+/// use lilbig::ByteOrder;
+///
+/// assert_eq!(isize_from_wire((-5i64).to_le(), ByteOrder::Le), Ok(-5));
+/// ```
+#[inline]
+pub fn isize_from_wire<W>(value: W, order: ByteOrder) -> Result<isize, W::Error>
+where
+    W: ByteOrdered + TryInto<isize>,
+{
+    value.ordered_ne(order).try_into()
+}
+
+/// Convert `value` to a wire integer of type `W` encoded in `order`.
+///
+/// # Errors
+/// Returns an error if `value` cannot be represented by `W`.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::portable::isize_to_wire;
+/// use lilbig::ByteOrder;
+///
+/// assert_eq!(isize_to_wire::<i16>(-5, ByteOrder::Be), Ok((-5i16).to_be()));
+/// assert!(isize_to_wire::<i16>(isize::MAX, ByteOrder::Be).is_err());
+/// ```
+#[inline]
+pub fn isize_to_wire<W>(value: isize, order: ByteOrder) -> Result<W, W::Error>
+where
+    W: ByteOrdered + TryFrom<isize>,
+{
+    W::try_from(value).map(|wire| wire.ordered_as(ByteOrder::NATIVE, order))
+}
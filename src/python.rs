@@ -0,0 +1,199 @@
+//! Python bindings of the crate's bulk and layout-driven swapping utilities.
+//!
+//! The functions operate upon objects supporting Python's buffer protocol, such as `bytes`,
+//! `bytearray`, `memoryview`, and NumPy arrays. Writable, C-contiguous buffers are swapped in place
+//! without being copied, while the `swapped_` functions return swapped copies of any C-contiguous
+//! buffer as `bytes`.
+//!
+//! The functions are added to a Python module by [`register()`], which is intended to be called
+//! from the `#[pymodule]` of an extension module built with [maturin](https://www.maturin.rs/):
+//! ```ignore
+//! use pyo3::prelude::*;
+//!
+//! #[pymodule]
+//! fn byteswap(module: &Bound<'_, PyModule>) -> PyResult<()> {
+//!     lilbig::python::register(module)
+//! }
+//! ```
+//!
+//! See [`register()`] for the functions being called from Python.
+
+use crate::bulk;
+use crate::layout::{FieldLayout, LayoutError, TypeLayout};
+use crate::WidthError;
+use pyo3::buffer::PyUntypedBuffer;
+use pyo3::exceptions::{PyBufferError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use std::string::ToString;
+use std::vec::Vec;
+
+/// Add the crate's functions to `module`.
+///
+/// # Errors
+/// Returns an error if a function could not be added to `module`.
+///
+/// # Examples
+/// Calling the functions from an embedded interpreter:
+/// ```
+/// use pyo3::prelude::*;
+/// use pyo3::types::PyDict;
+///
+/// Python::initialize();
+/// Python::attach(|py| {
+///     let module = PyModule::new(py, "lilbig")?;
+///     lilbig::python::register(&module)?;
+///     let globals = PyDict::new(py);
+///     globals.set_item("lilbig", module)?;
+///     py.run(
+///         cr#"
+/// samples = bytearray(b"\x00\x01\x00\x02")
+/// lilbig.swap_elements(samples, 2)
+/// assert samples == b"\x01\x00\x02\x00"
+///
+/// record = lilbig.swapped_records(b"\x01\x02\x03\x04\x05\x06\x07\x08", 8, [(0, 4), (4, 2)])
+/// assert record == b"\x04\x03\x02\x01\x06\x05\x07\x08"
+///
+/// try:
+///     lilbig.swap_elements(b"\x00\x01", 2)
+/// except BufferError:
+///     pass
+/// else:
+///     raise AssertionError("read-only buffer was swapped")
+///
+/// try:
+///     lilbig.swapped_records(b"\x01\x02\x03\x04", 4, [(0, 4), (2, 2)])
+/// except ValueError:
+///     pass
+/// else:
+///     raise AssertionError("overlapping fields were swapped")
+/// "#,
+///         Some(&globals),
+///         None,
+///     )
+/// })?;
+/// # Ok::<(), PyErr>(())
+/// ```
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(swap_elements, module)?)?;
+    module.add_function(wrap_pyfunction!(swapped_elements, module)?)?;
+    module.add_function(wrap_pyfunction!(swap_records, module)?)?;
+    module.add_function(wrap_pyfunction!(swapped_records, module)?)?;
+    Ok(())
+}
+
+/// Swap the byte-order of each element of `width` bytes within the writable `buffer` in place.
+#[pyfunction]
+fn swap_elements(buffer: &Bound<'_, PyAny>, width: usize) -> PyResult<()> {
+    with_writable_bytes(buffer, |bytes| bulk::try_swap_elements(bytes, width))
+}
+
+/// Retrieve a copy of `buffer` with the byte-order of each element of `width` bytes swapped.
+#[pyfunction]
+fn swapped_elements<'py>(
+    buffer: &Bound<'py, PyAny>,
+    width: usize,
+) -> PyResult<Bound<'py, PyBytes>> {
+    swapped_copy(buffer, |bytes| bulk::try_swap_elements(bytes, width))
+}
+
+/// Swap the byte-order of the `fields` of each record of `size` bytes within the writable `buffer`
+/// in place, where each field is given as a tuple of its offset and width in bytes.
+///
+/// Raises a `ValueError` if a field extends beyond the end of a record, overlaps another field, or
+/// is of an unsupported width.
+#[pyfunction]
+fn swap_records(
+    buffer: &Bound<'_, PyAny>,
+    size: usize,
+    fields: Vec<(usize, usize)>,
+) -> PyResult<()> {
+    let fields = field_layouts(fields);
+    let layout = TypeLayout::try_new(size, &fields).map_err(layout_error)?;
+    with_writable_bytes(buffer, |bytes| layout.try_swap_records(bytes))
+}
+
+/// Retrieve a copy of `buffer` with the byte-order of the `fields` of each record of `size` bytes
+/// swapped, where each field is given as a tuple of its offset and width in bytes.
+///
+/// Raises a `ValueError` if a field extends beyond the end of a record, overlaps another field, or
+/// is of an unsupported width.
+#[pyfunction]
+fn swapped_records<'py>(
+    buffer: &Bound<'py, PyAny>,
+    size: usize,
+    fields: Vec<(usize, usize)>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let fields = field_layouts(fields);
+    let layout = TypeLayout::try_new(size, &fields).map_err(layout_error)?;
+    swapped_copy(buffer, |bytes| layout.try_swap_records(bytes))
+}
+
+/// Convert fields given as tuples of their offsets and widths to [`FieldLayout`]s.
+fn field_layouts(fields: Vec<(usize, usize)>) -> Vec<FieldLayout> {
+    fields
+        .into_iter()
+        .map(|(offset, width)| FieldLayout::new(offset, width))
+        .collect()
+}
+
+/// Convert a [`WidthError`] to a Python `ValueError`.
+fn width_error(err: WidthError) -> PyErr {
+    PyValueError::new_err(err.message())
+}
+
+/// Convert a [`LayoutError`] to a Python `ValueError`.
+fn layout_error(err: LayoutError) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+/// Retrieve the C-contiguous buffer of `object`.
+fn contiguous_buffer(object: &Bound<'_, PyAny>) -> PyResult<PyUntypedBuffer> {
+    let buffer = PyUntypedBuffer::get(object)?;
+    if !buffer.is_c_contiguous() {
+        return Err(PyBufferError::new_err("buffer is not C-contiguous"));
+    }
+    Ok(buffer)
+}
+
+/// Pass the bytes of the writable buffer of `object` to `swap`.
+fn with_writable_bytes(
+    object: &Bound<'_, PyAny>,
+    swap: impl FnOnce(&mut [u8]) -> Result<(), WidthError>,
+) -> PyResult<()> {
+    let buffer = contiguous_buffer(object)?;
+    if buffer.readonly() {
+        return Err(PyBufferError::new_err("buffer is read-only"));
+    }
+    let len = buffer.len_bytes();
+    let bytes = if len == 0 {
+        &mut []
+    } else {
+        // SAFETY: The buffer is writable and C-contiguous, so its `len` bytes starting at its
+        // pointer may be written. The buffer keeps its memory alive until it is released, and no
+        // Python code that could access the memory runs while the slice exists.
+        unsafe { core::slice::from_raw_parts_mut(buffer.buf_ptr().cast::<u8>(), len) }
+    };
+    swap(bytes).map_err(width_error)
+}
+
+/// Create `bytes` holding a copy of the buffer of `object` that is passed to `swap`.
+fn swapped_copy<'py>(
+    object: &Bound<'py, PyAny>,
+    swap: impl FnOnce(&mut [u8]) -> Result<(), WidthError>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let buffer = contiguous_buffer(object)?;
+    let len = buffer.len_bytes();
+    let source = if len == 0 {
+        &[]
+    } else {
+        // SAFETY: The buffer is C-contiguous, so its `len` bytes starting at its pointer may be
+        // read. The buffer keeps its memory alive until it is released, and no Python code that
+        // could modify the memory runs while the slice exists.
+        unsafe { core::slice::from_raw_parts(buffer.buf_ptr().cast::<u8>(), len) }
+    };
+    PyBytes::new_with(object.py(), len, |bytes| {
+        bytes.copy_from_slice(source);
+        swap(bytes).map_err(width_error)
+    })
+}
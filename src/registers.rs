@@ -0,0 +1,10 @@
+//! Byte-order correcting adapters for memory-mapped register crates.
+//!
+//! Peripherals attached to a bus of a different byte-order than the processor expose registers
+//! whose values must be swapped upon every access. The adapters within this module apply that
+//! correction at the access layer so that existing register definitions can remain unchanged.
+
+#[cfg(feature = "tock-registers")]
+pub mod tock;
+#[cfg(feature = "volatile-register")]
+pub mod volatile;
@@ -0,0 +1,97 @@
+//! Adapters for the register types of [`tock-registers`](::tock_registers).
+
+use crate::{ByteOrder, ByteOrdered};
+use ::tock_registers::interfaces::{Readable, Writeable};
+
+/// Adapter over a [`tock-registers`](::tock_registers) register whose raw value is encoded in a
+/// byte-order other than the machine's native byte-order.
+///
+/// The adapter itself implements [`Readable`] and [`Writeable`], so the full field-level interface
+/// of the register (such as [`Readable::read()`], [`Readable::is_set()`], and
+/// [`ReadWriteable::modify()`](::tock_registers::interfaces::ReadWriteable::modify)) operates upon
+/// values in the machine's native byte-order.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::registers::tock::OrderedRegister;
+/// use lilbig::ByteOrder;
+/// use tock_registers::interfaces::{Readable, Writeable};
+/// use tock_registers::registers::InMemoryRegister;
+/// use tock_registers::register_bitfields;
+///
+/// register_bitfields![u32,
+///     STATUS [
+///         READY OFFSET(0) NUMBITS(1) [],
+///         COUNT OFFSET(8) NUMBITS(8) [],
+///     ],
+/// ];
+///
+/// // A register belonging to a big-endian peripheral.
+/// let register: InMemoryRegister<u32, STATUS::Register> = InMemoryRegister::new(0);
+/// let status = OrderedRegister::new(&register, ByteOrder::Be);
+///
+/// status.write(STATUS::READY::SET + STATUS::COUNT.val(0x2a));
+/// assert_eq!(register.get(), 0x2a01u32.to_be());
+/// assert!(status.is_set(STATUS::READY));
+/// assert_eq!(status.read(STATUS::COUNT), 0x2a);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OrderedRegister<'r, R: ?Sized> {
+    /// The adapted register.
+    register: &'r R,
+    /// The byte-order of the adapted register's raw value.
+    order: ByteOrder,
+}
+
+impl<'r, R: ?Sized> OrderedRegister<'r, R> {
+    /// Create an adapter over `register` whose raw value is encoded in `order`.
+    #[inline]
+    #[must_use]
+    pub const fn new(register: &'r R, order: ByteOrder) -> Self {
+        Self { register, order }
+    }
+
+    /// Retrieve the adapted register.
+    #[inline]
+    #[must_use]
+    pub const fn register(&self) -> &'r R {
+        self.register
+    }
+
+    /// Retrieve the byte-order of the adapted register's raw value.
+    #[inline]
+    #[must_use]
+    pub const fn order(&self) -> ByteOrder {
+        self.order
+    }
+}
+
+impl<R: Readable + ?Sized> Readable for OrderedRegister<'_, R>
+where
+    R::T: ByteOrdered,
+{
+    type T = R::T;
+    type R = R::R;
+
+    /// Get the raw register value converted to the machine's native byte-order.
+    #[inline]
+    fn get(&self) -> Self::T {
+        self.register.get().ordered_ne(self.order)
+    }
+}
+
+impl<R: Writeable + ?Sized> Writeable for OrderedRegister<'_, R>
+where
+    R::T: ByteOrdered,
+{
+    type T = R::T;
+    type R = R::R;
+
+    /// Set the raw register value after converting it from the machine's native byte-order.
+    #[inline]
+    fn set(&self, value: Self::T) {
+        self.register
+            .set(value.ordered_as(ByteOrder::NATIVE, self.order));
+    }
+}
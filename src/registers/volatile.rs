@@ -0,0 +1,103 @@
+//! Extension traits for the register types of [`volatile-register`](::volatile_register).
+
+use crate::{ByteOrder, ByteOrdered};
+use ::volatile_register::{RO, RW, WO};
+
+/// Extension trait for reading registers whose value is encoded in a specified byte-order.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::registers::volatile::{ReadOrdered, WriteOrdered};
+/// use lilbig::ByteOrder;
+/// use volatile_register::RW;
+///
+/// // A register belonging to a little-endian peripheral.
+/// let register: RW<u32> = unsafe { core::mem::transmute(0x12345678u32.to_le()) };
+/// assert_eq!(register.read_ordered(ByteOrder::Le), 0x12345678);
+///
+/// unsafe { register.write_ordered(0xcafe, ByteOrder::Le) };
+/// assert_eq!(register.read(), 0xcafeu32.to_le());
+/// ```
+pub trait ReadOrdered {
+    /// The type of the register's value.
+    type Value: ByteOrdered;
+
+    /// Read the register's value, which is encoded in `order`, converting it to the machine's
+    /// native byte-order.
+    #[must_use]
+    fn read_ordered(&self, order: ByteOrder) -> Self::Value;
+}
+
+/// Extension trait for writing registers whose value is encoded in a specified byte-order.
+pub trait WriteOrdered {
+    /// The type of the register's value.
+    type Value: ByteOrdered;
+
+    /// Convert `value` from the machine's native byte-order to `order` and write it to the
+    /// register.
+    ///
+    /// # Safety
+    /// Writes to a register are side effectful. See the documentation of the underlying register
+    /// type's `write()` function.
+    unsafe fn write_ordered(&self, value: Self::Value, order: ByteOrder);
+}
+
+/// Extension trait for performing read-modify-write operations upon registers whose value is
+/// encoded in a specified byte-order.
+pub trait ModifyOrdered: ReadOrdered + WriteOrdered<Value = <Self as ReadOrdered>::Value> {
+    /// Read the register's value converted to the machine's native byte-order, pass it to `f`, and
+    /// write the value returned by `f` back to the register encoded in `order`.
+    ///
+    /// # Safety
+    /// Writes to a register are side effectful. See the documentation of the underlying register
+    /// type's `modify()` function.
+    #[inline]
+    unsafe fn modify_ordered<F>(&self, order: ByteOrder, f: F)
+    where
+        F: FnOnce(<Self as ReadOrdered>::Value) -> <Self as ReadOrdered>::Value,
+    {
+        // SAFETY: The caller upholds the safety requirements of the register's write.
+        unsafe { self.write_ordered(f(self.read_ordered(order)), order) }
+    }
+}
+
+impl<T: Copy + ByteOrdered> ReadOrdered for RO<T> {
+    type Value = T;
+
+    #[inline]
+    fn read_ordered(&self, order: ByteOrder) -> T {
+        self.read().ordered_ne(order)
+    }
+}
+
+impl<T: Copy + ByteOrdered> ReadOrdered for RW<T> {
+    type Value = T;
+
+    #[inline]
+    fn read_ordered(&self, order: ByteOrder) -> T {
+        self.read().ordered_ne(order)
+    }
+}
+
+impl<T: Copy + ByteOrdered> WriteOrdered for RW<T> {
+    type Value = T;
+
+    #[inline]
+    unsafe fn write_ordered(&self, value: T, order: ByteOrder) {
+        // SAFETY: The caller upholds the safety requirements of the register's write.
+        unsafe { self.write(value.ordered_as(ByteOrder::NATIVE, order)) }
+    }
+}
+
+impl<T: Copy + ByteOrdered> WriteOrdered for WO<T> {
+    type Value = T;
+
+    #[inline]
+    unsafe fn write_ordered(&self, value: T, order: ByteOrder) {
+        // SAFETY: The caller upholds the safety requirements of the register's write.
+        unsafe { self.write(value.ordered_as(ByteOrder::NATIVE, order)) }
+    }
+}
+
+impl<T: Copy + ByteOrdered> ModifyOrdered for RW<T> {}
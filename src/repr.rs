@@ -0,0 +1,191 @@
+//! Fieldless enums stored through their integer representation.
+//!
+//! C-style enums declared with `#[repr(u32)]` or a similar integer representation are commonly
+//! stored directly in file headers. Such enums may not implement [`FieldsByteOrdered`] themselves,
+//! as the byte-order swapped discriminant of a variant is rarely the discriminant of another
+//! variant. [`ReprOrdered`] instead converts an enum to and from its integer representation,
+//! validating the integer upon conversion, and [`OrderedRepr`] holds the integer within structs
+//! such that it is swapped along with the struct's other fields.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # fn main() {
+//! use lilbig::repr::{OrderedRepr, ReprOrdered};
+//! use lilbig::{ByteOrder, FieldsByteOrdered};
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, ReprOrdered)]
+//! #[repr(u32)]
+//! enum Compression {
+//!     None = 0,
+//!     Deflate = 8,
+//! }
+//!
+//! #[derive(FieldsByteOrdered)]
+//! #[repr(C)]
+//! struct Header {
+//!     length: u32,
+//!     compression: OrderedRepr<Compression>,
+//! }
+//!
+//! let mut header = Header {
+//!     length: 0x100_u32.to_be(),
+//!     compression: OrderedRepr::from_repr(8_u32.to_be()),
+//! };
+//! header.order_fields_ne(ByteOrder::Be);
+//! assert_eq!(header.length, 0x100);
+//! assert_eq!(header.compression.get(), Some(Compression::Deflate));
+//!
+//! header.compression = OrderedRepr::from_repr(3);
+//! assert_eq!(header.compression.get(), None);
+//! # }
+//! # #[cfg(not(feature = "derive"))]
+//! # fn main() {}
+//! ```
+
+use crate::layout::{FieldLayout, SwapLayout};
+use crate::{FieldsByteOrdered, WireSafe};
+use core::marker::PhantomData;
+
+#[cfg(feature = "derive")]
+pub use lilbig_derive::ReprOrdered;
+
+/// Conversion of a fieldless enum to and from its integer representation.
+///
+/// With the `derive` feature enabled, this trait may be derived for fieldless enums with an
+/// explicit integer representation, such as `#[repr(u32)]`.
+///
+/// # Examples
+/// Implementing the trait manually:
+/// ```
+/// use lilbig::repr::ReprOrdered;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// #[repr(u8)]
+/// enum Class {
+///     Elf32 = 1,
+///     Elf64 = 2,
+/// }
+///
+/// impl ReprOrdered for Class {
+///     type Repr = u8;
+///
+///     fn to_repr(self) -> u8 {
+///         self as u8
+///     }
+///
+///     fn from_repr(repr: u8) -> Option<Self> {
+///         match repr {
+///             1 => Some(Self::Elf32),
+///             2 => Some(Self::Elf64),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Class::from_repr(Class::Elf64.to_repr()), Some(Class::Elf64));
+/// assert_eq!(Class::from_repr(3), None);
+/// ```
+pub trait ReprOrdered: Copy + Sized {
+    /// Integer representation of the enum.
+    type Repr: FieldsByteOrdered + WireSafe;
+
+    /// Convert the enum to its integer representation.
+    #[must_use]
+    fn to_repr(self) -> Self::Repr;
+
+    /// Convert the integer representation `repr` to the enum, or [`None`] if `repr` is not the
+    /// discriminant of any of its variants.
+    #[must_use]
+    fn from_repr(repr: Self::Repr) -> Option<Self>;
+}
+
+/// Fieldless enum held through its integer representation.
+///
+/// The integer is held in whatever byte-order the enclosing struct is in, and is swapped along
+/// with the struct's other fields. The integer is only validated when converted to the enum by
+/// [`OrderedRepr::get()`], which should thus be done while the integer is in the machine's native
+/// byte-order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct OrderedRepr<E: ReprOrdered> {
+    /// Integer representation of the enum.
+    repr: E::Repr,
+    /// Marker of the enum represented by the integer.
+    marker: PhantomData<E>,
+}
+
+impl<E: ReprOrdered> OrderedRepr<E> {
+    /// Create a holder of `value`, whose integer representation is in the machine's native
+    /// byte-order.
+    #[inline]
+    #[must_use]
+    pub fn new(value: E) -> Self {
+        Self::from_repr(value.to_repr())
+    }
+
+    /// Create a holder of the integer representation `repr`, which is not validated.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_repr(repr: E::Repr) -> Self {
+        Self {
+            repr,
+            marker: PhantomData,
+        }
+    }
+
+    /// Retrieve the held integer representation.
+    #[inline(always)]
+    #[must_use]
+    pub fn repr(self) -> E::Repr {
+        self.repr
+    }
+
+    /// Retrieve the enum represented by the held integer, or [`None`] if the integer is not the
+    /// discriminant of any of its variants.
+    #[inline]
+    #[must_use]
+    pub fn get(self) -> Option<E> {
+        E::from_repr(self.repr)
+    }
+
+    /// Replace the held integer with the integer representation of `value`, which is in the
+    /// machine's native byte-order.
+    #[inline]
+    pub fn set(&mut self, value: E) {
+        *self = Self::new(value);
+    }
+}
+
+impl<E: ReprOrdered> From<E> for OrderedRepr<E> {
+    #[inline]
+    fn from(value: E) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<E: ReprOrdered> FieldsByteOrdered for OrderedRepr<E> {
+    const IS_ORDER_INVARIANT: bool = E::Repr::IS_ORDER_INVARIANT;
+
+    #[inline]
+    fn swap_field_orders(&mut self) {
+        self.repr.swap_field_orders();
+    }
+}
+
+// SAFETY: The holder is transparent, so it shares the layout and valid bit patterns of the
+// integer representation.
+unsafe impl<E: ReprOrdered + 'static> WireSafe for OrderedRepr<E> {}
+
+impl<E: ReprOrdered> SwapLayout for OrderedRepr<E>
+where
+    E::Repr: SwapLayout,
+{
+    const SWAP_FIELD_COUNT: usize = E::Repr::SWAP_FIELD_COUNT;
+
+    #[inline]
+    fn swap_field(index: usize) -> Option<FieldLayout> {
+        E::Repr::swap_field(index)
+    }
+}
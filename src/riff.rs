@@ -0,0 +1,187 @@
+//! RIFF and RIFX container chunk structs.
+//!
+//! RIFF files (such as WAV and AVI files) are encoded in little-endian, whereas RIFX files are
+//! their big-endian counterpart. The byte-order of a file is identified by the ID of its outermost
+//! chunk, which [`RiffHeader::read()`] detects. The detected byte-order may then be used to iterate
+//! over the file's chunks with [`Chunks`] and to read the contents of those chunks.
+//!
+//! # Examples
+//! Converting the samples of a big-endian RIFX WAV file to the machine's native byte-order:
+//! ```
+//! use lilbig::riff::{Chunks, FmtChunk, RiffHeader};
+//! use lilbig::ByteOrder;
+//!
+//! let file = [
+//!     b'R', b'I', b'F', b'X', 0x00, 0x00, 0x00, 0x28, b'W', b'A', b'V', b'E', // Header.
+//!     b'f', b'm', b't', b' ', 0x00, 0x00, 0x00, 0x10, // Format chunk.
+//!     0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x1f, 0x40, 0x00, 0x00, 0x3e, 0x80, 0x00, 0x02, 0x00,
+//!     0x10, //
+//!     b'd', b'a', b't', b'a', 0x00, 0x00, 0x00, 0x04, // Data chunk.
+//!     0x12, 0x34, 0x56, 0x78,
+//! ];
+//!
+//! let (header, order, body) = RiffHeader::read(&file).unwrap();
+//! assert_eq!(order, ByteOrder::Be);
+//! assert_eq!(&header.form, b"WAVE");
+//!
+//! let mut format = None;
+//! let mut samples = Vec::new();
+//! for (chunk, data) in Chunks::new(body, order) {
+//!     match &chunk.id {
+//!         b"fmt " => format = FmtChunk::read(data, order),
+//!         b"data" => samples.extend_from_slice(data),
+//!         _ => {}
+//!     }
+//! }
+//!
+//! let format = format.unwrap();
+//! assert_eq!(format.sample_rate, 8000);
+//! assert_eq!(format.bits_per_sample, 16);
+//!
+//! // Convert the samples from the file's byte-order to the machine's native byte-order.
+//! if order != ByteOrder::NATIVE {
+//!     lilbig::bulk::try_swap_elements(&mut samples, usize::from(format.bits_per_sample / 8))?;
+//! }
+//! assert_eq!(samples, [0x1234u16.to_ne_bytes(), 0x5678u16.to_ne_bytes()].concat());
+//! # Ok::<(), lilbig::WidthError>(())
+//! ```
+
+use crate::headers::{impl_header, read_ordered};
+use crate::ByteOrder;
+
+/// Header of the outermost chunk of a RIFF or RIFX file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct RiffHeader {
+    /// ID of the chunk, which is either `RIFF` or `RIFX`.
+    pub id: [u8; 4],
+    /// Size of the chunk's data in bytes, including the form type.
+    pub size: u32,
+    /// Form type of the file, such as `WAVE` or `AVI `.
+    pub form: [u8; 4],
+}
+
+impl_header!(RiffHeader { id, size, form });
+
+impl RiffHeader {
+    /// Read a header from the start of `bytes`, detecting the file's byte-order from the chunk's
+    /// ID.
+    ///
+    /// Returns the header in the machine's native byte-order, the detected byte-order of the file,
+    /// and the file's subchunks. The subchunks are truncated to the size given by the header if
+    /// `bytes` extends beyond it. Returns [`None`] if `bytes` is too short or is not a RIFF or RIFX
+    /// file.
+    #[must_use]
+    pub fn read(bytes: &[u8]) -> Option<(Self, ByteOrder, &[u8])> {
+        let order = match bytes.first_chunk()? {
+            b"RIFF" => ByteOrder::Le,
+            b"RIFX" => ByteOrder::Be,
+            _ => return None,
+        };
+        let (header, rest) = read_ordered::<Self>(bytes, order)?;
+        let body_size = usize::try_from(header.size).ok()?.saturating_sub(4);
+        Some((header, order, rest.get(..body_size).unwrap_or(rest)))
+    }
+}
+
+/// Header of a chunk within a RIFF or RIFX file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct ChunkHeader {
+    /// ID of the chunk.
+    pub id: [u8; 4],
+    /// Size of the chunk's data in bytes, excluding the pad byte of chunks of odd size.
+    pub size: u32,
+}
+
+impl_header!(ChunkHeader { id, size });
+
+impl ChunkHeader {
+    /// Read a header encoded in `order` from the start of `bytes`.
+    ///
+    /// Returns the header in the machine's native byte-order along with the bytes that follow it,
+    /// or [`None`] if `bytes` is too short.
+    #[must_use]
+    pub fn read(bytes: &[u8], order: ByteOrder) -> Option<(Self, &[u8])> {
+        read_ordered(bytes, order)
+    }
+}
+
+/// Contents of the format chunk (`fmt `) of a WAV file, excluding any extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct FmtChunk {
+    /// Format of the samples, such as `1` for integer PCM or `3` for floating point PCM.
+    pub audio_format: u16,
+    /// Number of channels.
+    pub num_channels: u16,
+    /// Number of sample frames per second.
+    pub sample_rate: u32,
+    /// Number of bytes per second.
+    pub byte_rate: u32,
+    /// Size of a sample frame in bytes.
+    pub block_align: u16,
+    /// Number of bits per sample.
+    pub bits_per_sample: u16,
+}
+
+impl_header!(FmtChunk {
+    audio_format,
+    num_channels,
+    sample_rate,
+    byte_rate,
+    block_align,
+    bits_per_sample,
+});
+
+impl FmtChunk {
+    /// Read a format chunk's contents encoded in `order` from the start of `bytes`.
+    ///
+    /// Returns the contents in the machine's native byte-order, or [`None`] if `bytes` is too
+    /// short.
+    #[must_use]
+    pub fn read(bytes: &[u8], order: ByteOrder) -> Option<Self> {
+        Some(read_ordered(bytes, order)?.0)
+    }
+}
+
+/// Iterator over the chunks of a RIFF or RIFX file.
+///
+/// Yields the header of each chunk in the machine's native byte-order along with the chunk's data.
+/// The pad byte following chunks of odd size is skipped. Iteration ends upon reaching the end of
+/// the bytes or a truncated chunk.
+#[derive(Debug, Clone)]
+pub struct Chunks<'a> {
+    /// The bytes following the last yielded chunk.
+    bytes: &'a [u8],
+    /// The byte-order of the file.
+    order: ByteOrder,
+}
+
+impl<'a> Chunks<'a> {
+    /// Create an iterator over the chunks within `bytes`, which are encoded in `order`.
+    ///
+    /// Chunks that contain subchunks (such as `LIST` chunks) may be iterated over by creating an
+    /// iterator over their data following their list type.
+    #[inline]
+    #[must_use]
+    pub const fn new(bytes: &'a [u8], order: ByteOrder) -> Self {
+        Self { bytes, order }
+    }
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = (ChunkHeader, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (header, rest) = ChunkHeader::read(self.bytes, self.order)?;
+        let size = usize::try_from(header.size).ok()?;
+        let (data, rest) = rest.split_at_checked(size)?;
+        self.bytes = if size % 2 == 1 {
+            rest.get(1..).unwrap_or_default()
+        } else {
+            rest
+        };
+        Some((header, data))
+    }
+}
@@ -0,0 +1,297 @@
+//! Packing and unpacking of sample streams narrower than 16 bits.
+//!
+//! ADCs and image sensors commonly capture samples of 10, 12, or 14 bits and pack them into a
+//! continuous stream of bits without padding. How the bits of the samples are laid out within the
+//! stream's bytes is given by a [`ByteOrder`]:
+//!
+//! * [`ByteOrder::Be`] - Samples fill each byte from its most significant bit, as if the stream
+//!   were one big-endian integer. Two 12-bit samples `0xabc` and `0xdef` pack to `[0xab, 0xcd,
+//!   0xef]`.
+//! * [`ByteOrder::Le`] - Samples fill each byte from its least significant bit, as if the stream
+//!   were one little-endian integer. Two 12-bit samples `0xabc` and `0xdef` pack to `[0xbc, 0xfa,
+//!   0xde]`.
+//!
+//! Unpacked samples are held in `u16` values in the machine's native byte-order, aligned to either
+//! end of the `u16` as given by an [`Alignment`].
+//!
+//! # Examples
+//! Unpacking 12-bit samples:
+//! ```
+//! use lilbig::samples::{self, Alignment, SampleError};
+//! use lilbig::ByteOrder;
+//!
+//! let packed = [0xab, 0xcd, 0xef, 0x12, 0x34, 0x56];
+//! let mut unpacked = [0u16; 4];
+//! samples::try_unpack_samples(&packed, 12, ByteOrder::Be, Alignment::Low, &mut unpacked)?;
+//! assert_eq!(unpacked, [0xabc, 0xdef, 0x123, 0x456]);
+//!
+//! let mut repacked = [0u8; 6];
+//! samples::try_pack_samples(&unpacked, 12, ByteOrder::Le, Alignment::Low, &mut repacked)?;
+//! assert_eq!(repacked, [0xbc, 0xfa, 0xde, 0x23, 0x61, 0x45]);
+//! # Ok::<(), SampleError>(())
+//! ```
+
+use crate::ByteOrder;
+
+/// Alignment of the bits of a sample within an unpacked `u16`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    /// The sample occupies the least significant bits, such that its value is unscaled.
+    Low,
+    /// The sample occupies the most significant bits, such that its value is scaled to the full
+    /// range of a `u16`.
+    High,
+}
+
+/// Error returned when packing or unpacking samples fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SampleError {
+    /// The width of the samples was zero or exceeded 16 bits.
+    UnsupportedWidth,
+    /// The packed stream was too short to hold the samples.
+    InsufficientLength,
+    /// A sample held bits outside of its width.
+    Overflow,
+}
+
+impl SampleError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::UnsupportedWidth => "sample width must be between 1 and 16 bits",
+            Self::InsufficientLength => "packed stream is too short to hold the samples",
+            Self::Overflow => "sample holds bits outside of its width",
+        }
+    }
+}
+
+impl core::fmt::Display for SampleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for SampleError {}
+
+/// Compute the number of bytes in which `count` samples of `bits` bits are packed.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::samples::packed_len;
+///
+/// assert_eq!(packed_len(4, 12), 6);
+/// assert_eq!(packed_len(3, 10), 4);
+/// ```
+#[inline]
+#[must_use]
+pub const fn packed_len(count: usize, bits: u32) -> usize {
+    (count * bits as usize).div_ceil(8)
+}
+
+/// Validate the width of samples and that `packed_len` bytes hold `count` of them, returning the
+/// mask of a sample.
+fn validate(count: usize, bits: u32, packed_len: usize) -> Result<u32, SampleError> {
+    if bits == 0 || bits > 16 {
+        return Err(SampleError::UnsupportedWidth);
+    }
+    let required = count
+        .checked_mul(bits as usize)
+        .map(|bits| bits.div_ceil(8))
+        .ok_or(SampleError::InsufficientLength)?;
+    if packed_len < required {
+        return Err(SampleError::InsufficientLength);
+    }
+    Ok((1 << bits) - 1)
+}
+
+/// Unpack `samples.len()` samples of `bits` bits from the stream `packed`, whose bits are laid out
+/// in `order`, aligning each within its `u16` to `alignment`.
+///
+/// # Panics
+/// Panics if `bits` is zero or exceeds 16, or if `packed` is too short to hold the samples. See
+/// [`try_unpack_samples()`] for a non-panicking alternative.
+#[cfg(not(feature = "no-panic"))]
+pub fn unpack_samples(
+    packed: &[u8],
+    bits: u32,
+    order: ByteOrder,
+    alignment: Alignment,
+    samples: &mut [u16],
+) {
+    if let Err(err) = try_unpack_samples(packed, bits, order, alignment, samples) {
+        panic!("{err}");
+    }
+}
+
+/// Unpack `samples.len()` samples of `bits` bits from the stream `packed`, whose bits are laid out
+/// in `order`, aligning each within its `u16` to `alignment`.
+///
+/// This is the non-panicking alternative of `unpack_samples()`.
+///
+/// # Errors
+/// Returns [`SampleError::UnsupportedWidth`] if `bits` is zero or exceeds 16, or
+/// [`SampleError::InsufficientLength`] if `packed` is too short to hold the samples. The contents
+/// of `samples` are left unmodified upon error.
+///
+/// # Examples
+/// Unpacking 10-bit samples into the most significant bits:
+/// ```
+/// use lilbig::samples::{try_unpack_samples, Alignment};
+/// use lilbig::ByteOrder;
+///
+/// let packed = [0b1111_1111, 0b1100_0000, 0b0000_0000];
+/// let mut samples = [0u16; 2];
+/// let result = try_unpack_samples(&packed, 10, ByteOrder::Be, Alignment::High, &mut samples);
+/// assert_eq!(result, Ok(()));
+/// assert_eq!(samples, [0xffc0, 0x0000]);
+/// ```
+pub fn try_unpack_samples(
+    packed: &[u8],
+    bits: u32,
+    order: ByteOrder,
+    alignment: Alignment,
+    samples: &mut [u16],
+) -> Result<(), SampleError> {
+    let mask = validate(samples.len(), bits, packed.len())?;
+    let mut bytes = packed.iter().map(|&byte| u32::from(byte));
+    let mut window = 0u32;
+    let mut window_bits = 0;
+    for sample in samples {
+        while window_bits < bits {
+            let byte = bytes.next().unwrap_or(0);
+            window = match order {
+                ByteOrder::Be => window << 8 | byte,
+                ByteOrder::Le => window | byte << window_bits,
+            };
+            window_bits += 8;
+        }
+        let value = match order {
+            ByteOrder::Be => window >> (window_bits - bits) & mask,
+            ByteOrder::Le => {
+                let value = window & mask;
+                window >>= bits;
+                value
+            }
+        };
+        window_bits -= bits;
+        window &= (1 << window_bits) - 1;
+        *sample = match alignment {
+            Alignment::Low => value as u16,
+            Alignment::High => (value << (16 - bits)) as u16,
+        };
+    }
+    Ok(())
+}
+
+/// Pack `samples`, each of which is `bits` bits aligned within its `u16` to `alignment`, into the
+/// stream `packed`, laying out their bits in `order`.
+///
+/// The first [`packed_len()`] bytes of `packed` are written, with any unused bits of the final
+/// byte zeroed.
+///
+/// # Panics
+/// Panics if `bits` is zero or exceeds 16, if `packed` is too short to hold the samples, or if a
+/// sample holds bits outside of its width. See [`try_pack_samples()`] for a non-panicking
+/// alternative.
+#[cfg(not(feature = "no-panic"))]
+pub fn pack_samples(
+    samples: &[u16],
+    bits: u32,
+    order: ByteOrder,
+    alignment: Alignment,
+    packed: &mut [u8],
+) {
+    if let Err(err) = try_pack_samples(samples, bits, order, alignment, packed) {
+        panic!("{err}");
+    }
+}
+
+/// Pack `samples`, each of which is `bits` bits aligned within its `u16` to `alignment`, into the
+/// stream `packed`, laying out their bits in `order`.
+///
+/// The first [`packed_len()`] bytes of `packed` are written, with any unused bits of the final
+/// byte zeroed. This is the non-panicking alternative of `pack_samples()`.
+///
+/// # Errors
+/// Returns [`SampleError::UnsupportedWidth`] if `bits` is zero or exceeds 16,
+/// [`SampleError::InsufficientLength`] if `packed` is too short to hold the samples, or
+/// [`SampleError::Overflow`] if a sample holds bits outside of its width. The contents of `packed`
+/// are left unmodified upon error.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::samples::{try_pack_samples, Alignment, SampleError};
+/// use lilbig::ByteOrder;
+///
+/// let mut packed = [0u8; 4];
+/// let samples = [0x3ff, 0x001, 0x155];
+/// let result = try_pack_samples(&samples, 10, ByteOrder::Le, Alignment::Low, &mut packed);
+/// assert_eq!(result, Ok(()));
+/// assert_eq!(packed, [0xff, 0x07, 0x50, 0x15]);
+///
+/// let result = try_pack_samples(&[0x400], 10, ByteOrder::Le, Alignment::Low, &mut packed);
+/// assert_eq!(result, Err(SampleError::Overflow));
+/// ```
+pub fn try_pack_samples(
+    samples: &[u16],
+    bits: u32,
+    order: ByteOrder,
+    alignment: Alignment,
+    packed: &mut [u8],
+) -> Result<(), SampleError> {
+    let mask = validate(samples.len(), bits, packed.len())?;
+    let values = samples.iter().map(|&sample| match alignment {
+        Alignment::Low => u32::from(sample),
+        Alignment::High => u32::from(sample) >> (16 - bits),
+    });
+    let overflowed = samples
+        .iter()
+        .zip(values.clone())
+        .any(|(&sample, value)| match alignment {
+            Alignment::Low => value > mask,
+            Alignment::High => value << (16 - bits) != u32::from(sample),
+        });
+    if overflowed {
+        return Err(SampleError::Overflow);
+    }
+
+    let mut bytes = packed.iter_mut();
+    let mut window = 0u32;
+    let mut window_bits = 0;
+    for value in values {
+        window = match order {
+            ByteOrder::Be => window << bits | value,
+            ByteOrder::Le => window | value << window_bits,
+        };
+        window_bits += bits;
+        while window_bits >= 8 {
+            window_bits -= 8;
+            let byte = match order {
+                ByteOrder::Be => window >> window_bits,
+                ByteOrder::Le => {
+                    let byte = window;
+                    window >>= 8;
+                    byte
+                }
+            };
+            window &= (1 << window_bits) - 1;
+            if let Some(packed) = bytes.next() {
+                *packed = byte as u8;
+            }
+        }
+    }
+    if window_bits > 0 {
+        let byte = match order {
+            ByteOrder::Be => window << (8 - window_bits),
+            ByteOrder::Le => window,
+        };
+        if let Some(packed) = bytes.next() {
+            *packed = byte as u8;
+        }
+    }
+    Ok(())
+}
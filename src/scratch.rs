@@ -0,0 +1,241 @@
+//! Reusable scratch space for out-of-place conversions.
+//!
+//! Converting a buffer without modifying it requires somewhere to place the converted copy.
+//! [`Scratch`] provides that space and may be reused across calls, such that processing a stream of
+//! packets or records does not allocate per item. Scratch space either borrows a caller-provided
+//! slice, which suits `no_std` targets with a statically sized buffer, or, with the `alloc` feature
+//! enabled, owns a heap buffer that grows to the largest request it has served.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! use lilbig::scratch::{Scratch, ScratchError};
+//! use lilbig::ByteOrder;
+//!
+//! let mut storage = [0u8; 8];
+//! let mut scratch = Scratch::from_slice(&mut storage);
+//!
+//! for packet in [[0x12, 0x34, 0x56, 0x78], [0x9a, 0xbc, 0xde, 0xf0]] {
+//!     let converted = scratch.try_copy_ordered(&packet, 2, ByteOrder::Be, ByteOrder::Le)?;
+//!     assert_eq!(converted, [packet[1], packet[0], packet[3], packet[2]]);
+//! }
+//!
+//! assert_eq!(scratch.try_take(16), Err(ScratchError::InsufficientCapacity));
+//! # Ok::<(), ScratchError>(())
+//! ```
+
+use crate::{bulk, ByteOrder, WidthError};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Error returned when an operation upon scratch space fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ScratchError {
+    /// The borrowed scratch space was smaller than the requested length.
+    InsufficientCapacity,
+    /// The width of the elements being converted was invalid.
+    Width(WidthError),
+}
+
+impl ScratchError {
+    /// Retrieve a description of the error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::InsufficientCapacity => "scratch space is smaller than the requested length",
+            Self::Width(err) => err.message(),
+        }
+    }
+}
+
+impl core::fmt::Display for ScratchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for ScratchError {}
+
+impl From<WidthError> for ScratchError {
+    #[inline]
+    fn from(err: WidthError) -> Self {
+        Self::Width(err)
+    }
+}
+
+/// Storage backing scratch space.
+#[derive(Debug)]
+enum Storage<'a> {
+    /// Caller-provided storage of a fixed length.
+    Borrowed(&'a mut [u8]),
+    /// Heap storage growing as required.
+    #[cfg(feature = "alloc")]
+    Owned(Vec<u8>),
+}
+
+/// Reusable scratch space borrowed by out-of-place conversions.
+///
+/// The contents of the space handed out by each call are unspecified, and may hold bytes left over
+/// from a prior call.
+#[derive(Debug)]
+pub struct Scratch<'a> {
+    /// Storage of the scratch space.
+    storage: Storage<'a>,
+}
+
+impl<'a> Scratch<'a> {
+    /// Create scratch space borrowing `bytes`, which bounds the length of any request.
+    #[inline]
+    #[must_use]
+    pub fn from_slice(bytes: &'a mut [u8]) -> Self {
+        Self {
+            storage: Storage::Borrowed(bytes),
+        }
+    }
+
+    /// Retrieve the number of bytes that may be taken without growing the scratch space.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        match &self.storage {
+            Storage::Borrowed(bytes) => bytes.len(),
+            #[cfg(feature = "alloc")]
+            Storage::Owned(bytes) => bytes.len(),
+        }
+    }
+
+    /// Take `len` bytes of scratch space, growing owned scratch space as required.
+    ///
+    /// # Panics
+    /// Panics if the scratch space is borrowed and is smaller than `len`. See
+    /// [`try_take()`](Scratch::try_take) for a non-panicking alternative.
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    pub fn take(&mut self, len: usize) -> &mut [u8] {
+        match self.try_take(len) {
+            Ok(bytes) => bytes,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Take `len` bytes of scratch space, growing owned scratch space as required.
+    ///
+    /// This is the non-panicking alternative of `take()`.
+    ///
+    /// # Errors
+    /// Returns [`ScratchError::InsufficientCapacity`] if the scratch space is borrowed and is
+    /// smaller than `len`.
+    pub fn try_take(&mut self, len: usize) -> Result<&mut [u8], ScratchError> {
+        match &mut self.storage {
+            Storage::Borrowed(bytes) => bytes
+                .get_mut(..len)
+                .ok_or(ScratchError::InsufficientCapacity),
+            #[cfg(feature = "alloc")]
+            Storage::Owned(bytes) => {
+                if bytes.len() < len {
+                    bytes.resize(len, 0);
+                }
+                bytes
+                    .get_mut(..len)
+                    .ok_or(ScratchError::InsufficientCapacity)
+            }
+        }
+    }
+
+    /// Copy `bytes` into the scratch space, converting each `width` byte wide element from
+    /// `current_order` to `new_order`, and retrieve the converted copy.
+    ///
+    /// # Panics
+    /// Panics if `width` is zero, if the length of `bytes` is not a multiple of `width`, or if the
+    /// scratch space is borrowed and is smaller than `bytes`. See
+    /// [`try_copy_ordered()`](Scratch::try_copy_ordered) for a non-panicking alternative.
+    #[cfg(not(feature = "no-panic"))]
+    pub fn copy_ordered(
+        &mut self,
+        bytes: &[u8],
+        width: usize,
+        current_order: ByteOrder,
+        new_order: ByteOrder,
+    ) -> &mut [u8] {
+        match self.try_copy_ordered(bytes, width, current_order, new_order) {
+            Ok(bytes) => bytes,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Copy `bytes` into the scratch space, converting each `width` byte wide element from
+    /// `current_order` to `new_order`, and retrieve the converted copy.
+    ///
+    /// This is the non-panicking alternative of `copy_ordered()`.
+    ///
+    /// # Errors
+    /// Returns [`ScratchError::Width`] if `width` is zero or if the length of `bytes` is not a
+    /// multiple of `width`, or [`ScratchError::InsufficientCapacity`] if the scratch space is
+    /// borrowed and is smaller than `bytes`.
+    pub fn try_copy_ordered(
+        &mut self,
+        bytes: &[u8],
+        width: usize,
+        current_order: ByteOrder,
+        new_order: ByteOrder,
+    ) -> Result<&mut [u8], ScratchError> {
+        if width == 0 {
+            return Err(WidthError::Zero.into());
+        }
+        if !bytes.len().is_multiple_of(width) {
+            return Err(WidthError::IndivisibleLength.into());
+        }
+        let copy = self.try_take(bytes.len())?;
+        copy.copy_from_slice(bytes);
+        if current_order != new_order {
+            bulk::swap_elements_unchecked(copy, width);
+        }
+        Ok(copy)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Scratch<'static> {
+    /// Create empty owned scratch space.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #[cfg(feature = "alloc")]
+    /// # fn main() {
+    /// use lilbig::scratch::Scratch;
+    ///
+    /// let mut scratch = Scratch::new();
+    /// assert_eq!(scratch.try_take(64).map(|bytes| bytes.len()), Ok(64));
+    /// assert_eq!(scratch.try_take(16).map(|bytes| bytes.len()), Ok(16));
+    /// assert_eq!(scratch.capacity(), 64);
+    /// # }
+    /// # #[cfg(not(feature = "alloc"))]
+    /// # fn main() {}
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            storage: Storage::Owned(Vec::new()),
+        }
+    }
+
+    /// Create owned scratch space of `capacity` bytes.
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            storage: Storage::Owned(alloc::vec![0; capacity]),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for Scratch<'static> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,33 @@
+//! Implementations of [`serde`]'s traits for the wrappers of [`ordered`](crate::ordered).
+//!
+//! The wrappers are serialized as their decoded values, and encoded in their byte-order once
+//! deserialized, such that the byte-order of the wrapped values doesn't leak into the formats they
+//! are serialized to.
+
+use crate::marker::OrderMarker;
+use crate::ordered::{Be, Le, Ne, Ordered};
+use crate::ByteOrdered;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Implement serialization and deserialization of wrappers as their decoded values.
+macro_rules! impl_serde {
+    ($([$($params: tt)*] $name: ty),+) => {
+        $(
+            impl<T: ByteOrdered + Copy + Serialize, $($params)*> Serialize for $name {
+                #[inline]
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.get().serialize(serializer)
+                }
+            }
+
+            impl<'de, T: ByteOrdered + Deserialize<'de>, $($params)*> Deserialize<'de> for $name {
+                #[inline]
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    T::deserialize(deserializer).map(Self::new)
+                }
+            }
+        )+
+    };
+}
+
+impl_serde!([] Le<T>, [] Be<T>, [] Ne<T>, [O: OrderMarker] Ordered<T, O>);
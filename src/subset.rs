@@ -0,0 +1,113 @@
+//! Swapping of a subset of the fields of a struct.
+//!
+//! Partially updating a large struct, such as rewriting a few fields of an on-disk header, only
+//! requires those fields to be converted. A type implementing [`SwapSubset`] swaps the byte-order of
+//! its fields individually by their index, which is the position at which the field is declared.
+//!
+//! With the `derive` feature enabled, [`SwapSubset`] may be derived for structs.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # fn main() {
+//! use lilbig::subset::SwapSubset;
+//! use lilbig::FieldsByteOrdered;
+//!
+//! #[derive(FieldsByteOrdered, SwapSubset)]
+//! struct Header {
+//!     magic: u32,
+//!     version: u16,
+//!     length: u64,
+//! }
+//!
+//! let mut header = Header {
+//!     magic: 0x01020304,
+//!     version: 0x0506,
+//!     length: 0x0708,
+//! };
+//! let length = Header::field_index("length").unwrap();
+//! header.swap_fields_at(&[0, length]);
+//! assert_eq!(header.magic, 0x04030201);
+//! assert_eq!(header.version, 0x0506);
+//! assert_eq!(header.length, 0x0708_u64.swap_bytes());
+//! # }
+//! # #[cfg(not(feature = "derive"))]
+//! # fn main() {}
+//! ```
+
+use crate::FieldsByteOrdered;
+
+#[cfg(feature = "derive")]
+pub use lilbig_derive::SwapSubset;
+
+/// Trait for types whose fields may be swapped individually.
+///
+/// Swapping every field of the type individually must be equivalent to
+/// [`FieldsByteOrdered::swap_field_orders()`].
+///
+/// # Examples
+/// Implementing the trait for a struct:
+/// ```
+/// use lilbig::subset::SwapSubset;
+/// use lilbig::FieldsByteOrdered;
+///
+/// struct Sample {
+///     id: u32,
+///     channel: u16,
+/// }
+///
+/// impl FieldsByteOrdered for Sample {
+///     fn swap_field_orders(&mut self) {
+///         self.id.swap_field_orders();
+///         self.channel.swap_field_orders();
+///     }
+/// }
+///
+/// impl SwapSubset for Sample {
+///     const FIELD_NAMES: &'static [&'static str] = &["id", "channel"];
+///
+///     fn swap_field_order(&mut self, index: usize) -> bool {
+///         match index {
+///             0 => self.id.swap_field_orders(),
+///             1 => self.channel.swap_field_orders(),
+///             _ => return false,
+///         }
+///         true
+///     }
+/// }
+///
+/// let mut sample = Sample { id: 1, channel: 2 };
+/// assert!(sample.swap_field_order(1));
+/// assert!(!sample.swap_field_order(2));
+/// assert_eq!((sample.id, sample.channel), (1, 0x0200));
+/// ```
+pub trait SwapSubset: FieldsByteOrdered {
+    /// Names of the fields of the type in the order in which they are declared, such that the
+    /// index of a name is the index of its field. The fields of tuple structs are named by their
+    /// index.
+    const FIELD_NAMES: &'static [&'static str];
+
+    /// Swap the byte-order of the field at `index`, as it would be swapped by
+    /// [`FieldsByteOrdered::swap_field_orders()`].
+    ///
+    /// Returns `false` without modifying `self` if `index` is not less than the number of fields.
+    fn swap_field_order(&mut self, index: usize) -> bool;
+
+    /// Swap the byte-order of each of the fields at `indices`, ignoring indices that are not less
+    /// than the number of fields.
+    ///
+    /// A field whose index is given more than once is swapped once for each occurrence.
+    #[inline]
+    fn swap_fields_at(&mut self, indices: &[usize]) {
+        for &index in indices {
+            self.swap_field_order(index);
+        }
+    }
+
+    /// Retrieve the index of the field named `name`, or [`None`] if the type has no such field.
+    #[must_use]
+    fn field_index(name: &str) -> Option<usize> {
+        Self::FIELD_NAMES.iter().position(|&field| field == name)
+    }
+}
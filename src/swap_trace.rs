@@ -0,0 +1,86 @@
+//! Recording of the swaps performed while converting the byte-order of a value.
+//!
+//! While recording, each in-place swap of a primitive field lying within the recorded value is
+//! noted as the offset and width of the field. The resulting sequence describes exactly how a
+//! type's conversion behaves, which allows snapshot tests to assert that the behavior remains
+//! stable across refactors and upgrades of derived implementations.
+//!
+//! Swaps of single bytes are not performed and are therefore not recorded. Neither are swaps of
+//! values copied out of the recorded value, such as the fields of packed structs.
+//!
+//! # Examples
+//! Recording the conversion of an array:
+//! ```
+//! use lilbig::layout::FieldLayout;
+//! use lilbig::swap_trace;
+//! use lilbig::FieldsByteOrdered;
+//!
+//! let mut value = ([0x0102u16, 0x0304], 0x05060708u32);
+//! let swaps = swap_trace::record(&mut value.0, |value| value.swap_field_orders());
+//! assert_eq!(swaps, [FieldLayout::new(0, 2), FieldLayout::new(2, 2)]);
+//! ```
+
+use crate::layout::FieldLayout;
+use core::cell::RefCell;
+use std::vec::Vec;
+
+/// State of an ongoing recording.
+struct Recording {
+    /// Address of the first byte of the recorded value.
+    start: usize,
+    /// Address following the last byte of the recorded value.
+    end: usize,
+    /// Swaps recorded so far.
+    swaps: Vec<FieldLayout>,
+}
+
+std::thread_local! {
+    /// The ongoing recording of the current thread, if any.
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+/// Restores the recording that was ongoing prior to a nested recording once dropped, even if the
+/// nested recording is unwound.
+struct Restore(Option<Recording>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        let _ = RECORDING.try_with(|recording| recording.replace(previous));
+    }
+}
+
+/// Record each swap performed upon the fields of `value` by `convert`.
+///
+/// Returns the offset and width of each swapped field in the order in which they were swapped.
+/// Recordings may be nested, in which case swaps are only recorded by the innermost recording.
+pub fn record<T: ?Sized, F: FnOnce(&mut T)>(value: &mut T, convert: F) -> Vec<FieldLayout> {
+    let start = (value as *mut T).cast::<u8>() as usize;
+    let recording = Recording {
+        start,
+        end: start + core::mem::size_of_val(value),
+        swaps: Vec::new(),
+    };
+    let restore = Restore(RECORDING.with(|current| current.replace(Some(recording))));
+    convert(value);
+    let recording = RECORDING.with(|current| current.replace(None));
+    drop(restore);
+    recording
+        .map(|recording| recording.swaps)
+        .unwrap_or_default()
+}
+
+/// Note the swap of `value` if it lies within the ongoing recording.
+#[inline]
+pub(crate) fn swap<T>(value: &T) {
+    let address = (value as *const T).cast::<u8>() as usize;
+    let width = core::mem::size_of::<T>();
+    let _ = RECORDING.try_with(|recording| {
+        if let Some(recording) = recording.borrow_mut().as_mut() {
+            if address >= recording.start && address + width <= recording.end {
+                let field = FieldLayout::new(address - recording.start, width);
+                recording.swaps.push(field);
+            }
+        }
+    });
+}
@@ -0,0 +1,241 @@
+//! Values tagged with a byte-order known only at runtime.
+//!
+//! The byte-order of data read from a file is often only known once a header has been parsed, so
+//! it can't be stated by the types of the values that are read. [`OrderTagged`] instead holds a
+//! value along with the byte-order it is currently stored in, such that the byte-order follows the
+//! value around and every access converts the value from the byte-order it is actually in.
+//! [`OrderedBytes`] likewise holds the bytes of a value whose type is only decided later, such as
+//! once a field's type code has been read, along with the byte-order of those bytes.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! use lilbig::tagged::OrderTagged;
+//! use lilbig::ByteOrder;
+//!
+//! let mut length = OrderTagged::new(0x1234_u16.to_be(), ByteOrder::Be);
+//! assert_eq!(length.get_ne(), 0x1234);
+//!
+//! length.set_ne(0x5678);
+//! assert_eq!(*length.inner(), 0x5678_u16.to_be());
+//!
+//! length.normalize();
+//! assert_eq!(length.order(), ByteOrder::NATIVE);
+//! assert_eq!(*length.inner(), 0x5678);
+//! ```
+
+use crate::{ByteOrder, FieldsByteOrdered, WireSafe};
+
+/// Value held along with the byte-order it is currently stored in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OrderTagged<T> {
+    /// Held value in the byte-order of the tag.
+    value: T,
+    /// Byte-order the value is currently stored in.
+    order: ByteOrder,
+}
+
+impl<T: FieldsByteOrdered> OrderTagged<T> {
+    /// Create a holder of `value`, which is stored in `order`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: T, order: ByteOrder) -> Self {
+        Self { value, order }
+    }
+
+    /// Create a holder of `value`, which is in the machine's native byte-order.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_ne(value: T) -> Self {
+        Self::new(value, ByteOrder::NATIVE)
+    }
+
+    /// Retrieve the byte-order the held value is currently stored in.
+    #[inline(always)]
+    #[must_use]
+    pub const fn order(&self) -> ByteOrder {
+        self.order
+    }
+
+    /// Retrieve the held value without converting it from the byte-order of the tag.
+    #[inline(always)]
+    #[must_use]
+    pub const fn inner(&self) -> &T {
+        &self.value
+    }
+
+    /// Retrieve the held value and its byte-order without converting the value.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_parts(self) -> (T, ByteOrder) {
+        (self.value, self.order)
+    }
+
+    /// Retrieve a copy of the held value in the machine's native byte-order.
+    #[inline]
+    #[must_use]
+    pub fn get_ne(&self) -> T
+    where
+        T: Clone,
+    {
+        let mut value = self.value.clone();
+        value.order_fields_ne(self.order);
+        value
+    }
+
+    /// Replace the held value with `value`, which is in the machine's native byte-order, storing it
+    /// in the byte-order of the tag.
+    #[inline]
+    pub fn set_ne(&mut self, mut value: T) {
+        value.order_fields_as(ByteOrder::NATIVE, self.order);
+        self.value = value;
+    }
+
+    /// Convert the held value to `order`, tagging it with `order` thereafter.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::tagged::OrderTagged;
+    /// use lilbig::ByteOrder;
+    ///
+    /// let mut offset = OrderTagged::from_ne(0x12345678_u32);
+    /// offset.retag(ByteOrder::Le);
+    /// assert_eq!(offset.order(), ByteOrder::Le);
+    /// assert_eq!(*offset.inner(), 0x12345678_u32.to_le());
+    /// assert_eq!(offset.get_ne(), 0x12345678);
+    /// ```
+    #[inline]
+    pub fn retag(&mut self, order: ByteOrder) {
+        self.value.order_fields_as(self.order, order);
+        self.order = order;
+    }
+
+    /// Convert the held value to the machine's native byte-order, tagging it as such thereafter.
+    #[inline]
+    pub fn normalize(&mut self) {
+        self.retag(ByteOrder::NATIVE);
+    }
+
+    /// Convert the held value to the machine's native byte-order and retrieve it.
+    #[inline]
+    #[must_use]
+    pub fn into_ne(mut self) -> T {
+        self.normalize();
+        self.value
+    }
+}
+
+/// Bytes of a value held along with the byte-order they are stored in.
+///
+/// The bytes are reinterpreted as values of any [`WireSafe`] type of the same size, which are
+/// decoded from and encoded in the byte-order of the tag.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::tagged::OrderedBytes;
+/// use lilbig::ByteOrder;
+///
+/// let mut bytes = OrderedBytes::new([0x3f, 0x80, 0x00, 0x00], ByteOrder::Be);
+/// assert_eq!(bytes.as_ne_value::<u32>(), Some(0x3f800000));
+/// assert_eq!(bytes.as_ne_value::<u16>(), None);
+///
+/// bytes.reverse();
+/// assert_eq!(bytes.order(), ByteOrder::Le);
+/// assert_eq!(bytes.bytes(), &[0x00, 0x00, 0x80, 0x3f]);
+/// assert_eq!(bytes.as_ne_value::<u32>(), Some(0x3f800000));
+///
+/// let bytes = OrderedBytes::<2>::from_ne_value(0x1234_u16, ByteOrder::Be).unwrap();
+/// assert_eq!(bytes.into_bytes(), [0x12, 0x34]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OrderedBytes<const N: usize> {
+    /// Bytes of the value in the byte-order of the tag.
+    bytes: [u8; N],
+    /// Byte-order the bytes are stored in.
+    order: ByteOrder,
+}
+
+impl<const N: usize> OrderedBytes<N> {
+    /// Create a holder of `bytes`, which are stored in `order`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(bytes: [u8; N], order: ByteOrder) -> Self {
+        Self { bytes, order }
+    }
+
+    /// Create a holder of the bytes of `value`, which is in the machine's native byte-order,
+    /// encoding them in `order`.
+    ///
+    /// Returns [`None`] if the size of `T` is not `N`.
+    #[inline]
+    #[must_use]
+    pub fn from_ne_value<T: FieldsByteOrdered + WireSafe>(
+        mut value: T,
+        order: ByteOrder,
+    ) -> Option<Self> {
+        value.order_fields_as(ByteOrder::NATIVE, order);
+        let bytes = value.as_bytes().try_into().ok()?;
+        Some(Self::new(bytes, order))
+    }
+
+    /// Retrieve the byte-order the bytes are stored in.
+    #[inline(always)]
+    #[must_use]
+    pub const fn order(&self) -> ByteOrder {
+        self.order
+    }
+
+    /// Retrieve the held bytes.
+    #[inline(always)]
+    #[must_use]
+    pub const fn bytes(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Retrieve the held bytes, discarding the byte-order they are stored in.
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_bytes(self) -> [u8; N] {
+        self.bytes
+    }
+
+    /// Reverse the held bytes, tagging them with the opposite byte-order thereafter.
+    ///
+    /// This converts the byte-order of a single value spanning every byte, such as an integer,
+    /// whereas the byte-order of values of several fields is converted by
+    /// [`OrderedBytes::retag_as()`].
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.bytes.reverse();
+        self.order = !self.order;
+    }
+
+    /// Convert the held bytes to `order` as the bytes of a value of type `T`, tagging them with
+    /// `order` thereafter.
+    ///
+    /// Returns `false` without modifying `self` if the size of `T` is not `N`.
+    #[inline]
+    pub fn retag_as<T: FieldsByteOrdered + WireSafe>(&mut self, order: ByteOrder) -> bool {
+        let Some(mut value) = T::from_bytes(&self.bytes) else {
+            return false;
+        };
+        value.order_fields_as(self.order, order);
+        self.bytes.copy_from_slice(value.as_bytes());
+        self.order = order;
+        true
+    }
+
+    /// Reinterpret the held bytes as a value of type `T`, converting it to the machine's native
+    /// byte-order.
+    ///
+    /// Returns [`None`] if the size of `T` is not `N`.
+    #[inline]
+    #[must_use]
+    pub fn as_ne_value<T: FieldsByteOrdered + WireSafe>(&self) -> Option<T> {
+        let mut value = T::from_bytes(&self.bytes)?;
+        value.order_fields_ne(self.order);
+        Some(value)
+    }
+}
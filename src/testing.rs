@@ -0,0 +1,503 @@
+//! Helpers for testing implementations of the crate's traits.
+//!
+//! The assertions within this module panic upon failure, as is customary for test helpers, and are
+//! intended for use within tests. The proptest strategies within this module allow those
+//! assertions to be checked against arbitrary values.
+//!
+//! # Examples
+//! Property testing a manual implementation:
+//! ```
+//! use lilbig::testing::{assert_matches_bytewise_reference, assert_swap_roundtrip, wire_safe};
+//! use lilbig::{FieldsByteOrdered, WireSafe};
+//! use proptest::prelude::*;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! #[repr(C)]
+//! struct Sample {
+//!     id: u32,
+//!     channel: u16,
+//!     flags: [u8; 2],
+//! }
+//!
+//! impl FieldsByteOrdered for Sample {
+//!     fn swap_field_orders(&mut self) {
+//!         self.id.swap_field_orders();
+//!         self.channel.swap_field_orders();
+//!     }
+//! }
+//!
+//! unsafe impl WireSafe for Sample {}
+//!
+//! proptest!(|(sample in wire_safe::<Sample>())| {
+//!     assert_swap_roundtrip(sample);
+//!     assert_matches_bytewise_reference(sample, &[4, 2, 1, 1]);
+//! });
+//! ```
+
+use crate::layout::{FieldLayout, SwapLayout};
+use crate::{ByteOrder, ByteOrdered, FieldsByteOrdered, WireSafe, WordOrder};
+use core::fmt::Debug;
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{Just, Strategy, Union};
+use std::vec::Vec;
+
+/// Assert that swapping the byte-order of `value`'s fields twice results in `value`.
+///
+/// # Panics
+/// Panics if the assertion fails.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::testing::assert_swap_roundtrip;
+///
+/// assert_swap_roundtrip(0x12345678u32);
+/// assert_swap_roundtrip([-3i64, 0x1234]);
+/// ```
+pub fn assert_swap_roundtrip<T: FieldsByteOrdered + Clone + PartialEq + Debug>(value: T) {
+    let mut swapped = value.clone();
+    swapped.swap_field_orders();
+    swapped.swap_field_orders();
+    assert_eq!(
+        swapped, value,
+        "swapping the byte-order of a value's fields twice did not restore the value",
+    );
+}
+
+/// Assert that swapping the byte-order of `value`'s fields matches an independent reference that
+/// reverses the bytes of each field of `value` in turn.
+///
+/// `field_widths` lists the width in bytes of each of `value`'s fields in order of their offsets,
+/// with padding and the elements of arrays listed individually. Fields whose bytes are not
+/// reordered, such as bytes or byte arrays, should be listed with a width of 1.
+///
+/// # Panics
+/// Panics if the widths do not sum to the size of `T` or if the assertion fails.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::testing::assert_matches_bytewise_reference;
+///
+/// assert_matches_bytewise_reference([0x0102u16, 0x0304], &[2, 2]);
+/// assert_matches_bytewise_reference(0x0102030405060708u64, &[8]);
+/// ```
+pub fn assert_matches_bytewise_reference<T: FieldsByteOrdered + WireSafe + Debug>(
+    value: T,
+    field_widths: &[usize],
+) {
+    let size: usize = field_widths.iter().sum();
+    assert_eq!(
+        size,
+        core::mem::size_of::<T>(),
+        "field widths do not sum to the size of the type",
+    );
+
+    let mut offset = 0;
+    let fields = field_widths.iter().map(|&width| {
+        let field = FieldLayout::new(offset, width);
+        offset += width;
+        field
+    });
+    assert_matches_reference(value, fields);
+}
+
+/// Assert that swapping the byte-order of `value`'s fields matches an independent reference that
+/// reverses the bytes of each field listed within `T`'s [`SwapLayout`] table in turn.
+///
+/// As the table of a derived [`SwapLayout`] is generated separately from a derived
+/// [`FieldsByteOrdered`], this catches mistakes in either as well as manual implementations that
+/// have drifted from the type's fields.
+///
+/// # Panics
+/// Panics if a field of the table lies outside of `T` or if the assertion fails.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::testing::assert_matches_layout;
+///
+/// assert_matches_layout([0x0102u16, 0x0304]);
+/// assert_matches_layout([[0x01020304u32; 2]; 3]);
+/// ```
+pub fn assert_matches_layout<T: SwapLayout + WireSafe + Debug>(value: T) {
+    assert_matches_reference(value, T::swap_fields());
+}
+
+/// Assert that swapping the byte-order of `value`'s fields matches reversing the bytes of each of
+/// `fields` in turn.
+fn assert_matches_reference<T, I>(value: T, fields: I)
+where
+    T: FieldsByteOrdered + WireSafe + Debug,
+    I: IntoIterator<Item = FieldLayout>,
+{
+    let mut expected = Vec::from(value.as_bytes());
+    for field in fields {
+        let bytes = field
+            .offset
+            .checked_add(field.width)
+            .and_then(|end| expected.get_mut(field.offset..end));
+        assert!(bytes.is_some(), "{field:?} lies outside of the type");
+        if let Some(bytes) = bytes {
+            bytes.reverse();
+        }
+    }
+
+    let mut swapped = value;
+    swapped.swap_field_orders();
+    assert_eq!(
+        swapped.as_bytes(),
+        expected.as_slice(),
+        "swapping the byte-order of {value:?}'s fields did not match the bytewise reference",
+    );
+}
+
+/// Create a strategy producing values of `T` from arbitrary bytes.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::testing::{assert_swap_roundtrip, wire_safe};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(pair in wire_safe::<[u32; 2]>())| {
+///     assert_swap_roundtrip(pair);
+/// });
+/// ```
+pub fn wire_safe<T: WireSafe + Debug>() -> impl Strategy<Value = T> {
+    // The bytes are always of the size of `T`, so no values are filtered.
+    proptest::collection::vec(proptest::num::u8::ANY, core::mem::size_of::<T>())
+        .prop_filter_map("bytes are of the size of the type", |bytes| {
+            T::from_bytes(&bytes)
+        })
+}
+
+impl Arbitrary for ByteOrder {
+    type Parameters = ();
+    type Strategy = Union<Just<Self>>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        Union::new([Just(Self::Le), Just(Self::Be)])
+    }
+}
+
+impl Arbitrary for WordOrder {
+    type Parameters = ();
+    type Strategy = Union<Just<Self>>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        Union::new([Just(Self::Le), Just(Self::Be)])
+    }
+}
+
+/// Assert that swapping the byte-order of `value` through [`ByteOrdered`] and through
+/// [`FieldsByteOrdered`] produce the same value.
+///
+/// # Panics
+/// Panics if the assertion fails.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::testing::assert_swaps_agree;
+///
+/// assert_swaps_agree(0x1234u16);
+/// assert_swaps_agree(-7i32);
+/// ```
+pub fn assert_swaps_agree<T: ByteOrdered + FieldsByteOrdered + Clone + PartialEq + Debug>(
+    value: T,
+) {
+    let mut fields_swapped = value.clone();
+    fields_swapped.swap_field_orders();
+    assert_eq!(
+        value.swapped_order(),
+        fields_swapped,
+        "`swapped_order()` and `swap_field_orders()` disagree",
+    );
+}
+
+/// Assert that the `ordered_*` methods of [`ByteOrdered`] and the `order_fields_*` methods of
+/// [`FieldsByteOrdered`] agree with one another for every combination of current and new
+/// byte-order.
+///
+/// Fields pinned to a byte-order are expected to be converted by the `order_fields_*` methods only
+/// if exactly one of the current and new byte-orders is the machine's native byte-order, as
+/// described by [`FieldsByteOrdered::swap_pinned_field_orders()`].
+///
+/// # Panics
+/// Panics if the assertion fails.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::testing::assert_order_matrix;
+///
+/// assert_order_matrix(0x7cf3a4b1u32);
+/// assert_order_matrix(i64::MIN);
+/// ```
+///
+/// Checking a type holding a pinned field:
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # fn main() {
+/// use lilbig::testing::assert_order_matrix;
+/// use lilbig::{ByteOrdered, FieldsByteOrdered};
+///
+/// #[derive(Debug, Clone, PartialEq, FieldsByteOrdered)]
+/// struct Header {
+///     length: u32,
+///     #[lilbig(order = "be")]
+///     checksum: u16,
+/// }
+///
+/// impl ByteOrdered for Header {
+///     fn swapped_order(mut self) -> Self {
+///         self.swap_field_orders();
+///         self
+///     }
+/// }
+///
+/// assert_order_matrix(Header {
+///     length: 0x01020304,
+///     checksum: 0x0506,
+/// });
+/// # }
+/// # #[cfg(not(feature = "derive"))]
+/// # fn main() {}
+/// ```
+pub fn assert_order_matrix<T: ByteOrdered + FieldsByteOrdered + Clone + PartialEq + Debug>(
+    value: T,
+) {
+    const ORDERS: [ByteOrder; 2] = [ByteOrder::Le, ByteOrder::Be];
+    let fields_as = |current: ByteOrder, new: ByteOrder| {
+        let mut value = value.clone();
+        if current != new {
+            value = value.swapped_order();
+        }
+        if T::HAS_PINNED_FIELDS && current.is_native() != new.is_native() {
+            value.swap_pinned_field_orders();
+        }
+        value
+    };
+    for current in ORDERS {
+        let ordered = |f: fn(T, ByteOrder) -> T| f(value.clone(), current);
+        let fields_ordered = |f: fn(&mut T, ByteOrder)| {
+            let mut value = value.clone();
+            f(&mut value, current);
+            value
+        };
+        assert_eq!(
+            ordered(T::ordered_ne),
+            value.clone().ordered_as(current, ByteOrder::NATIVE),
+            "`ordered_ne({current:?})` disagrees",
+        );
+        assert_eq!(
+            ordered(T::ordered_le),
+            value.clone().ordered_as(current, ByteOrder::Le),
+            "`ordered_le({current:?})` disagrees",
+        );
+        assert_eq!(
+            ordered(T::ordered_be),
+            value.clone().ordered_as(current, ByteOrder::Be),
+            "`ordered_be({current:?})` disagrees",
+        );
+        assert_eq!(
+            fields_ordered(T::order_fields_ne),
+            fields_as(current, ByteOrder::NATIVE),
+            "`order_fields_ne({current:?})` disagrees",
+        );
+        assert_eq!(
+            fields_ordered(T::order_fields_le),
+            fields_as(current, ByteOrder::Le),
+            "`order_fields_le({current:?})` disagrees",
+        );
+        assert_eq!(
+            fields_ordered(T::order_fields_be),
+            fields_as(current, ByteOrder::Be),
+            "`order_fields_be({current:?})` disagrees",
+        );
+        assert_eq!(
+            fields_ordered(|value, current| current.apply_to(value)),
+            fields_as(current, ByteOrder::NATIVE),
+            "`{current:?}.apply_to()` disagrees",
+        );
+
+        for new in ORDERS {
+            let expected = if current == new {
+                value.clone()
+            } else {
+                value.clone().swapped_order()
+            };
+            let mut fields_ordered = value.clone();
+            fields_ordered.order_fields_as(current, new);
+            assert_eq!(
+                value.clone().ordered_as(current, new),
+                expected,
+                "`ordered_as({current:?}, {new:?})` disagrees",
+            );
+            assert_eq!(
+                fields_ordered,
+                fields_as(current, new),
+                "`order_fields_as({current:?}, {new:?})` disagrees",
+            );
+        }
+    }
+}
+
+/// Simulated host whose native byte-order may differ from that of the machine running the tests.
+///
+/// The provided methods of [`ByteOrdered`] and [`FieldsByteOrdered`] convert to and from the
+/// compilation target's native byte-order, so code running upon a little-endian machine never
+/// exercises the conversions that a big-endian machine would perform, and vice versa. A simulated
+/// host performs the conversions of its own native byte-order instead. Values held by the host are
+/// in the host's native byte-order, and [`host_value()`](SimulatedNative::host_value) converts
+/// expected values to that representation for comparison.
+///
+/// # Examples
+/// Exercising reading big-endian data upon both little-endian and big-endian hosts:
+/// ```
+/// use lilbig::testing::SimulatedNative;
+/// use lilbig::ByteOrder;
+///
+/// let wire = 0x12345678u32.to_be();
+/// for host in SimulatedNative::both() {
+///     assert_eq!(host.ordered_ne(wire, ByteOrder::Be), host.host_value(0x12345678));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimulatedNative {
+    /// The simulated native byte-order.
+    native: ByteOrder,
+}
+
+impl SimulatedNative {
+    /// Create a host whose native byte-order is `native`.
+    #[inline]
+    #[must_use]
+    pub const fn new(native: ByteOrder) -> Self {
+        Self { native }
+    }
+
+    /// Create a host whose native byte-order is the opposite of the compilation target's.
+    #[inline]
+    #[must_use]
+    pub const fn opposite() -> Self {
+        Self::new(ByteOrder::NATIVE_OPPOSITE)
+    }
+
+    /// Create a host of each byte-order.
+    #[inline]
+    #[must_use]
+    pub const fn both() -> [Self; 2] {
+        [Self::new(ByteOrder::Le), Self::new(ByteOrder::Be)]
+    }
+
+    /// Retrieve the host's simulated native byte-order.
+    #[inline]
+    #[must_use]
+    pub const fn native(&self) -> ByteOrder {
+        self.native
+    }
+
+    /// Convert `value` from the compilation target's native byte-order to the host's.
+    #[inline]
+    #[must_use]
+    pub fn host_value<T: ByteOrdered>(&self, value: T) -> T {
+        value.ordered_as(ByteOrder::NATIVE, self.native)
+    }
+
+    /// Convert the fields of `value` from the compilation target's native byte-order to the
+    /// host's.
+    #[inline]
+    pub fn host_fields<T: FieldsByteOrdered + ?Sized>(&self, value: &mut T) {
+        value.order_fields_as(ByteOrder::NATIVE, self.native);
+    }
+
+    /// Equivalent of [`ByteOrdered::ordered_ne()`] upon the host.
+    #[inline]
+    #[must_use]
+    pub fn ordered_ne<T: ByteOrdered>(&self, value: T, current_order: ByteOrder) -> T {
+        value.ordered_as(current_order, self.native)
+    }
+
+    /// Equivalent of [`ByteOrdered::ordered_as()`] converting from the host's native byte-order,
+    /// as is performed when writing a value held by the host.
+    #[inline]
+    #[must_use]
+    pub fn ordered_from_ne<T: ByteOrdered>(&self, value: T, new_order: ByteOrder) -> T {
+        value.ordered_as(self.native, new_order)
+    }
+
+    /// Equivalent of [`FieldsByteOrdered::order_fields_ne()`] upon the host.
+    #[inline]
+    pub fn order_fields_ne<T: FieldsByteOrdered + ?Sized>(
+        &self,
+        value: &mut T,
+        current_order: ByteOrder,
+    ) {
+        value.order_fields_as(current_order, self.native);
+    }
+
+    /// Equivalent of [`FieldsByteOrdered::order_fields_as()`] converting from the host's native
+    /// byte-order, as is performed when writing a value held by the host.
+    #[inline]
+    pub fn order_fields_from_ne<T: FieldsByteOrdered + ?Sized>(
+        &self,
+        value: &mut T,
+        new_order: ByteOrder,
+    ) {
+        value.order_fields_as(self.native, new_order);
+    }
+}
+
+/// Generate a module of tests for each of a list of types implementing both [`ByteOrdered`] and
+/// [`FieldsByteOrdered`].
+///
+/// Each entry takes the form `name: Type = value;`, where `name` is the name of the generated
+/// module and `value` is the value under test. The generated module holds the tests:
+/// - `double_swap`, which checks [`assert_swap_roundtrip()`].
+/// - `swaps_agree`, which checks [`assert_swaps_agree()`].
+/// - `order_matrix`, which checks [`assert_order_matrix()`].
+///
+/// The generated modules import the items of the module in which the macro is invoked.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// # use lilbig::f80::F80;
+/// mod tests {
+///     use super::*;
+///
+///     lilbig::roundtrip_tests! {
+///         word: u32 = 0x7cf3a4b1;
+///         extended: F80 = F80::from_f64(-1.5);
+///         pair: [i16; 2] = [-2, 300];
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! roundtrip_tests {
+    ($($name:ident: $ty:ty = $value:expr;)*) => {
+        $(
+            mod $name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #[test]
+                fn double_swap() {
+                    $crate::testing::assert_swap_roundtrip::<$ty>($value);
+                }
+
+                #[test]
+                fn swaps_agree() {
+                    $crate::testing::assert_swaps_agree::<$ty>($value);
+                }
+
+                #[test]
+                fn order_matrix() {
+                    $crate::testing::assert_order_matrix::<$ty>($value);
+                }
+            }
+        )*
+    };
+}
@@ -0,0 +1,190 @@
+//! NTP and PTP timestamp types.
+//!
+//! Both timestamp types are `#[repr(C)]`, free of padding, and implement [`FieldsByteOrdered`] and
+//! [`WireSafe`], so they may be read directly from packets and converted from network byte-order
+//! (big-endian) like any other field.
+//!
+//! # Examples
+//! Reading the transmit timestamp of an NTP packet:
+//! ```
+//! use lilbig::timestamp::NtpTimestamp;
+//! use lilbig::{ByteOrder, FieldsByteOrdered, WireSafe};
+//!
+//! let bytes = [0xe9, 0x2a, 0x4c, 0x00, 0x80, 0x00, 0x00, 0x00];
+//! let mut transmit = NtpTimestamp::from_bytes(&bytes).unwrap();
+//! transmit.order_fields_ne(ByteOrder::Be);
+//! assert_eq!(transmit.seconds, 0xe92a4c00);
+//! assert_eq!(transmit.to_nanos(), 0xe92a4c00 * 1_000_000_000 + 500_000_000);
+//! ```
+
+use crate::{FieldsByteOrdered, WireSafe};
+
+/// The number of nanoseconds within a second.
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// NTP timestamp of 32-bit seconds and 32-bit fractions of a second.
+///
+/// Seconds are counted from the start of the timestamp's NTP era, the first of which began at
+/// 1900-01-01 00:00:00 UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(C)]
+pub struct NtpTimestamp {
+    /// Whole seconds.
+    pub seconds: u32,
+    /// Fractions of a second in units of 2<sup>-32</sup> seconds.
+    pub fraction: u32,
+}
+
+impl FieldsByteOrdered for NtpTimestamp {
+    #[inline]
+    fn swap_field_orders(&mut self) {
+        self.seconds.swap_field_orders();
+        self.fraction.swap_field_orders();
+    }
+}
+
+// SAFETY: `NtpTimestamp` is `repr(C)`, all of its fields are `WireSafe`, and its fields are
+// arranged such that it contains no padding.
+unsafe impl WireSafe for NtpTimestamp {}
+
+impl NtpTimestamp {
+    /// The number of seconds between the start of the first NTP era and the Unix epoch.
+    pub const UNIX_OFFSET: u64 = 2_208_988_800;
+
+    /// Create a timestamp from a number of nanoseconds since the start of its NTP era.
+    ///
+    /// Fractions of a second are rounded to the nearest representable fraction. Returns [`None`]
+    /// if `nanos` lies beyond the end of the era.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::timestamp::NtpTimestamp;
+    ///
+    /// let timestamp = NtpTimestamp::from_nanos(1_250_000_000).unwrap();
+    /// assert_eq!((timestamp.seconds, timestamp.fraction), (1, 0x40000000));
+    /// assert_eq!(timestamp.to_nanos(), 1_250_000_000);
+    /// assert_eq!(NtpTimestamp::from_nanos(u64::MAX), None);
+    /// ```
+    #[must_use]
+    pub fn from_nanos(nanos: u64) -> Option<Self> {
+        let seconds = u32::try_from(nanos / NANOS_PER_SECOND).ok()?;
+        let sub_nanos = nanos % NANOS_PER_SECOND;
+        let fraction = ((sub_nanos << 32) + NANOS_PER_SECOND / 2) / NANOS_PER_SECOND;
+        Some(Self {
+            seconds,
+            fraction: fraction as u32,
+        })
+    }
+
+    /// Convert the timestamp to a number of nanoseconds since the start of its NTP era.
+    ///
+    /// Fractions of a second are rounded to the nearest nanosecond.
+    #[must_use]
+    pub const fn to_nanos(&self) -> u64 {
+        let sub_nanos = (self.fraction as u64 * NANOS_PER_SECOND + (1 << 31)) >> 32;
+        self.seconds as u64 * NANOS_PER_SECOND + sub_nanos
+    }
+}
+
+/// PTP timestamp of 48-bit seconds and 32-bit nanoseconds.
+///
+/// The seconds and nanoseconds are held as 16-bit words ordered from most significant to least
+/// significant, matching their big-endian encoding within PTP messages. This keeps the timestamp
+/// free of padding despite the seconds being 48 bits wide, but means that the timestamp may only be
+/// converted to and from big-endian.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::timestamp::PtpTimestamp;
+/// use lilbig::{ByteOrder, FieldsByteOrdered, WireSafe};
+///
+/// let bytes = [0x00, 0x00, 0x65, 0x4a, 0x1c, 0x00, 0x00, 0x0f, 0x42, 0x40];
+/// let mut origin = PtpTimestamp::from_bytes(&bytes).unwrap();
+/// origin.order_fields_ne(ByteOrder::Be);
+/// assert_eq!(origin.seconds(), 0x654a1c00);
+/// assert_eq!(origin.nanoseconds(), 1_000_000);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(C)]
+pub struct PtpTimestamp {
+    /// Words of the seconds, most significant first.
+    seconds: [u16; 3],
+    /// Words of the nanoseconds, most significant first.
+    nanoseconds: [u16; 2],
+}
+
+impl FieldsByteOrdered for PtpTimestamp {
+    #[inline]
+    fn swap_field_orders(&mut self) {
+        self.seconds.swap_field_orders();
+        self.nanoseconds.swap_field_orders();
+    }
+}
+
+// SAFETY: `PtpTimestamp` is `repr(C)`, all of its fields are `WireSafe`, and its fields are
+// arranged such that it contains no padding.
+unsafe impl WireSafe for PtpTimestamp {}
+
+impl PtpTimestamp {
+    /// Create a timestamp from its seconds and nanoseconds.
+    ///
+    /// Bits of `seconds` beyond the 48 bits of the timestamp are discarded.
+    #[inline]
+    #[must_use]
+    pub const fn new(seconds: u64, nanoseconds: u32) -> Self {
+        Self {
+            seconds: [
+                (seconds >> 32) as u16,
+                (seconds >> 16) as u16,
+                seconds as u16,
+            ],
+            nanoseconds: [(nanoseconds >> 16) as u16, nanoseconds as u16],
+        }
+    }
+
+    /// Retrieve the timestamp's seconds.
+    #[inline]
+    #[must_use]
+    pub const fn seconds(&self) -> u64 {
+        let [high, mid, low] = self.seconds;
+        (high as u64) << 32 | (mid as u64) << 16 | low as u64
+    }
+
+    /// Retrieve the timestamp's nanoseconds.
+    #[inline]
+    #[must_use]
+    pub const fn nanoseconds(&self) -> u32 {
+        let [high, low] = self.nanoseconds;
+        (high as u32) << 16 | low as u32
+    }
+
+    /// Create a timestamp from a number of nanoseconds since the PTP epoch.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::timestamp::PtpTimestamp;
+    ///
+    /// let timestamp = PtpTimestamp::from_nanos(1_700_000_000_123_456_789);
+    /// assert_eq!(timestamp.seconds(), 1_700_000_000);
+    /// assert_eq!(timestamp.nanoseconds(), 123_456_789);
+    /// assert_eq!(timestamp.to_nanos(), Some(1_700_000_000_123_456_789));
+    /// ```
+    #[must_use]
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Self::new(nanos / NANOS_PER_SECOND, (nanos % NANOS_PER_SECOND) as u32)
+    }
+
+    /// Convert the timestamp to a number of nanoseconds since the PTP epoch.
+    ///
+    /// Returns [`None`] if the number of nanoseconds exceeds [`u64::MAX`].
+    #[must_use]
+    pub const fn to_nanos(&self) -> Option<u64> {
+        match self.seconds().checked_mul(NANOS_PER_SECOND) {
+            Some(nanos) => nanos.checked_add(self.nanoseconds() as u64),
+            None => None,
+        }
+    }
+}
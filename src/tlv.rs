@@ -0,0 +1,383 @@
+//! Tag-length-value encoding of a configurable layout.
+//!
+//! Each element consists of an unsigned tag, an unsigned length, and a value of that many bytes.
+//! The widths of the tag and length and the byte-order in which they are encoded are described by
+//! a [`TlvFormat`]. Values may themselves hold sequences of elements, which allows for nesting.
+//!
+//! Reading and writing are built upon [`OrderedReader`] and [`OrderedWriter`], which are also used
+//! to read and write the contents of values.
+//!
+//! # Examples
+//! Writing and reading nested elements:
+//! ```
+//! use lilbig::cursor::CursorError;
+//! use lilbig::tlv::{TlvFormat, TlvReader, TlvWriter};
+//! use lilbig::ByteOrder;
+//!
+//! // 1-byte tags and 2-byte big-endian lengths.
+//! let format = TlvFormat::try_new(1, 2, ByteOrder::Be)?;
+//!
+//! let mut buffer = [0u8; 32];
+//! let mut writer = TlvWriter::new(&mut buffer, format);
+//! writer.write(0x01, b"probe-7")?;
+//! writer.write_nested(0x02, |nested| {
+//!     nested.write_value(0x10, 2150u16)?;
+//!     nested.write_value(0x11, -40i32)
+//! })?;
+//! let encoded = writer.into_inner().into_written();
+//! assert_eq!(encoded.len(), 25);
+//!
+//! let mut elements = TlvReader::new(encoded, format);
+//! let name = elements.next().unwrap()?;
+//! assert_eq!((name.tag(), name.value()), (0x01, &b"probe-7"[..]));
+//!
+//! let reading = elements.next().unwrap()?;
+//! assert_eq!(reading.tag(), 0x02);
+//! let mut fields = reading.nested();
+//! assert_eq!(fields.next().unwrap()?.reader().read::<u16>()?, 2150);
+//! assert_eq!(fields.next().unwrap()?.reader().read::<i32>()?, -40);
+//! assert!(fields.next().is_none());
+//! assert!(elements.next().is_none());
+//! # Ok::<(), CursorError>(())
+//! ```
+
+use crate::cursor::{CursorError, OrderedReader, OrderedWriter};
+use crate::{ByteOrder, FieldsByteOrdered, WireSafe};
+
+/// Description of the layout of tag-length-value elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TlvFormat {
+    /// Width of tags in bytes.
+    tag_width: usize,
+    /// Width of lengths in bytes.
+    length_width: usize,
+    /// Byte-order of tags, lengths, and values.
+    order: ByteOrder,
+}
+
+impl TlvFormat {
+    /// Create a format of tags and lengths of the given widths in bytes encoded in `order`.
+    ///
+    /// # Panics
+    /// Panics if either width is zero or greater than 8. See [`try_new()`](TlvFormat::try_new)
+    /// for a non-panicking alternative.
+    #[cfg(not(feature = "no-panic"))]
+    #[must_use]
+    pub const fn new(tag_width: usize, length_width: usize, order: ByteOrder) -> Self {
+        match Self::try_new(tag_width, length_width, order) {
+            Ok(format) => format,
+            Err(err) => panic!("{}", err.message()),
+        }
+    }
+
+    /// Create a format of tags and lengths of the given widths in bytes encoded in `order`.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::InvalidWidth`] if either width is zero or greater than 8.
+    pub const fn try_new(
+        tag_width: usize,
+        length_width: usize,
+        order: ByteOrder,
+    ) -> Result<Self, CursorError> {
+        if tag_width == 0 || tag_width > 8 || length_width == 0 || length_width > 8 {
+            return Err(CursorError::InvalidWidth);
+        }
+        Ok(Self {
+            tag_width,
+            length_width,
+            order,
+        })
+    }
+
+    /// Retrieve the width of tags in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn tag_width(&self) -> usize {
+        self.tag_width
+    }
+
+    /// Retrieve the width of lengths in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn length_width(&self) -> usize {
+        self.length_width
+    }
+
+    /// Retrieve the byte-order of tags, lengths, and values.
+    #[inline]
+    #[must_use]
+    pub const fn order(&self) -> ByteOrder {
+        self.order
+    }
+}
+
+/// Element read by a [`TlvReader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tlv<'a> {
+    /// The element's tag.
+    tag: u64,
+    /// The element's value.
+    value: &'a [u8],
+    /// The format of the element.
+    format: TlvFormat,
+}
+
+impl<'a> Tlv<'a> {
+    /// Retrieve the element's tag.
+    #[inline]
+    #[must_use]
+    pub const fn tag(&self) -> u64 {
+        self.tag
+    }
+
+    /// Retrieve the bytes of the element's value.
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> &'a [u8] {
+        self.value
+    }
+
+    /// Create a reader of the contents of the element's value in the element's byte-order.
+    #[inline]
+    #[must_use]
+    pub const fn reader(&self) -> OrderedReader<'a> {
+        OrderedReader::new(self.value, self.format.order)
+    }
+
+    /// Create a reader of the elements nested within the element's value, which share the
+    /// element's format.
+    #[inline]
+    #[must_use]
+    pub const fn nested(&self) -> TlvReader<'a> {
+        TlvReader::new(self.value, self.format)
+    }
+}
+
+/// Iterator over the tag-length-value elements within a byte slice.
+///
+/// Iteration ends upon reaching the end of the bytes. Upon encountering a truncated element, the
+/// error is yielded and iteration ends.
+#[derive(Debug, Clone)]
+pub struct TlvReader<'a> {
+    /// Reader of the remaining elements.
+    reader: OrderedReader<'a>,
+    /// The format of the elements.
+    format: TlvFormat,
+}
+
+impl<'a> TlvReader<'a> {
+    /// Create an iterator over the elements of `format` within `bytes`.
+    #[inline]
+    #[must_use]
+    pub const fn new(bytes: &'a [u8], format: TlvFormat) -> Self {
+        Self {
+            reader: OrderedReader::new(bytes, format.order),
+            format,
+        }
+    }
+
+    /// Retrieve the format of the elements.
+    #[inline]
+    #[must_use]
+    pub const fn format(&self) -> TlvFormat {
+        self.format
+    }
+
+    /// Read the next element.
+    fn read_element(&mut self) -> Result<Tlv<'a>, CursorError> {
+        let tag = self.reader.read_uint(self.format.tag_width)?;
+        let length = self.reader.read_uint(self.format.length_width)?;
+        let length = usize::try_from(length).map_err(|_| CursorError::UnexpectedEnd)?;
+        let value = self.reader.read_bytes(length)?;
+        Ok(Tlv {
+            tag,
+            value,
+            format: self.format,
+        })
+    }
+}
+
+impl<'a> Iterator for TlvReader<'a> {
+    type Item = Result<Tlv<'a>, CursorError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.is_empty() {
+            return None;
+        }
+        let element = self.read_element();
+        if element.is_err() {
+            // Cease iteration after yielding the error.
+            self.reader = OrderedReader::new(&[], self.format.order);
+        }
+        Some(element)
+    }
+}
+
+/// Writer of tag-length-value elements to a byte slice.
+#[derive(Debug)]
+pub struct TlvWriter<'a> {
+    /// Writer of the elements.
+    writer: OrderedWriter<'a>,
+    /// The format of the elements.
+    format: TlvFormat,
+}
+
+impl<'a> TlvWriter<'a> {
+    /// Create a writer of elements of `format` to `bytes`.
+    #[inline]
+    #[must_use]
+    pub fn new(bytes: &'a mut [u8], format: TlvFormat) -> Self {
+        Self {
+            writer: OrderedWriter::new(bytes, format.order),
+            format,
+        }
+    }
+
+    /// Retrieve the format of the elements.
+    #[inline]
+    #[must_use]
+    pub const fn format(&self) -> TlvFormat {
+        self.format
+    }
+
+    /// Consume the writer, retrieving the underlying [`OrderedWriter`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> OrderedWriter<'a> {
+        self.writer
+    }
+
+    /// Write an element whose value is `value`.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::ValueOutOfRange`] if `tag` or the length of `value` do not fit within
+    /// their widths, or [`CursorError::BufferFull`] if the element does not fit within the
+    /// remaining bytes. Nothing is written upon error.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::cursor::CursorError;
+    /// use lilbig::tlv::{TlvFormat, TlvWriter};
+    /// use lilbig::ByteOrder;
+    ///
+    /// let mut buffer = [0u8; 8];
+    /// let mut writer = TlvWriter::new(&mut buffer, TlvFormat::try_new(1, 1, ByteOrder::Be)?);
+    /// assert_eq!(writer.write(0x07, &[0; 300]), Err(CursorError::ValueOutOfRange));
+    /// assert_eq!(writer.write(0x07, &[0; 8]), Err(CursorError::BufferFull));
+    /// writer.write(0x01, b"ab")?;
+    /// assert_eq!(writer.into_inner().written(), [0x01, 0x02, b'a', b'b']);
+    /// # Ok::<(), CursorError>(())
+    /// ```
+    pub fn write(&mut self, tag: u64, value: &[u8]) -> Result<(), CursorError> {
+        let length = u64::try_from(value.len()).map_err(|_| CursorError::ValueOutOfRange)?;
+        self.transaction(|this| {
+            this.write_header(tag, length)?;
+            this.writer.write_bytes(value)
+        })
+    }
+
+    /// Write an element whose value is `value` converted to the format's byte-order.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::ValueOutOfRange`] if `tag` or the size of `value` do not fit within
+    /// their widths, or [`CursorError::BufferFull`] if the element does not fit within the
+    /// remaining bytes. Nothing is written upon error.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::cursor::CursorError;
+    /// use lilbig::tlv::{TlvFormat, TlvWriter};
+    /// use lilbig::ByteOrder;
+    ///
+    /// let mut buffer = [0u8; 8];
+    /// let mut writer = TlvWriter::new(&mut buffer, TlvFormat::try_new(2, 2, ByteOrder::Le)?);
+    /// assert_eq!(writer.write_value(0x1_0000, 1u8), Err(CursorError::ValueOutOfRange));
+    /// assert_eq!(writer.write_value(0x10, 1u64), Err(CursorError::BufferFull));
+    /// writer.write_value(0x10, 0x0102u16)?;
+    /// assert_eq!(writer.into_inner().written(), [0x10, 0, 0x02, 0, 0x02, 0x01]);
+    /// # Ok::<(), CursorError>(())
+    /// ```
+    pub fn write_value<T: FieldsByteOrdered + WireSafe>(
+        &mut self,
+        tag: u64,
+        value: T,
+    ) -> Result<(), CursorError> {
+        let length =
+            u64::try_from(core::mem::size_of::<T>()).map_err(|_| CursorError::ValueOutOfRange)?;
+        self.transaction(|this| {
+            this.write_header(tag, length)?;
+            this.writer.write(value)
+        })
+    }
+
+    /// Write an element whose value holds the elements written by `f`.
+    ///
+    /// The element's length is filled in once `f` returns.
+    ///
+    /// # Errors
+    /// Returns any error returned by `f`, [`CursorError::ValueOutOfRange`] if `tag` or the length
+    /// of the nested elements do not fit within their widths, or [`CursorError::BufferFull`] if the
+    /// element does not fit within the remaining bytes. Nothing is written upon error, including
+    /// the elements written by `f`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use lilbig::cursor::CursorError;
+    /// use lilbig::tlv::{TlvFormat, TlvWriter};
+    /// use lilbig::ByteOrder;
+    ///
+    /// let mut buffer = [0u8; 8];
+    /// let mut writer = TlvWriter::new(&mut buffer, TlvFormat::try_new(1, 1, ByteOrder::Be)?);
+    /// let result = writer.write_nested(0x02, |nested| {
+    ///     nested.write(0x10, b"a")?;
+    ///     nested.write(0x11, b"bcdef")
+    /// });
+    /// assert_eq!(result, Err(CursorError::BufferFull));
+    /// assert_eq!(
+    ///     writer.write_nested(0x02, |nested| nested.write(0x10, &[0; 300])),
+    ///     Err(CursorError::ValueOutOfRange),
+    /// );
+    /// writer.write_nested(0x02, |nested| nested.write(0x10, b"a"))?;
+    /// assert_eq!(writer.into_inner().written(), [0x02, 0x03, 0x10, 0x01, b'a']);
+    /// # Ok::<(), CursorError>(())
+    /// ```
+    pub fn write_nested<F>(&mut self, tag: u64, f: F) -> Result<(), CursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<(), CursorError>,
+    {
+        self.transaction(|this| {
+            this.writer.write_uint(tag, this.format.tag_width)?;
+            let length_offset = this.writer.position();
+            this.writer.write_uint(0, this.format.length_width)?;
+            let start = this.writer.position();
+            f(this)?;
+            let length = u64::try_from(this.writer.position() - start)
+                .map_err(|_| CursorError::ValueOutOfRange)?;
+            this.writer
+                .write_uint_at(length_offset, length, this.format.length_width)
+        })
+    }
+
+    /// Write the tag and length of an element.
+    fn write_header(&mut self, tag: u64, length: u64) -> Result<(), CursorError> {
+        self.writer.write_uint(tag, self.format.tag_width)?;
+        self.writer.write_uint(length, self.format.length_width)
+    }
+
+    /// Perform `write`, rolling back anything it wrote upon error.
+    fn transaction<F>(&mut self, write: F) -> Result<(), CursorError>
+    where
+        F: FnOnce(&mut Self) -> Result<(), CursorError>,
+    {
+        let checkpoint = self.writer.checkpoint();
+        let result = write(self);
+        match result {
+            Ok(()) => self.writer.commit(checkpoint),
+            Err(_) => self.writer.rollback(checkpoint),
+        }
+        result
+    }
+}
@@ -0,0 +1,34 @@
+//! Tracing of conversions between byte-orders through the [`log`] crate.
+//!
+//! Struct-level conversions performed by the `order_fields_*` methods of [`FieldsByteOrdered`]
+//! that swap the byte-order of a value emit a debug event whose target is `lilbig`. Conversions
+//! that leave a value unmodified emit no event.
+//!
+//! [`FieldsByteOrdered`]: crate::FieldsByteOrdered
+
+use crate::ByteOrder;
+
+/// Emit an event for the conversion of `value` from `current_order` to `new_order`.
+#[inline]
+pub(crate) fn conversion<T: ?Sized>(value: &T, current_order: ByteOrder, new_order: ByteOrder) {
+    log::debug!(
+        target: "lilbig",
+        "converting {} from {:?} to {:?} ({} bytes)",
+        core::any::type_name::<T>(),
+        current_order,
+        new_order,
+        core::mem::size_of_val(value),
+    );
+}
+
+/// Emit an event for the violation `err` detected while tracking the byte-order of a `T`.
+#[cfg(feature = "checked")]
+#[inline]
+pub(crate) fn violation<T>(err: crate::checked::CheckedError) {
+    log::warn!(
+        target: "lilbig",
+        "{} of {}",
+        err,
+        core::any::type_name::<T>(),
+    );
+}
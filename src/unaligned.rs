@@ -0,0 +1,169 @@
+//! Values stored without the alignment of their types.
+//!
+//! Wire formats commonly place multi-byte fields at offsets that aren't multiples of their sizes.
+//! Declaring such structs `#[repr(packed)]` matches their layouts, although references to their
+//! fields may not be created, which rules out swapping the byte-order of the fields in place.
+//! [`Unaligned`] instead holds a value with an alignment of one, and accesses it through unaligned
+//! copies, so structs of such fields match the layouts of wire formats without being packed
+//! themselves.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # fn main() {
+//! use lilbig::unaligned::Unaligned;
+//! use lilbig::{ByteOrder, FieldsByteOrdered, WireSafe};
+//!
+//! #[derive(Clone, Copy, FieldsByteOrdered)]
+//! #[repr(C)]
+//! struct Record {
+//!     kind: u8,
+//!     length: Unaligned<u32>,
+//!     offset: Unaligned<u16>,
+//! }
+//!
+//! // SAFETY: `Record` is `repr(C)`, contains no padding as each of its fields has an alignment of
+//! // one, and all of its fields are `WireSafe`.
+//! unsafe impl WireSafe for Record {}
+//!
+//! let mut record = Record::from_bytes(&[7, 0x00, 0x00, 0x01, 0x02, 0x00, 0x10]).unwrap();
+//! record.order_fields_ne(ByteOrder::Be);
+//! assert_eq!(core::mem::size_of::<Record>(), 7);
+//! assert_eq!(record.kind, 7);
+//! assert_eq!(record.length.get(), 0x102);
+//! assert_eq!(record.offset.get(), 0x10);
+//! # }
+//! # #[cfg(not(feature = "derive"))]
+//! # fn main() {}
+//! ```
+
+use crate::layout::{FieldLayout, SwapLayout};
+use crate::{ByteOrdered, FieldsByteOrdered, WireSafe};
+
+/// Value stored without the alignment of its type.
+///
+/// The value is only ever copied into and out of the holder, as references to it may not be
+/// created. The holder is compared, hashed, and formatted as the value it holds.
+#[derive(Clone, Copy)]
+#[repr(C, packed)]
+pub struct Unaligned<T: Copy>(T);
+
+impl<T: Copy> Unaligned<T> {
+    /// Create a holder of `value`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Retrieve a copy of the held value.
+    #[inline(always)]
+    #[must_use]
+    pub const fn get(self) -> T {
+        self.0
+    }
+
+    /// Replace the held value with `value`.
+    #[inline(always)]
+    pub fn set(&mut self, value: T) {
+        self.0 = value;
+    }
+}
+
+impl<T: Copy> From<T> for Unaligned<T> {
+    #[inline(always)]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Copy + Default> Default for Unaligned<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Copy + PartialEq> PartialEq for Unaligned<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T: Copy + Eq> Eq for Unaligned<T> {}
+
+impl<T: Copy + PartialOrd> PartialOrd for Unaligned<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.get().partial_cmp(&other.get())
+    }
+}
+
+impl<T: Copy + Ord> Ord for Unaligned<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.get().cmp(&other.get())
+    }
+}
+
+impl<T: Copy + core::hash::Hash> core::hash::Hash for Unaligned<T> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.get().hash(state);
+    }
+}
+
+impl<T: Copy + core::fmt::Debug> core::fmt::Debug for Unaligned<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.get(), f)
+    }
+}
+
+impl<T: Copy + ByteOrdered> ByteOrdered for Unaligned<T> {
+    #[inline]
+    fn swapped_order(self) -> Self {
+        Self::new(self.get().swapped_order())
+    }
+}
+
+impl<T: Copy + FieldsByteOrdered> FieldsByteOrdered for Unaligned<T> {
+    const IS_ORDER_INVARIANT: bool = T::IS_ORDER_INVARIANT;
+    const HAS_PINNED_FIELDS: bool = T::HAS_PINNED_FIELDS;
+
+    #[inline]
+    fn swap_field_orders(&mut self) {
+        let mut value = self.get();
+        value.swap_field_orders();
+        self.set(value);
+    }
+
+    #[inline]
+    fn zero_padding(&mut self) {
+        let mut value = self.get();
+        value.zero_padding();
+        self.set(value);
+    }
+
+    #[inline]
+    fn swap_pinned_field_orders(&mut self) {
+        let mut value = self.get();
+        value.swap_pinned_field_orders();
+        self.set(value);
+    }
+}
+
+// SAFETY: The holder is packed with no other fields, so it shares the size and valid bit patterns
+// of the held type without introducing padding.
+unsafe impl<T: WireSafe> WireSafe for Unaligned<T> {}
+
+/// The held value is at the start of the holder thus it shares the held type's fields.
+impl<T: Copy + SwapLayout> SwapLayout for Unaligned<T> {
+    const SWAP_FIELD_COUNT: usize = T::SWAP_FIELD_COUNT;
+
+    #[inline]
+    fn swap_field(index: usize) -> Option<FieldLayout> {
+        T::swap_field(index)
+    }
+}
@@ -0,0 +1,120 @@
+//! Zero-copy views decoding the fields of structs from buffers of a runtime byte-order.
+//!
+//! Converting a struct in place requires it to be copied out of the buffer holding it, or the
+//! buffer itself to be modified. A view instead borrows the buffer and decodes or encodes each
+//! field as it is accessed, leaving every other byte of the buffer untouched.
+//!
+//! With the `derive` feature enabled, `#[derive(OrderedView)]` generates for a `#[repr(C)]` struct
+//! `Foo` the views `FooView<'a>` over a `&'a [u8]` and `FooViewMut<'a>` over a `&'a mut [u8]`,
+//! along with an implementation of [`OrderedView`] creating them. Each view provides a getter
+//! prefixed with `get_` for each field, and the mutable view additionally provides a setter
+//! prefixed with `set_`.
+//!
+//! # Examples
+//! Basic usage:
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # fn main() {
+//! use lilbig::view::OrderedView;
+//! use lilbig::ByteOrder;
+//!
+//! #[derive(OrderedView)]
+//! #[repr(C)]
+//! struct Record {
+//!     id: u32,
+//!     length: u16,
+//!     flags: [u8; 2],
+//! }
+//!
+//! let mut bytes = [0x00, 0x00, 0x00, 0x07, 0x01, 0x00, 0xaa, 0xbb];
+//! let view = Record::view(&bytes, ByteOrder::Be).unwrap();
+//! assert_eq!(view.get_id(), 7);
+//! assert_eq!(view.get_length(), 0x100);
+//! assert_eq!(view.get_flags(), [0xaa, 0xbb]);
+//!
+//! let mut view = Record::view_mut(&mut bytes, ByteOrder::Be).unwrap();
+//! view.set_length(0x0203);
+//! assert_eq!(bytes, [0x00, 0x00, 0x00, 0x07, 0x02, 0x03, 0xaa, 0xbb]);
+//! # }
+//! # #[cfg(not(feature = "derive"))]
+//! # fn main() {}
+//! ```
+
+use crate::{ByteOrder, FieldsByteOrdered, WireSafe};
+
+#[cfg(feature = "derive")]
+pub use lilbig_derive::OrderedView;
+
+/// Trait for types whose encoded bytes may be viewed without copying them.
+pub trait OrderedView {
+    /// View decoding the fields of the type from a borrowed buffer.
+    type View<'a>;
+    /// View decoding and encoding the fields of the type within a mutably borrowed buffer.
+    type ViewMut<'a>;
+
+    /// Create a view of the type encoded in `order` at the start of `bytes`, or [`None`] if
+    /// `bytes` is shorter than the type.
+    #[must_use]
+    fn view(bytes: &[u8], order: ByteOrder) -> Option<Self::View<'_>>;
+
+    /// Create a mutable view of the type encoded in `order` at the start of `bytes`, or [`None`]
+    /// if `bytes` is shorter than the type.
+    #[must_use]
+    fn view_mut(bytes: &mut [u8], order: ByteOrder) -> Option<Self::ViewMut<'_>>;
+}
+
+/// Decode a value encoded in `order` at `offset` within `bytes`, converting it to the machine's
+/// native byte-order.
+///
+/// Returns [`None`] if `bytes` ends before the value does.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::view::read_field;
+/// use lilbig::ByteOrder;
+///
+/// let bytes = [0xff, 0x12, 0x34];
+/// assert_eq!(read_field::<u16>(&bytes, 1, ByteOrder::Be), Some(0x1234));
+/// assert_eq!(read_field::<u16>(&bytes, 2, ByteOrder::Be), None);
+/// ```
+#[must_use]
+pub fn read_field<T: FieldsByteOrdered + WireSafe>(
+    bytes: &[u8],
+    offset: usize,
+    order: ByteOrder,
+) -> Option<T> {
+    let end = offset.checked_add(core::mem::size_of::<T>())?;
+    let mut value = T::from_bytes(bytes.get(offset..end)?)?;
+    value.order_fields_ne(order);
+    Some(value)
+}
+
+/// Encode `value`, which is in the machine's native byte-order, in `order` at `offset` within
+/// `bytes`.
+///
+/// Returns [`None`] without modifying `bytes` if `bytes` ends before the value does.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use lilbig::view::write_field;
+/// use lilbig::ByteOrder;
+///
+/// let mut bytes = [0xff; 3];
+/// assert_eq!(write_field(&mut bytes, 1, 0x1234_u16, ByteOrder::Le), Some(()));
+/// assert_eq!(bytes, [0xff, 0x34, 0x12]);
+/// assert_eq!(write_field(&mut bytes, 2, 0x1234_u16, ByteOrder::Le), None);
+/// ```
+pub fn write_field<T: FieldsByteOrdered + WireSafe>(
+    bytes: &mut [u8],
+    offset: usize,
+    mut value: T,
+    order: ByteOrder,
+) -> Option<()> {
+    let end = offset.checked_add(core::mem::size_of::<T>())?;
+    let bytes = bytes.get_mut(offset..end)?;
+    value.order_fields_as(ByteOrder::NATIVE, order);
+    bytes.copy_from_slice(value.as_bytes());
+    Some(())
+}
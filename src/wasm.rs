@@ -0,0 +1,125 @@
+//! JavaScript bindings of the crate's bulk and layout-driven swapping utilities.
+//!
+//! The bindings are exported through [wasm-bindgen](https://rustwasm.github.io/wasm-bindgen/) when
+//! the crate is built for WebAssembly, and operate upon `Uint8Array`s and `ArrayBuffer`s. Records
+//! described by a layout are swapped through the exported `Layout` class.
+//!
+//! # Examples
+//! Calling the bindings from JavaScript:
+//! ```js
+//! import { swapElements, Layout } from "./pkg/lilbig.js";
+//!
+//! const samples = new Uint8Array([0x00, 0x01, 0x00, 0x02]);
+//! swapElements(samples, 2);
+//! // samples: [0x01, 0x00, 0x02, 0x00]
+//!
+//! // A 4-byte field, a 2-byte field, and 2 bytes of padding.
+//! const layout = new Layout(8, new Uint32Array([0, 4, 4, 2]));
+//! const records = new ArrayBuffer(16);
+//! layout.swapRecords(records);
+//! ```
+
+use crate::bulk;
+use crate::layout::{FieldLayout, LayoutError, TypeLayout};
+use crate::WidthError;
+use js_sys::{ArrayBuffer, Uint8Array};
+use std::string::ToString;
+use std::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+/// Swap the byte-order of each element of `width` bytes within `buffer`, which is either a
+/// `Uint8Array` or an `ArrayBuffer`.
+///
+/// # Errors
+/// Returns an error if `buffer` is of neither type, if `width` is zero, or if the length of
+/// `buffer` is not a multiple of `width`. The contents of `buffer` are left unmodified upon error.
+#[wasm_bindgen(js_name = swapElements)]
+pub fn swap_elements(buffer: &JsValue, width: usize) -> Result<(), JsError> {
+    swap_in_place(buffer, |bytes| bulk::try_swap_elements(bytes, width))
+}
+
+/// Runtime description of the fields of a record, exported to JavaScript as `Layout`.
+#[wasm_bindgen(js_name = Layout)]
+#[derive(Debug, Clone)]
+pub struct WasmLayout {
+    /// Size of a record in bytes.
+    size: usize,
+    /// Fields within a record.
+    fields: Vec<FieldLayout>,
+}
+
+#[wasm_bindgen(js_class = Layout)]
+impl WasmLayout {
+    /// Create a layout of records of `size` bytes whose fields are given by `fields` as pairs of
+    /// offsets and widths in bytes.
+    ///
+    /// # Errors
+    /// Returns an error if `fields` holds an odd number of elements, or if a field extends beyond
+    /// the end of a record, overlaps another field, or is of an unsupported width.
+    #[wasm_bindgen(constructor)]
+    pub fn new(size: usize, fields: &[u32]) -> Result<WasmLayout, JsError> {
+        if !fields.len().is_multiple_of(2) {
+            return Err(JsError::new(
+                "fields must be given as pairs of offsets and widths",
+            ));
+        }
+        let offsets = fields.iter().step_by(2);
+        let widths = fields.iter().skip(1).step_by(2);
+        let fields = offsets
+            .zip(widths)
+            .map(|(&offset, &width)| FieldLayout::new(offset as usize, width as usize))
+            .collect::<Vec<_>>();
+        TypeLayout::try_new(size, &fields).map_err(layout_error)?;
+        Ok(Self { size, fields })
+    }
+
+    /// Retrieve the size of a record in bytes.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Swap the byte-order of each field within each record of `buffer`, which is either a
+    /// `Uint8Array` or an `ArrayBuffer`.
+    ///
+    /// # Errors
+    /// Returns an error if `buffer` is of neither type, if the layout's size is zero, or if the
+    /// length of `buffer` is not a multiple of the layout's size. The contents of `buffer` are left
+    /// unmodified upon error.
+    #[wasm_bindgen(js_name = swapRecords)]
+    pub fn swap_records(&self, buffer: &JsValue) -> Result<(), JsError> {
+        let layout = TypeLayout::try_new(self.size, &self.fields).map_err(layout_error)?;
+        swap_in_place(buffer, |bytes| layout.try_swap_records(bytes))
+    }
+}
+
+/// Convert a [`LayoutError`] to a JavaScript `Error`.
+fn layout_error(err: LayoutError) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+/// Retrieve a view of the bytes of `buffer`, which is either a `Uint8Array` or an `ArrayBuffer`.
+fn bytes_of(buffer: &JsValue) -> Result<Uint8Array, JsError> {
+    if let Some(array) = buffer.dyn_ref::<Uint8Array>() {
+        Ok(array.clone())
+    } else if let Some(buffer) = buffer.dyn_ref::<ArrayBuffer>() {
+        Ok(Uint8Array::new(buffer))
+    } else {
+        Err(JsError::new(
+            "buffer must be a Uint8Array or an ArrayBuffer",
+        ))
+    }
+}
+
+/// Copy the bytes of `buffer` into the module's memory, pass them to `swap`, and copy them back.
+fn swap_in_place(
+    buffer: &JsValue,
+    swap: impl FnOnce(&mut [u8]) -> Result<(), WidthError>,
+) -> Result<(), JsError> {
+    let array = bytes_of(buffer)?;
+    let mut bytes = array.to_vec();
+    swap(&mut bytes).map_err(|err| JsError::new(err.message()))?;
+    array.copy_from(&bytes);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+//! Implementations bridging the byte-order aware types of [`zerocopy`] with those of this crate.
+//!
+//! The byte-order of zerocopy's byte-order aware types is fixed, so they are order invariant fields
+//! that may be placed within structs implementing [`FieldsByteOrdered`]. Each type converts to and
+//! from the [`Le`] or [`Be`] wrapper of the same byte-order without modifying its bytes.
+
+use crate::layout::{FieldLayout, SwapLayout};
+use crate::ordered::{Be, Le};
+use crate::{FieldsByteOrdered, WireSafe};
+use zerocopy::byteorder::{BigEndian, ByteOrder, LittleEndian};
+#[cfg(not(feature = "strict-usize"))]
+use zerocopy::byteorder::{Isize, Usize};
+#[cfg(not(feature = "no-float"))]
+use zerocopy::byteorder::{F32, F64};
+use zerocopy::byteorder::{I128, I16, I32, I64, U128, U16, U32, U64};
+
+/// Implement the crate's traits and conversions for a set of zerocopy byte-order aware types along
+/// with their native types.
+macro_rules! impl_zerocopy {
+    ($($ty: ident: $native: ty),+) => {
+        $(
+        /// Provided for completeness. The byte-order of the value is fixed thus this function
+        /// applies no modifications to `self`.
+        impl<O: ByteOrder> FieldsByteOrdered for $ty<O> {
+            const IS_ORDER_INVARIANT: bool = true;
+
+            #[inline(always)]
+            fn swap_field_orders(&mut self) {}
+        }
+
+        // SAFETY: zerocopy's byte-order aware types are byte arrays, which contain no padding and
+        // for which every bit pattern is valid.
+        unsafe impl<O: ByteOrder + 'static> WireSafe for $ty<O> {}
+
+        /// The byte-order of the value is fixed thus the type has no fields.
+        impl<O: ByteOrder> SwapLayout for $ty<O> {
+            const SWAP_FIELD_COUNT: usize = 0;
+
+            #[inline]
+            fn swap_field(_index: usize) -> Option<FieldLayout> {
+                None
+            }
+        }
+
+        impl From<$ty<LittleEndian>> for Le<$native> {
+            #[inline]
+            fn from(value: $ty<LittleEndian>) -> Self {
+                Self::from_inner(<$native>::from_ne_bytes(value.to_bytes()))
+            }
+        }
+
+        impl From<Le<$native>> for $ty<LittleEndian> {
+            #[inline]
+            fn from(value: Le<$native>) -> Self {
+                Self::from_bytes(value.into_inner().to_ne_bytes())
+            }
+        }
+
+        impl From<$ty<BigEndian>> for Be<$native> {
+            #[inline]
+            fn from(value: $ty<BigEndian>) -> Self {
+                Self::from_inner(<$native>::from_ne_bytes(value.to_bytes()))
+            }
+        }
+
+        impl From<Be<$native>> for $ty<BigEndian> {
+            #[inline]
+            fn from(value: Be<$native>) -> Self {
+                Self::from_bytes(value.into_inner().to_ne_bytes())
+            }
+        }
+        )+
+    };
+}
+
+impl_zerocopy!(
+    U16: u16,
+    U32: u32,
+    U64: u64,
+    U128: u128,
+    I16: i16,
+    I32: i32,
+    I64: i64,
+    I128: i128
+);
+#[cfg(not(feature = "no-float"))]
+impl_zerocopy!(F32: f32, F64: f64);
+#[cfg(not(feature = "strict-usize"))]
+impl_zerocopy!(Usize: usize, Isize: isize);
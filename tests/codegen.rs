@@ -0,0 +1,142 @@
+//! Codegen checks of the ordering methods taking the byte-order as a marker type parameter.
+//!
+//! The byte-order of the marker is known at compile time, so each call must compile down to either
+//! nothing or an unconditional swap. A probe crate calling the methods is compiled to assembly
+//! against the crate's sources, and the bodies of its functions are checked for conditional
+//! branches. The checks read x86-64 assembly, and are skipped upon other architectures.
+
+#![cfg(target_arch = "x86_64")]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Source of the probe crate, whose functions convert from the machine's native byte-order and
+/// from the opposite byte-order.
+const PROBE: &str = r#"
+use lilbig::marker::{BigEndian, LittleEndian};
+use lilbig::{ByteOrdered, FieldsByteOrdered};
+
+#[no_mangle]
+pub fn ordered_native(value: u64) -> u64 {
+    value.ordered::<LittleEndian>()
+}
+
+#[no_mangle]
+pub fn ordered_opposite(value: u64) -> u64 {
+    value.ordered::<BigEndian>()
+}
+
+#[no_mangle]
+pub fn order_fields_native(values: &mut [u32; 4]) {
+    values.order_fields::<LittleEndian>();
+}
+
+#[no_mangle]
+pub fn order_fields_opposite(values: &mut [u32; 4]) {
+    values.order_fields::<BigEndian>();
+}
+"#;
+
+/// Run `rustc` with `args`, panicking if it fails.
+fn rustc(args: &[&str]) {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    let output = Command::new(rustc)
+        .args([
+            "--edition",
+            "2021",
+            "-C",
+            "opt-level=3",
+            "-C",
+            "codegen-units=1",
+        ])
+        .args(args)
+        .output()
+        .expect("failed to run rustc");
+    assert!(
+        output.status.success(),
+        "rustc failed:\n{}",
+        String::from_utf8_lossy(&output.stderr),
+    );
+}
+
+/// Compile the probe crate, returning its assembly.
+fn probe_assembly() -> String {
+    let out_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("codegen");
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let out = out_dir.to_str().unwrap();
+    let lib = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/lib.rs");
+    rustc(&[
+        "--crate-type=rlib",
+        "--crate-name=lilbig",
+        "--cap-lints=allow",
+        "--out-dir",
+        out,
+        lib.to_str().unwrap(),
+    ]);
+
+    let probe = out_dir.join("probe.rs");
+    std::fs::write(&probe, PROBE).unwrap();
+    let extern_lilbig = format!("lilbig={out}/liblilbig.rlib");
+    let assembly = out_dir.join("probe.s");
+    rustc(&[
+        "--crate-type=lib",
+        "--emit=asm",
+        "--extern",
+        &extern_lilbig,
+        "-o",
+        assembly.to_str().unwrap(),
+        probe.to_str().unwrap(),
+    ]);
+    std::fs::read_to_string(assembly).unwrap()
+}
+
+/// Retrieve the instructions of the function `name` within `assembly`.
+fn instructions<'a>(assembly: &'a str, name: &str) -> Vec<&'a str> {
+    let label = format!("{name}:");
+    assembly
+        .lines()
+        .skip_while(|line| *line != label)
+        .skip(1)
+        .take_while(|line| !line.starts_with(".Lfunc_end"))
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with(['.', '#']) && !line.ends_with(':'))
+        .collect()
+}
+
+/// Check whether `instruction` is a conditional branch or move.
+fn is_conditional(instruction: &str) -> bool {
+    let mnemonic = instruction.split_whitespace().next().unwrap_or_default();
+    (mnemonic.starts_with('j') && mnemonic != "jmp") || mnemonic.starts_with("cmov")
+}
+
+#[test]
+fn marker_orders_are_resolved_at_compile_time() {
+    let assembly = probe_assembly();
+    for name in [
+        "ordered_native",
+        "ordered_opposite",
+        "order_fields_native",
+        "order_fields_opposite",
+    ] {
+        let body = instructions(&assembly, name);
+        assert!(!body.is_empty(), "`{name}` not found within:\n{assembly}");
+        assert!(
+            !body.iter().any(|instruction| is_conditional(instruction)),
+            "`{name}` contains a conditional instruction:\n{}",
+            body.join("\n"),
+        );
+    }
+
+    let native = instructions(&assembly, "ordered_native");
+    assert!(!native
+        .iter()
+        .any(|instruction| instruction.starts_with("bswap")));
+    let opposite = instructions(&assembly, "ordered_opposite");
+    assert!(opposite
+        .iter()
+        .any(|instruction| instruction.starts_with("bswap")));
+    let native = instructions(&assembly, "order_fields_native");
+    assert!(native
+        .iter()
+        .all(|instruction| instruction.starts_with("ret")));
+}